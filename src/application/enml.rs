@@ -0,0 +1,629 @@
+use quick_xml::{
+    Reader,
+    escape::resolve_html5_entity,
+    events::{BytesStart, Event},
+};
+
+/// The XHTML dialect Evernote writes the content of the notes in, as found in `.enex` files:
+///
+/// ```text
+/// <en-note>
+///   <div>Buy <b>milk</b></div>
+///   <div><en-todo checked="true"/>Call Ana</div>
+/// </en-note>
+/// ```
+///
+/// It is converted to Markdown: divs and paragraphs become paragraphs, `<en-todo>` checkboxes
+/// task list items and the code blocks of Evernote (divs styled with `-en-codeblock`) fenced code blocks.
+/// Attachments (`<en-media>`) and encrypted text (`<en-crypt>`) are not converted,
+/// a placeholder is left in their place.
+pub struct Enml;
+
+impl Enml {
+    /// Converts the content of an Evernote note to Markdown.
+    ///
+    /// # Arguments
+    /// - `enml`: The ENML document, with or without its XML declaration and doctype.
+    ///
+    /// # Returns
+    /// - `Ok(String)` with the Markdown text, without blank lines around it.
+    /// - `Err(String)` if the document is not well-formed XML.
+    pub fn to_markdown(enml: &str) -> Result<String, String> {
+        let mut reader = Reader::from_str(enml);
+        reader.config_mut().check_end_names = false;
+
+        let mut writer = MarkdownWriter::default();
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(element)) => writer.start(&element),
+                Ok(Event::Empty(element)) => {
+                    writer.start(&element);
+                    writer.end(element.local_name().as_ref());
+                }
+                Ok(Event::End(element)) => writer.end(element.local_name().as_ref()),
+                Ok(Event::Text(text)) => {
+                    let text = text
+                        .unescape_with(resolve_html5_entity)
+                        .map_err(|err| format!("the content is not valid ENML: {}", err))?;
+                    writer.text(&text);
+                }
+                Ok(Event::CData(data)) => writer.text(&String::from_utf8_lossy(&data.into_inner())),
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(err) => return Err(format!("the content is not valid ENML: {}", err)),
+            }
+        }
+
+        Ok(writer.finish())
+    }
+
+    /// Reads an attribute of an element, `None` if it is missing or empty.
+    fn attribute(element: &BytesStart, name: &str) -> Option<String> {
+        element
+            .try_get_attribute(name)
+            .ok()
+            .flatten()
+            .and_then(|attribute| attribute.unescape_value_with(resolve_html5_entity).ok())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+/// Elements without content, which may be written without their end tag.
+const VOID_ELEMENTS: [&str; 5] = ["br", "hr", "img", "en-media", "en-todo"];
+
+/// Elements laid out as a paragraph, besides `div` and `p`.
+const BLOCK_ELEMENTS: [&str; 16] = [
+    "address",
+    "article",
+    "aside",
+    "center",
+    "dd",
+    "details",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "header",
+    "nav",
+    "section",
+];
+
+/// What happens when an element ends, decided when it starts.
+enum Closer {
+    Nothing,
+    Block,
+    Inline,
+    List,
+    Item,
+    Quote,
+    Code,
+    CodeLine,
+    Table,
+    Row,
+    Cell,
+    Skip,
+}
+
+/// An inline element whose text is collected before being written, so its markers can wrap it.
+struct InlineText {
+    /// Marker around the text, or the target of a link
+    kind: InlineKind,
+    text: String,
+}
+
+enum InlineKind {
+    Marker(&'static str),
+    Link(String),
+}
+
+/// A list being written.
+struct List {
+    /// Number of the next item, `None` for a bulleted list
+    number: Option<u32>,
+
+    /// Width of the marker of the last item, the indentation of the lines that follow it
+    width: usize,
+}
+
+/// Writes the Markdown text while the ENML elements are read.
+///
+/// Blocks are not written when they end but when the next text arrives, so the
+/// line breaks between them can be merged: `newlines` holds how many are pending.
+#[derive(Default)]
+struct MarkdownWriter {
+    out: String,
+    closers: Vec<Closer>,
+
+    /// Line breaks pending before the next text, 2 for a blank line
+    newlines: usize,
+
+    /// Whether the pending line break comes from a `<br>`, written as a hard break
+    hard_break: bool,
+
+    /// Whether the current line has been started, with its prefix
+    line_open: bool,
+
+    /// Whether the current line holds nothing but its prefix
+    fresh: bool,
+
+    /// Whether a space is pending before the next text
+    space: bool,
+
+    /// Whether the current line is a task list item outside a list
+    todo_line: bool,
+
+    inline: Vec<InlineText>,
+    lists: Vec<List>,
+
+    /// Marker of the list item whose first line has not been written yet
+    marker: Option<String>,
+
+    quotes: usize,
+
+    /// Quote depth of the last line written
+    line_quotes: usize,
+
+    code: usize,
+    tables: usize,
+    rows: usize,
+    cells: usize,
+    in_cell: bool,
+    skip: usize,
+}
+
+impl MarkdownWriter {
+    fn start(&mut self, element: &BytesStart) {
+        let local_name = element.local_name();
+        let name = String::from_utf8_lossy(local_name.as_ref()).to_lowercase();
+        let void = VOID_ELEMENTS.contains(&name.as_str());
+
+        let closer = if self.skip > 0 {
+            Closer::Nothing
+        } else if self.code > 0 {
+            match name.as_str() {
+                "div" | "p" => Closer::CodeLine,
+                "br" => {
+                    self.code_newline();
+                    Closer::Nothing
+                }
+                _ => Closer::Nothing,
+            }
+        } else {
+            self.start_element(&name, element)
+        };
+
+        if !void {
+            self.closers.push(closer);
+        }
+    }
+
+    fn start_element(&mut self, name: &str, element: &BytesStart) -> Closer {
+        match name {
+            "div" | "p" => {
+                let style = Enml::attribute(element, "style").unwrap_or_default();
+                if style.contains("-en-codeblock") {
+                    self.start_code();
+                    Closer::Code
+                } else {
+                    self.separate();
+                    Closer::Block
+                }
+            }
+            "pre" => {
+                self.start_code();
+                Closer::Code
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = name[1..].parse().unwrap_or(1);
+                self.separate();
+                self.emit(&"#".repeat(level));
+                self.space = true;
+                Closer::Block
+            }
+            "blockquote" => {
+                self.separate();
+                self.quotes += 1;
+                Closer::Quote
+            }
+            "ul" | "ol" => {
+                self.separate_list();
+                let number = Enml::attribute(element, "start").and_then(|start| start.parse().ok()).unwrap_or(1);
+                self.lists.push(List {
+                    number: Some(number).filter(|_| name == "ol"),
+                    width: 2,
+                });
+                Closer::List
+            }
+            "li" => {
+                self.end_line();
+                if let Some(list) = self.lists.last_mut() {
+                    let marker = match list.number.as_mut() {
+                        Some(number) => {
+                            *number += 1;
+                            format!("{}. ", *number - 1)
+                        }
+                        None => String::from("- "),
+                    };
+                    list.width = marker.len();
+                    self.marker = Some(marker);
+                }
+                Closer::Item
+            }
+            "table" => {
+                self.tables += 1;
+                if self.tables > 1 {
+                    return Closer::Table;
+                }
+                self.separate();
+                self.rows = 0;
+                Closer::Table
+            }
+            "tr" if self.tables == 1 => {
+                self.cells = 0;
+                Closer::Row
+            }
+            "td" | "th" if self.tables == 1 && !self.in_cell => {
+                self.emit("|");
+                self.space = true;
+                self.in_cell = true;
+                self.cells += 1;
+                Closer::Cell
+            }
+            "br" => {
+                self.line_break();
+                Closer::Nothing
+            }
+            "hr" => {
+                self.separate();
+                self.emit("---");
+                self.separate();
+                Closer::Nothing
+            }
+            "img" => {
+                if let Some(source) = Enml::attribute(element, "src") {
+                    let alt = Enml::attribute(element, "alt").unwrap_or_default();
+                    self.emit(&format!("![{}]({})", alt, source));
+                }
+                Closer::Nothing
+            }
+            "en-media" => {
+                let kind = Enml::attribute(element, "type").unwrap_or_else(|| String::from("file"));
+                self.emit(&format!("_[attachment: {}]_", kind));
+                Closer::Nothing
+            }
+            "en-todo" => {
+                self.todo(Enml::attribute(element, "checked").is_some_and(|checked| checked == "true"));
+                Closer::Nothing
+            }
+            "en-crypt" => {
+                self.emit("_[encrypted text]_");
+                self.skip += 1;
+                Closer::Skip
+            }
+            "head" | "title" | "style" | "script" => {
+                self.skip += 1;
+                Closer::Skip
+            }
+            "b" | "strong" => self.start_inline(InlineKind::Marker("**")),
+            "i" | "em" => self.start_inline(InlineKind::Marker("_")),
+            "s" | "strike" | "del" => self.start_inline(InlineKind::Marker("~~")),
+            "code" | "tt" | "kbd" | "samp" => self.start_inline(InlineKind::Marker("`")),
+            "a" => match Enml::attribute(element, "href") {
+                Some(href) => self.start_inline(InlineKind::Link(href)),
+                None => Closer::Nothing,
+            },
+            name if BLOCK_ELEMENTS.contains(&name) => {
+                self.separate();
+                Closer::Block
+            }
+            _ => Closer::Nothing,
+        }
+    }
+
+    fn end(&mut self, name: &[u8]) {
+        if VOID_ELEMENTS.iter().any(|void| void.as_bytes().eq_ignore_ascii_case(name)) {
+            return;
+        }
+
+        match self.closers.pop() {
+            Some(Closer::Block) => self.separate(),
+            Some(Closer::Inline) => self.end_inline(),
+            Some(Closer::List) => {
+                self.lists.pop();
+                self.separate_list();
+            }
+            Some(Closer::Item) => {
+                self.end_line();
+                self.marker = None;
+            }
+            Some(Closer::Quote) => {
+                self.quotes -= 1;
+                self.separate();
+            }
+            Some(Closer::Code) => {
+                if !self.fresh {
+                    self.code_newline();
+                }
+                self.out.push_str("```");
+                self.fresh = false;
+                self.code -= 1;
+                self.separate();
+            }
+            Some(Closer::CodeLine) => {
+                if !self.fresh {
+                    self.code_newline();
+                }
+            }
+            Some(Closer::Table) => {
+                self.tables -= 1;
+                if self.tables == 0 {
+                    self.separate();
+                }
+            }
+            Some(Closer::Row) => self.end_row(),
+            Some(Closer::Cell) => {
+                self.in_cell = false;
+                self.space = true;
+            }
+            Some(Closer::Skip) => self.skip -= 1,
+            Some(Closer::Nothing) | None => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.skip > 0 {
+            return;
+        }
+        if self.code > 0 {
+            self.open_line();
+            for c in text.chars() {
+                match c {
+                    '\n' => self.code_newline(),
+                    '\r' => {}
+                    '\u{a0}' => self.out.push(' '),
+                    c => self.out.push(c),
+                }
+                self.fresh = false;
+            }
+            return;
+        }
+
+        for c in text.chars() {
+            if c.is_whitespace() {
+                match self.inline.last_mut() {
+                    Some(inline) if !inline.text.ends_with(' ') => inline.text.push(' '),
+                    Some(_) => {}
+                    None => self.space = true,
+                }
+            } else if c == '|' && self.in_cell {
+                self.emit("\\|");
+            } else {
+                self.emit(c.encode_utf8(&mut [0; 4]));
+            }
+        }
+    }
+
+    /// Writes a piece of text that is not split, starting the line if needed.
+    fn emit(&mut self, piece: &str) {
+        if self.skip > 0 {
+            return;
+        }
+        if let Some(inline) = self.inline.last_mut() {
+            inline.text.push_str(piece);
+            return;
+        }
+
+        self.open_line();
+        if self.space && !self.fresh {
+            self.out.push(' ');
+        }
+        self.space = false;
+        self.out.push_str(piece);
+        self.fresh = false;
+    }
+
+    /// Writes the pending line breaks and the prefix of the new line: the quote markers
+    /// and the indentation of the lists, or the marker of a new list item.
+    fn open_line(&mut self) {
+        if self.line_open {
+            return;
+        }
+
+        if !self.out.is_empty() {
+            if self.hard_break && self.newlines == 1 {
+                self.out.push_str("  ");
+            }
+            // A blank line stays inside the quotes only when both lines around it are quoted
+            let quotes = self.quotes.min(self.line_quotes);
+            for index in 0..self.newlines {
+                if index > 0 && quotes > 0 {
+                    self.out.push_str(&vec![">"; quotes].join(" "));
+                }
+                self.out.push('\n');
+            }
+        }
+
+        let prefix = self.prefix();
+        self.out.push_str(&prefix);
+        self.newlines = 0;
+        self.hard_break = false;
+        self.line_open = true;
+        self.fresh = true;
+        self.space = false;
+        self.todo_line = false;
+        self.line_quotes = self.quotes;
+    }
+
+    fn prefix(&mut self) -> String {
+        let mut prefix = "> ".repeat(self.quotes);
+        let last = self.lists.len().saturating_sub(1);
+
+        for (index, list) in self.lists.iter().enumerate() {
+            if index == last {
+                if let Some(marker) = self.marker.take() {
+                    prefix.push_str(&marker);
+                    continue;
+                }
+            }
+            prefix.push_str(&" ".repeat(list.width));
+        }
+
+        prefix
+    }
+
+    /// Whether line breaks become spaces, inside inline elements and table cells.
+    fn breaks_are_spaces(&mut self) -> bool {
+        if !self.inline.is_empty() || self.in_cell {
+            self.text(" ");
+            return true;
+        }
+
+        false
+    }
+
+    /// Ends the current line, if any.
+    fn end_line(&mut self) {
+        if self.breaks_are_spaces() {
+            return;
+        }
+
+        self.hard_break = false;
+        if self.line_open {
+            self.line_open = false;
+            self.newlines = self.newlines.max(1);
+        }
+    }
+
+    /// Ends the current paragraph with a blank line, or the current line inside a list.
+    fn separate(&mut self) {
+        if !self.lists.is_empty() {
+            self.end_line();
+            return;
+        }
+        if self.breaks_are_spaces() {
+            return;
+        }
+
+        self.hard_break = false;
+        self.line_open = false;
+        if !self.out.is_empty() {
+            self.newlines = 2;
+        }
+    }
+
+    /// A list nested in another one only starts a new line.
+    fn separate_list(&mut self) {
+        if self.lists.is_empty() {
+            self.separate();
+        } else {
+            self.end_line();
+        }
+    }
+
+    /// `<br>`: a hard break in the middle of a paragraph, an empty line otherwise.
+    fn line_break(&mut self) {
+        if self.breaks_are_spaces() || self.out.is_empty() {
+            return;
+        }
+
+        if self.line_open {
+            self.line_open = false;
+            self.newlines = self.newlines.max(1);
+            self.hard_break = true;
+        } else {
+            self.newlines = (self.newlines + 1).min(2);
+        }
+    }
+
+    fn todo(&mut self, checked: bool) {
+        let marker = if checked { "[x]" } else { "[ ]" };
+        let at_start = !self.line_open || self.fresh;
+
+        if !self.inline.is_empty() || !self.lists.is_empty() || !at_start {
+            self.emit(marker);
+            self.space = true;
+            return;
+        }
+
+        // Consecutive checkboxes make a single list
+        if !self.line_open && self.todo_line && self.newlines == 2 {
+            self.newlines = 1;
+        }
+        self.emit(&format!("- {}", marker));
+        self.space = true;
+        self.todo_line = true;
+    }
+
+    fn start_code(&mut self) {
+        self.separate();
+        self.emit("```");
+        self.code += 1;
+        self.code_newline();
+    }
+
+    fn code_newline(&mut self) {
+        self.out.push('\n');
+        let prefix = self.prefix();
+        self.out.push_str(&prefix);
+        self.fresh = true;
+    }
+
+    fn start_inline(&mut self, kind: InlineKind) -> Closer {
+        self.inline.push(InlineText {
+            kind,
+            text: String::new(),
+        });
+        Closer::Inline
+    }
+
+    /// Writes the text of an inline element wrapped in its markers, keeping the spaces
+    /// around it outside them: `<b> bold </b>` becomes ` **bold** `.
+    fn end_inline(&mut self) {
+        let Some(inline) = self.inline.pop() else {
+            return;
+        };
+
+        let content = inline.text.trim();
+        let piece = match &inline.kind {
+            InlineKind::Marker(_) if content.is_empty() => String::new(),
+            InlineKind::Marker(marker) => format!("{}{}{}", marker, content, marker),
+            // Links between Evernote notes cannot be followed here
+            InlineKind::Link(href) if href.starts_with("evernote:") => content.to_string(),
+            InlineKind::Link(href) if content.is_empty() || content == href => format!("<{}>", href),
+            InlineKind::Link(href) => format!("[{}]({})", content, href),
+        };
+
+        if inline.text.starts_with(' ') {
+            self.text(" ");
+        }
+        if !piece.is_empty() {
+            self.emit(&piece);
+        }
+        if inline.text.ends_with(' ') && !content.is_empty() {
+            self.text(" ");
+        }
+    }
+
+    /// Ends a table row, adding the line under the header after the first one.
+    fn end_row(&mut self) {
+        if self.cells == 0 {
+            return;
+        }
+
+        self.emit("|");
+        self.end_line();
+        if self.rows == 0 {
+            self.emit(&format!("|{}", " --- |".repeat(self.cells)));
+            self.end_line();
+        }
+        self.rows += 1;
+    }
+
+    fn finish(self) -> String {
+        self.out.trim_end().trim_start_matches('\n').to_string()
+    }
+}
@@ -0,0 +1,196 @@
+use chrono::prelude::*;
+use serde::Serialize;
+use serde_yaml::{Mapping, Value};
+
+/// The YAML header of the Markdown files notes are exported to:
+///
+/// ```text
+/// ---
+/// id: 3
+/// title: Meeting
+/// created_at: 2025-03-01T10:20:00Z
+/// updated_at: 2025-03-02T08:00:00Z
+/// tags:
+/// - work
+/// notebook: Projects / Rust
+/// ---
+///
+/// Review the plan
+/// ```
+///
+/// Headers written by other tools are read as well: `created`, `date`, `updated` and `modified`
+/// are taken as dates, dates without a time are taken at midnight UTC, and tags can be
+/// a list or a single string separated by commas or spaces.
+/// The `aliases` of Obsidian are read too, to resolve the links to a note.
+/// Locked notes are exported with `locked: true` and their sealed content.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FrontMatter {
+    /// ID of the exported note
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Full path of the notebook, e.g. `Projects / Rust`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notebook: Option<String>,
+
+    /// Other names the note can be linked with, as written by Obsidian
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+
+    /// Whether the content is sealed with a passphrase, see `NoteCipher`
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub locked: bool,
+}
+
+impl FrontMatter {
+    /// Delimiter of the header
+    pub const DELIMITER: &'static str = "---";
+
+    /// Writes the header followed by the content, ending with a line break.
+    ///
+    /// # Arguments
+    /// - `content`: The content of the note.
+    ///
+    /// # Returns
+    /// The text of the whole file.
+    pub fn render(&self, content: &str) -> Result<String, String> {
+        let header = serde_yaml::to_string(self).map_err(|err| format!("cannot write the front matter: {}", err))?;
+
+        let mut text = format!("{}\n{}{}\n\n{}", Self::DELIMITER, header, Self::DELIMITER, content);
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+
+        Ok(text)
+    }
+
+    /// Splits a file into its header and its content.
+    ///
+    /// # Arguments
+    /// - `text`: The text of the file.
+    ///
+    /// # Returns
+    /// - `Ok((Some(header), content))` if the file starts with a header.
+    /// - `Ok((None, text))` if it does not.
+    /// - `Err(String)` if the header is not closed or is not valid YAML.
+    pub fn parse(text: &str) -> Result<(Option<FrontMatter>, &str), String> {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let rest = match text
+            .strip_prefix(Self::DELIMITER)
+            .and_then(|rest| rest.strip_prefix("\r\n").or_else(|| rest.strip_prefix('\n')))
+        {
+            Some(rest) => rest,
+            None => return Ok((None, text)),
+        };
+
+        // The header ends at the first line holding only the delimiter
+        let mut offset = 0;
+        let mut end = None;
+        for line in rest.split_inclusive('\n') {
+            if line.trim_end() == Self::DELIMITER {
+                end = Some((offset, offset + line.len()));
+                break;
+            }
+            offset += line.len();
+        }
+
+        let (header_end, content_start) = end.ok_or_else(|| String::from("the front matter is not closed with `---`"))?;
+        let header: Value = serde_yaml::from_str(&rest[..header_end])
+            .map_err(|err| format!("the front matter is not valid YAML: {}", err))?;
+
+        let front_matter = match header {
+            Value::Mapping(mapping) => FrontMatter::from_mapping(&mapping),
+            Value::Null => FrontMatter::default(),
+            _ => return Err(String::from("the front matter is not a list of `key: value` pairs")),
+        };
+
+        Ok((Some(front_matter), &rest[content_start..]))
+    }
+
+    fn from_mapping(mapping: &Mapping) -> FrontMatter {
+        let get = |keys: &[&str]| keys.iter().find_map(|key| mapping.get(*key)).filter(|value| !value.is_null());
+
+        FrontMatter {
+            id: get(&["id"]).and_then(Value::as_i64),
+            title: get(&["title"]).and_then(FrontMatter::as_text),
+            created_at: get(&["created_at", "created", "date"]).and_then(FrontMatter::as_date),
+            updated_at: get(&["updated_at", "updated", "modified"]).and_then(FrontMatter::as_date),
+            tags: get(&["tags", "tag"]).map(FrontMatter::as_list).unwrap_or_default(),
+            notebook: get(&["notebook"]).and_then(FrontMatter::as_text),
+            aliases: get(&["aliases", "alias"]).map(FrontMatter::as_names).unwrap_or_default(),
+            locked: get(&["locked"]).and_then(Value::as_bool).unwrap_or(false),
+        }
+    }
+
+    /// Reads a scalar as text, `title: 2025` is a number for YAML.
+    fn as_text(value: &Value) -> Option<String> {
+        let text = match value {
+            Value::String(text) => text.clone(),
+            Value::Number(number) => number.to_string(),
+            Value::Bool(flag) => flag.to_string(),
+            _ => return None,
+        };
+
+        Some(text.trim().to_string()).filter(|text| !text.is_empty())
+    }
+
+    fn as_list(value: &Value) -> Vec<String> {
+        match value {
+            Value::Sequence(values) => values.iter().filter_map(FrontMatter::as_text).collect(),
+            value => FrontMatter::as_text(value)
+                .map(|text| {
+                    text.split(|c: char| c == ',' || c.is_whitespace())
+                        .filter(|tag| !tag.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Like `as_list`, but a single string is only split by commas, as names can hold spaces.
+    fn as_names(value: &Value) -> Vec<String> {
+        match value {
+            Value::Sequence(values) => values.iter().filter_map(FrontMatter::as_text).collect(),
+            value => FrontMatter::as_text(value)
+                .map(|text| {
+                    text.split(',')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn as_date(value: &Value) -> Option<DateTime<Utc>> {
+        let text = FrontMatter::as_text(value)?;
+
+        if let Ok(date) = DateTime::parse_from_rfc3339(&text) {
+            return Some(date.with_timezone(&Utc));
+        }
+        for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"] {
+            if let Ok(date) = NaiveDateTime::parse_from_str(&text, format) {
+                return Some(date.and_utc());
+            }
+        }
+
+        NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date| date.and_utc())
+    }
+}
@@ -1 +1,5 @@
-pub mod use_cases;
+pub mod enml;
+pub mod front_matter;
+pub mod note_cipher;
+pub mod palette;
+pub mod use_cases;
\ No newline at end of file
@@ -0,0 +1,234 @@
+use crate::domain::errors::note_error::NoteError;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{Engine, engine::general_purpose::STANDARD_NO_PAD};
+use chacha20poly1305::{
+    Key, KeyInit, XChaCha20Poly1305, XNonce,
+    aead::{Aead, AeadCore, OsRng, Payload, rand_core::RngCore},
+};
+
+/// Seals the content of locked notes with a passphrase.
+///
+/// The key is derived from the passphrase with Argon2id and a random salt, and the content
+/// is encrypted with XChaCha20-Poly1305 under a random nonce, so the same content sealed twice
+/// gives two different texts and any change to a sealed content is detected when opening it.
+/// The sealed content is a single line of text holding everything needed to open it again:
+///
+/// ```text
+/// notes-lock:1:m=19456,t=2,p=1:<salt>:<nonce>:<ciphertext>
+/// ```
+///
+/// The first three fields are authenticated along with the content, so the Argon2 cost
+/// cannot be lowered by editing them. Salt, nonce and ciphertext are encoded in base64.
+///
+/// The cost is read before the content can be authenticated, so it is capped: a damaged or
+/// tampered note asking for more than `MAX_MEMORY_COST` KiB, `MAX_TIME_COST` passes or
+/// `MAX_PARALLELISM` lanes is rejected without deriving any key.
+pub struct NoteCipher;
+
+impl NoteCipher {
+    /// Prefix of every sealed content, followed by the version of the format
+    const PREFIX: &'static str = "notes-lock";
+
+    /// Version of the format written by `seal`
+    const VERSION: u32 = 1;
+
+    /// Bytes of the random salt given to Argon2
+    const SALT_LENGTH: usize = 16;
+
+    /// Bytes of the derived key, the key size of XChaCha20-Poly1305
+    const KEY_LENGTH: usize = 32;
+
+    /// Highest Argon2 memory cost accepted when opening a note, in KiB (64 MiB, `seal` uses 19 MiB)
+    pub const MAX_MEMORY_COST: u32 = 64 * 1024;
+
+    /// Highest number of Argon2 passes accepted when opening a note (`seal` uses 2)
+    pub const MAX_TIME_COST: u32 = 8;
+
+    /// Highest number of Argon2 lanes accepted when opening a note (`seal` uses 1)
+    pub const MAX_PARALLELISM: u32 = 4;
+
+    /// Minimum number of characters of a passphrase used to lock a note
+    pub const MIN_PASSPHRASE_LENGTH: usize = 8;
+
+    /// Checks that a passphrase is long enough to lock a note.
+    ///
+    /// # Errors
+    /// Returns `NoteError::InvalidPassphrase` if it has fewer than `MIN_PASSPHRASE_LENGTH` characters
+    /// once the spaces around it are left out.
+    pub fn validate_passphrase(passphrase: &str) -> Result<(), NoteError> {
+        if passphrase.trim().chars().count() < NoteCipher::MIN_PASSPHRASE_LENGTH {
+            return Err(NoteError::InvalidPassphrase(format!(
+                "it needs at least {} characters",
+                NoteCipher::MIN_PASSPHRASE_LENGTH
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Encrypts a content with a passphrase.
+    ///
+    /// # Arguments
+    /// - `content`: The plain content of the note.
+    /// - `passphrase`: The passphrase, checked with `validate_passphrase` beforehand.
+    ///
+    /// # Returns
+    /// The sealed content, see the format above.
+    ///
+    /// # Example
+    /// ```
+    /// let sealed = NoteCipher::seal("The server password is hunter2", "correct horse battery")?;
+    /// assert_eq!(NoteCipher::open(&sealed, "correct horse battery")?, "The server password is hunter2");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `NoteError::Storage` if the key cannot be derived or the content encrypted.
+    pub fn seal(content: &str, passphrase: &str) -> Result<String, NoteError> {
+        let params = Params::default();
+        let header = NoteCipher::header(NoteCipher::VERSION, &params);
+
+        let mut salt = [0u8; NoteCipher::SALT_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+        let key = NoteCipher::derive_key(passphrase, &salt, params)?;
+
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = XChaCha20Poly1305::new(&key)
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: content.as_bytes(),
+                    aad: header.as_bytes(),
+                },
+            )
+            .map_err(|_| NoteError::Storage(String::from("cannot encrypt the note")))?;
+
+        Ok(format!(
+            "{}:{}:{}:{}",
+            header,
+            STANDARD_NO_PAD.encode(salt),
+            STANDARD_NO_PAD.encode(nonce),
+            STANDARD_NO_PAD.encode(ciphertext)
+        ))
+    }
+
+    /// Decrypts a content sealed by `seal`.
+    ///
+    /// # Arguments
+    /// - `sealed`: The sealed content.
+    /// - `passphrase`: The passphrase the content was sealed with.
+    ///
+    /// # Returns
+    /// The plain content.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::WrongPassphrase` if the passphrase does not open the content,
+    ///   or the content was changed after sealing it.
+    /// * Returns `NoteError::Storage` if the text is not a sealed content, or asks for an Argon2 cost
+    ///   above `MAX_MEMORY_COST`, `MAX_TIME_COST` or `MAX_PARALLELISM`.
+    pub fn open(sealed: &str, passphrase: &str) -> Result<String, NoteError> {
+        let malformed = || NoteError::Storage(String::from("the sealed content of the note is damaged"));
+
+        let fields: Vec<&str> = sealed.trim().split(':').collect();
+        let [prefix, version, cost, salt, nonce, ciphertext] = fields.as_slice() else {
+            return Err(malformed());
+        };
+        if *prefix != NoteCipher::PREFIX || version.parse::<u32>().ok() != Some(NoteCipher::VERSION) {
+            return Err(malformed());
+        }
+
+        let params = NoteCipher::parse_params(cost).ok_or_else(malformed)?;
+        if params.m_cost() > NoteCipher::MAX_MEMORY_COST
+            || params.t_cost() > NoteCipher::MAX_TIME_COST
+            || params.p_cost() > NoteCipher::MAX_PARALLELISM
+        {
+            return Err(NoteError::Storage(format!(
+                "the sealed content of the note asks for a key derivation cost ({}) above the limit, it may be damaged",
+                cost
+            )));
+        }
+        let salt = STANDARD_NO_PAD.decode(salt).map_err(|_| malformed())?;
+        let nonce = STANDARD_NO_PAD.decode(nonce).map_err(|_| malformed())?;
+        let ciphertext = STANDARD_NO_PAD.decode(ciphertext).map_err(|_| malformed())?;
+        if nonce.len() != 24 {
+            return Err(malformed());
+        }
+
+        let key = NoteCipher::derive_key(passphrase, &salt, params)?;
+        let header = format!("{}:{}:{}", prefix, version, cost);
+        let content = XChaCha20Poly1305::new(&key)
+            .decrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: header.as_bytes(),
+                },
+            )
+            .map_err(|_| NoteError::WrongPassphrase)?;
+
+        String::from_utf8(content).map_err(|_| malformed())
+    }
+
+    /// Tells whether a text looks like a content sealed by `seal`, without opening it.
+    pub fn is_sealed(text: &str) -> bool {
+        text.trim()
+            .strip_prefix(NoteCipher::PREFIX)
+            .is_some_and(|rest| rest.starts_with(':') && rest.split(':').count() == 6)
+    }
+
+    fn header(version: u32, params: &Params) -> String {
+        format!(
+            "{}:{}:m={},t={},p={}",
+            NoteCipher::PREFIX,
+            version,
+            params.m_cost(),
+            params.t_cost(),
+            params.p_cost()
+        )
+    }
+
+    /// Reads the Argon2 cost written as `m=19456,t=2,p=1`.
+    fn parse_params(cost: &str) -> Option<Params> {
+        let mut values = [None; 3];
+        for pair in cost.split(',') {
+            let (name, value) = pair.split_once('=')?;
+            let index = ["m", "t", "p"].iter().position(|known| *known == name)?;
+            values[index] = Some(value.parse::<u32>().ok()?);
+        }
+
+        let [Some(m_cost), Some(t_cost), Some(p_cost)] = values else {
+            return None;
+        };
+        Params::new(m_cost, t_cost, p_cost, Some(NoteCipher::KEY_LENGTH)).ok()
+    }
+
+    fn derive_key(passphrase: &str, salt: &[u8], params: Params) -> Result<Key, NoteError> {
+        let mut key = Key::default();
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+            .map_err(|err| NoteError::Storage(format!("cannot derive the key of the note: {}", err)))?;
+
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSPHRASE: &str = "correct horse battery";
+
+    #[test]
+    fn opens_what_it_seals() {
+        let sealed = NoteCipher::seal("The server password is hunter2", PASSPHRASE).unwrap();
+
+        assert_eq!(NoteCipher::open(&sealed, PASSPHRASE).unwrap(), "The server password is hunter2");
+    }
+
+    #[test]
+    fn rejects_a_cost_above_the_limit_without_deriving_the_key() {
+        let sealed = NoteCipher::seal("secret", PASSPHRASE).unwrap();
+        let tampered = sealed.replacen("m=19456", "m=4194304", 1);
+
+        assert!(matches!(NoteCipher::open(&tampered, PASSPHRASE), Err(NoteError::Storage(_))));
+    }
+}
@@ -0,0 +1,99 @@
+use ansi_term::{ANSIString, Colour, Style};
+use std::{borrow::Cow, sync::OnceLock};
+
+static PALETTE: OnceLock<Palette> = OnceLock::new();
+
+/// Colors used to print messages, configurable in the `[colors]` section of the settings.
+///
+/// The use cases and every front end print, so the palette is installed once at startup
+/// instead of being handed to each of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    /// Whether anything is colored at all
+    pub enabled: bool,
+
+    /// Indicator of operations that succeeded
+    pub success: Colour,
+
+    /// Indicator of canceled operations and invalid input
+    pub warning: Colour,
+
+    /// Indicator of failures
+    pub error: Colour,
+
+    /// IDs and other values worth noticing
+    pub accent: Colour,
+
+    /// Search terms found in the snippets, shown in bold
+    pub highlight: Colour,
+
+    /// Tags and diff hunk headers
+    pub secondary: Colour,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette {
+            enabled: true,
+            success: Colour::Green,
+            warning: Colour::Yellow,
+            error: Colour::Red,
+            accent: Colour::Blue,
+            highlight: Colour::Yellow,
+            secondary: Colour::Cyan,
+        }
+    }
+}
+
+impl Palette {
+    /// Sets the palette used by the whole application. Only the first call has any effect.
+    ///
+    /// # Arguments
+    /// - `palette`: The palette read from the settings.
+    pub fn install(palette: Palette) {
+        let _ = PALETTE.set(palette);
+    }
+
+    /// Returns the installed palette, or the default one if none was installed.
+    pub fn current() -> &'static Palette {
+        PALETTE.get_or_init(Palette::default)
+    }
+
+    pub fn success<'a>(&self, text: impl Into<Cow<'a, str>>) -> ANSIString<'a> {
+        self.paint(self.success.normal(), text)
+    }
+
+    pub fn warning<'a>(&self, text: impl Into<Cow<'a, str>>) -> ANSIString<'a> {
+        self.paint(self.warning.normal(), text)
+    }
+
+    pub fn error<'a>(&self, text: impl Into<Cow<'a, str>>) -> ANSIString<'a> {
+        self.paint(self.error.normal(), text)
+    }
+
+    pub fn accent<'a>(&self, text: impl Into<Cow<'a, str>>) -> ANSIString<'a> {
+        self.paint(self.accent.normal(), text)
+    }
+
+    pub fn highlight<'a>(&self, text: impl Into<Cow<'a, str>>) -> ANSIString<'a> {
+        self.paint(self.highlight.bold(), text)
+    }
+
+    pub fn secondary<'a>(&self, text: impl Into<Cow<'a, str>>) -> ANSIString<'a> {
+        self.paint(self.secondary.normal(), text)
+    }
+
+    /// Titles and other headings.
+    pub fn bold<'a>(&self, text: impl Into<Cow<'a, str>>) -> ANSIString<'a> {
+        self.paint(Style::new().bold(), text)
+    }
+
+    /// Paints `text` with `style`, or leaves it plain when colors are disabled.
+    fn paint<'a>(&self, style: Style, text: impl Into<Cow<'a, str>>) -> ANSIString<'a> {
+        if self.enabled {
+            style.paint(text)
+        } else {
+            Style::new().paint(text)
+        }
+    }
+}
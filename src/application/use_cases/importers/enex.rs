@@ -0,0 +1,385 @@
+use crate::application::enml::Enml;
+use crate::application::use_cases::{
+    importers::{ForeignNote, ImportProblem},
+    notebooks::create::CreateNotebook,
+};
+use crate::domain::entities::{note::NoteLimits, notebook::Notebook};
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::{note_repository::NoteRepository, notebook_repository::NotebookRepository};
+use chrono::prelude::*;
+use quick_xml::{Reader, events::Event};
+use std::{
+    fs::{self, File},
+    io::BufReader,
+    mem,
+    path::{Path, PathBuf},
+};
+
+/// A note of an ENEX file that was saved.
+#[derive(Debug, Clone)]
+pub struct EnexNote {
+    pub path: PathBuf,
+    pub title: String,
+    pub id: i64,
+}
+
+/// An attachment of a saved note that was left out, as notes cannot hold files.
+#[derive(Debug, Clone)]
+pub struct SkippedResource {
+    pub path: PathBuf,
+
+    /// Title of the note holding it
+    pub note: String,
+
+    /// File name of the attachment or, when it has none, its MIME type
+    pub name: String,
+}
+
+/// A note, or a whole file when `title` is `None`, that could not be imported.
+#[derive(Debug, Clone)]
+pub struct EnexFailure {
+    pub path: PathBuf,
+    pub title: Option<String>,
+    pub problem: ImportProblem,
+}
+
+/// What happened while importing ENEX files.
+#[derive(Debug, Clone, Default)]
+pub struct EnexReport {
+    pub notes: Vec<EnexNote>,
+
+    /// Number of notebooks created for the files
+    pub notebooks_created: usize,
+
+    pub failures: Vec<EnexFailure>,
+    pub skipped: Vec<SkippedResource>,
+}
+
+/// A note read from an ENEX file, waiting to be saved.
+#[derive(Debug, Default)]
+struct EnexEntry {
+    title: String,
+    content: String,
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+    tags: Vec<String>,
+
+    /// Names of the attachments, see `SkippedResource::name`
+    resources: Vec<String>,
+}
+
+/// An attachment being read.
+#[derive(Debug, Default)]
+struct EnexResource {
+    mime: String,
+    file_name: Option<String>,
+}
+
+pub struct ImportEnex<'a> {
+    note_repository: &'a dyn NoteRepository,
+    notebook_repository: &'a dyn NotebookRepository,
+    limits: NoteLimits,
+}
+
+///
+/// The `ImportEnex` struct provides a use case for creating notes from the `.enex` files exported by Evernote.
+///
+/// Evernote exports a notebook per file, so the notes of every file go to the notebook named like it,
+/// created if no notebook at the top level has that name. The content, written in ENML, is converted
+/// to Markdown, the tags are attached (their spaces replaced by `-`) and the notes keep the dates
+/// they had in Evernote instead of the time of the import.
+///
+/// Files are read one note at a time, so large exports are not loaded in memory, and the
+/// attachments are left out: a placeholder stays in the content and they are reported as skipped.
+///
+impl<'a> ImportEnex<'a> {
+    /// Extension of the files exported by Evernote
+    const EXTENSION: &'static str = "enex";
+
+    /// Format of the dates in the files, e.g. `20240131T094500Z`
+    const DATE_FORMAT: &'static str = "%Y%m%dT%H%M%SZ";
+
+    ///
+    /// Creates a new instance of `ImportEnex`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: The repository the notes are saved to.
+    /// * `notebook_repository`: The repository the notebooks are read from and saved to.
+    /// * `limits`: The maximum lengths of the title and content, from the settings.
+    ///
+    /// # Returns
+    /// A new `ImportEnex` instance.
+    ///
+    pub fn new(
+        note_repository: &'a dyn NoteRepository,
+        notebook_repository: &'a dyn NotebookRepository,
+        limits: NoteLimits,
+    ) -> Self {
+        ImportEnex {
+            note_repository,
+            notebook_repository,
+            limits,
+        }
+    }
+
+    ///
+    /// Executes the use case to import ENEX files.
+    ///
+    /// # Arguments
+    /// * `path`: An `.enex` file, or a directory holding several of them.
+    ///
+    /// # Returns
+    /// * `Ok(EnexReport)`: The saved notes, the notes that failed and the attachments left out.
+    /// * `Err(NoteError)`: If there is nothing to import or a note cannot be saved.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::Storage` if the directory cannot be read or holds no `.enex` file,
+    ///   or if the database fails while saving. The notes saved before the failure are kept.
+    ///   Problems with single notes and files are part of the report instead.
+    ///
+    pub fn execute(&self, path: &Path) -> Result<EnexReport, NoteError> {
+        let files = ImportEnex::find_files(path)?;
+
+        let mut report = EnexReport::default();
+        for file in files {
+            if let Err(reason) = self.read_file(&file, &mut report)? {
+                report.failures.push(EnexFailure {
+                    path: file,
+                    title: None,
+                    problem: ImportProblem::Unreadable(reason),
+                });
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// The file itself, or the `.enex` files of a directory sorted by name.
+    fn find_files(path: &Path) -> Result<Vec<PathBuf>, NoteError> {
+        if !path.is_dir() {
+            return Ok(vec![path.to_path_buf()]);
+        }
+
+        let mut files: Vec<PathBuf> = fs::read_dir(path)
+            .map_err(|err| NoteError::Storage(format!("cannot read {}: {}", path.display(), err)))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|file| {
+                file.is_file()
+                    && file
+                        .extension()
+                        .is_some_and(|extension| extension.eq_ignore_ascii_case(Self::EXTENSION))
+            })
+            .collect();
+        files.sort();
+
+        if files.is_empty() {
+            return Err(NoteError::Storage(format!("{} holds no .{} file", path.display(), Self::EXTENSION)));
+        }
+
+        Ok(files)
+    }
+
+    /// Reads a file note by note, saving each one as soon as it ends.
+    ///
+    /// # Returns
+    /// - `Ok(Ok(()))` once the whole file is read.
+    /// - `Ok(Err(reason))` if the file cannot be read or is not valid XML, the notes before the problem are kept.
+    /// - `Err(NoteError)` if a note cannot be saved.
+    fn read_file(&self, path: &Path, report: &mut EnexReport) -> Result<Result<(), String>, NoteError> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) => return Ok(Err(format!("cannot read the file: {}", err))),
+        };
+        let mut reader = Reader::from_reader(BufReader::new(file));
+
+        let mut notebook_id = None;
+        let mut buffer = Vec::new();
+        let mut skipped = Vec::new();
+        let mut elements: Vec<Vec<u8>> = Vec::new();
+        let mut text = String::new();
+        let mut entry: Option<EnexEntry> = None;
+        let mut resource: Option<EnexResource> = None;
+
+        loop {
+            buffer.clear();
+            let event = match reader.read_event_into(&mut buffer) {
+                Ok(event) => event,
+                Err(err) => return Ok(Err(format!("not a valid ENEX file: {}", err))),
+            };
+
+            match event {
+                Event::Start(element) => {
+                    let name = element.local_name().as_ref().to_vec();
+                    match name.as_slice() {
+                        b"note" => entry = Some(EnexEntry::default()),
+                        b"resource" => resource = Some(EnexResource::default()),
+                        // The encoded attachments are the largest part of a file, they are skipped unread
+                        b"data" | b"recognition" | b"alternate-data" => {
+                            skipped.clear();
+                            if let Err(err) = reader.read_to_end_into(element.name(), &mut skipped) {
+                                return Ok(Err(format!("not a valid ENEX file: {}", err)));
+                            }
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    elements.push(name);
+                    text.clear();
+                }
+                Event::Text(content) => match content.unescape() {
+                    Ok(content) => text.push_str(&content),
+                    Err(err) => return Ok(Err(format!("not a valid ENEX file: {}", err))),
+                },
+                Event::CData(content) => text.push_str(&String::from_utf8_lossy(&content.into_inner())),
+                Event::End(_) => {
+                    let name = elements.pop().unwrap_or_default();
+                    let parent = elements.last().map(Vec::as_slice).unwrap_or_default();
+                    let value = mem::take(&mut text);
+
+                    match (parent, name.as_slice()) {
+                        (b"note", b"title") => Self::entry(&mut entry).title = value.trim().to_string(),
+                        (b"note", b"content") => Self::entry(&mut entry).content = value,
+                        (b"note", b"created") => Self::entry(&mut entry).created_at = ImportEnex::date(&value),
+                        (b"note", b"updated") => Self::entry(&mut entry).updated_at = ImportEnex::date(&value),
+                        (b"note", b"tag") => Self::entry(&mut entry).tags.push(value.trim().to_string()),
+                        (b"resource", b"mime") => {
+                            resource.get_or_insert_with(EnexResource::default).mime = value.trim().to_string()
+                        }
+                        (b"resource-attributes", b"file-name") => {
+                            resource.get_or_insert_with(EnexResource::default).file_name =
+                                Some(value.trim().to_string()).filter(|name| !name.is_empty())
+                        }
+                        (_, b"resource") => {
+                            if let Some(resource) = resource.take() {
+                                Self::entry(&mut entry).resources.push(resource.file_name.unwrap_or(resource.mime));
+                            }
+                        }
+                        (_, b"note") => {
+                            if let Some(entry) = entry.take() {
+                                self.save(path, entry, &mut notebook_id, report)?;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn entry(entry: &mut Option<EnexEntry>) -> &mut EnexEntry {
+        entry.get_or_insert_with(EnexEntry::default)
+    }
+
+    /// Saves a note read from a file, creating the notebook of the file with the first one.
+    fn save(
+        &self,
+        path: &Path,
+        entry: EnexEntry,
+        notebook_id: &mut Option<i64>,
+        report: &mut EnexReport,
+    ) -> Result<(), NoteError> {
+        let title = Some(entry.title)
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| ForeignNote::UNTITLED.to_string());
+        let fail = |problem: ImportProblem| EnexFailure {
+            path: path.to_path_buf(),
+            title: Some(title.clone()),
+            problem,
+        };
+
+        let content = match Enml::to_markdown(&entry.content) {
+            Ok(content) => content,
+            Err(reason) => {
+                report.failures.push(fail(ImportProblem::Unreadable(reason)));
+                return Ok(());
+            }
+        };
+
+        let foreign = ForeignNote {
+            title: title.clone(),
+            content,
+            tags: entry.tags,
+            created_at: entry.created_at,
+            updated_at: entry.updated_at,
+            ..ForeignNote::default()
+        };
+        let note = match foreign.to_note(&self.limits) {
+            Ok(note) => note,
+            Err(err) => {
+                report.failures.push(fail(ImportProblem::Invalid(err)));
+                return Ok(());
+            }
+        };
+
+        let notebook = match notebook_id {
+            Some(id) => *id,
+            None => {
+                let id = match self.notebook_for(path, report) {
+                    Ok(id) => id,
+                    Err(err) => {
+                        report.failures.push(EnexFailure {
+                            path: path.to_path_buf(),
+                            title: None,
+                            problem: ImportProblem::Invalid(err),
+                        });
+                        Notebook::INBOX_ID
+                    }
+                };
+                *notebook_id = Some(id);
+                id
+            }
+        };
+
+        let id = self
+            .note_repository
+            .save_with_timestamps(&note.with_notebook(notebook))
+            .map_err(NoteError::Storage)?;
+
+        for name in entry.resources {
+            report.skipped.push(SkippedResource {
+                path: path.to_path_buf(),
+                note: title.clone(),
+                name,
+            });
+        }
+        report.notes.push(EnexNote {
+            path: path.to_path_buf(),
+            title,
+            id,
+        });
+
+        Ok(())
+    }
+
+    /// Finds or creates the notebook at the top level named like the file.
+    /// When it cannot be created, the notes of the file go to the default notebook.
+    fn notebook_for(&self, path: &Path, report: &mut EnexReport) -> Result<i64, NoteError> {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy().trim().to_string();
+
+        let notebooks = self.notebook_repository.find_all_notebooks().map_err(NoteError::Storage)?;
+        let existing = notebooks.iter().find(|notebook| {
+            notebook.get_parent_id().is_none() && notebook.get_name().to_lowercase() == name.to_lowercase()
+        });
+        if let Some(id) = existing.and_then(|notebook| notebook.get_id()) {
+            return Ok(id);
+        }
+
+        let notebook = CreateNotebook::new(self.notebook_repository).execute(&name, None)?;
+        report.notebooks_created += 1;
+
+        notebook
+            .get_id()
+            .ok_or_else(|| NoteError::Storage(format!("the notebook {} was not saved", name)))
+    }
+
+    fn date(text: &str) -> Option<DateTime<Utc>> {
+        NaiveDateTime::parse_from_str(text.trim(), Self::DATE_FORMAT)
+            .ok()
+            .map(|date| date.and_utc())
+    }
+}
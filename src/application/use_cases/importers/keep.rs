@@ -0,0 +1,210 @@
+use crate::application::use_cases::importers::{ForeignNote, ImportProblem, ImportReport};
+use crate::domain::entities::note::NoteLimits;
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::note_repository::NoteRepository;
+use chrono::prelude::*;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A note as written by Google Takeout, one JSON file per note.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct KeepNote {
+    title: String,
+    text_content: String,
+
+    /// Items of a checklist, the content of the note when it is one
+    list_content: Vec<KeepListItem>,
+
+    labels: Vec<KeepLabel>,
+
+    /// Links Keep found in the note
+    annotations: Vec<KeepAnnotation>,
+
+    is_pinned: bool,
+    is_archived: bool,
+    is_trashed: bool,
+
+    /// Microseconds since the Unix epoch
+    created_timestamp_usec: i64,
+    user_edited_timestamp_usec: i64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct KeepListItem {
+    text: String,
+    is_checked: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct KeepLabel {
+    name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct KeepAnnotation {
+    title: String,
+    url: String,
+}
+
+pub struct ImportKeepNotes<'a> {
+    note_repository: &'a dyn NoteRepository,
+    limits: NoteLimits,
+}
+
+///
+/// The `ImportKeepNotes` struct provides a use case for creating notes from a Google Keep export of Google Takeout,
+/// the `Keep` directory holding a `.json` file per note.
+///
+/// Checklists become Markdown task lists (`- [x] Milk`), labels become tags and the notes keep
+/// the dates they had in Keep. Notes have no pinned or archived state, so those notes get
+/// the `pinned` and `archived` tags instead; the notes in the trash of Keep are moved to the trash.
+/// The links Keep found in a note are added at its end, attachments are not imported.
+///
+impl<'a> ImportKeepNotes<'a> {
+    /// Tag of the notes pinned in Keep
+    pub const PINNED_TAG: &'static str = "pinned";
+
+    /// Tag of the notes archived in Keep
+    pub const ARCHIVED_TAG: &'static str = "archived";
+
+    ///
+    /// Creates a new instance of `ImportKeepNotes`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: The repository the notes are saved to.
+    /// * `limits`: The maximum lengths of the title and content, from the settings.
+    ///
+    /// # Returns
+    /// A new `ImportKeepNotes` instance.
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository, limits: NoteLimits) -> Self {
+        ImportKeepNotes { note_repository, limits }
+    }
+
+    ///
+    /// Executes the use case to import a Keep export.
+    ///
+    /// # Arguments
+    /// * `path`: The directory holding the `.json` files, or a single one.
+    /// * `notebook_id`: The notebook the notes are saved to, as Keep has no notebooks.
+    ///
+    /// # Returns
+    /// * `Ok(ImportReport)`: The saved notes and the files that failed.
+    /// * `Err(NoteError)`: If there is nothing to import or a note cannot be saved.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::Storage` if the directory cannot be read or holds no `.json` file,
+    ///   or if the database fails while saving. The notes saved before the failure are kept.
+    ///
+    pub fn execute(&self, path: &Path, notebook_id: i64) -> Result<ImportReport, NoteError> {
+        let mut report = ImportReport::default();
+
+        for file in ImportKeepNotes::find_files(path)? {
+            let source = file.display().to_string();
+            let keep_note = fs::read_to_string(&file)
+                .map_err(|err| format!("cannot read the file: {}", err))
+                .and_then(|text| {
+                    serde_json::from_str::<KeepNote>(&text).map_err(|err| format!("not a Keep note: {}", err))
+                });
+
+            match keep_note {
+                Ok(keep_note) => {
+                    let foreign = self.foreign_note(keep_note, notebook_id);
+                    report.save(self.note_repository, &self.limits, source, &foreign)?;
+                }
+                Err(reason) => report.fail(source, ImportProblem::Unreadable(reason)),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// The file itself, or the `.json` files of a directory sorted by name.
+    fn find_files(path: &Path) -> Result<Vec<PathBuf>, NoteError> {
+        if !path.is_dir() {
+            return Ok(vec![path.to_path_buf()]);
+        }
+
+        let mut files: Vec<PathBuf> = fs::read_dir(path)
+            .map_err(|err| NoteError::Storage(format!("cannot read {}: {}", path.display(), err)))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|file| file.is_file() && file.extension().is_some_and(|extension| extension == "json"))
+            .collect();
+        files.sort();
+
+        if files.is_empty() {
+            return Err(NoteError::Storage(format!("{} holds no .json file", path.display())));
+        }
+
+        Ok(files)
+    }
+
+    fn foreign_note(&self, keep_note: KeepNote, notebook_id: i64) -> ForeignNote {
+        // A note without title is named after its first line, as Keep shows it
+        let first_line = match keep_note.list_content.first() {
+            Some(item) => item.text.trim(),
+            None => keep_note.text_content.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default(),
+        };
+        let title = Some(keep_note.title.trim())
+            .filter(|title| !title.is_empty())
+            .unwrap_or(first_line)
+            .chars()
+            .take(self.limits.max_title_length)
+            .collect::<String>();
+
+        let mut content = if keep_note.list_content.is_empty() {
+            keep_note.text_content.trim().to_string()
+        } else {
+            keep_note
+                .list_content
+                .iter()
+                .map(|item| format!("- [{}] {}", if item.is_checked { "x" } else { " " }, item.text.trim()))
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+
+        for annotation in keep_note.annotations.iter().filter(|annotation| !annotation.url.is_empty()) {
+            if content.contains(&annotation.url) {
+                continue;
+            }
+            let title = Some(annotation.title.trim()).filter(|title| !title.is_empty());
+            let link = match title {
+                Some(title) => format!("[{}]({})", title, annotation.url),
+                None => format!("<{}>", annotation.url),
+            };
+            content = format!("{}\n\n{}", content, link);
+        }
+
+        let mut tags: Vec<String> = keep_note.labels.into_iter().map(|label| label.name).collect();
+        if keep_note.is_pinned {
+            tags.push(Self::PINNED_TAG.to_string());
+        }
+        if keep_note.is_archived {
+            tags.push(Self::ARCHIVED_TAG.to_string());
+        }
+
+        ForeignNote {
+            title,
+            content,
+            tags,
+            created_at: ImportKeepNotes::date(keep_note.created_timestamp_usec),
+            updated_at: ImportKeepNotes::date(keep_note.user_edited_timestamp_usec),
+            trashed: keep_note.is_trashed,
+            notebook_id,
+        }
+    }
+
+    fn date(microseconds: i64) -> Option<DateTime<Utc>> {
+        Some(microseconds)
+            .filter(|microseconds| *microseconds > 0)
+            .and_then(DateTime::from_timestamp_micros)
+    }
+}
@@ -0,0 +1,138 @@
+pub mod enex;
+pub mod keep;
+pub mod obsidian;
+pub mod simplenote;
+
+use crate::domain::entities::note::{Note, NoteLimits};
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::note_repository::NoteRepository;
+use chrono::prelude::*;
+
+/// Why a note, or a whole file, of an export could not be imported.
+#[derive(Debug, Clone)]
+pub enum ImportProblem {
+    /// The note breaks a rule of the `Note` entity, e.g. a content longer than the limit
+    Invalid(NoteError),
+
+    /// The file cannot be read or is not in the format of the application
+    Unreadable(String),
+}
+
+/// A note of an export that was saved.
+#[derive(Debug, Clone)]
+pub struct ImportedNote {
+    /// Where the note comes from, e.g. the file holding it
+    pub source: String,
+
+    pub title: String,
+    pub id: i64,
+
+    /// Whether it was moved to the trash, as it was in the trash of the application
+    pub trashed: bool,
+}
+
+/// A note of an export, or a whole file, that could not be imported.
+#[derive(Debug, Clone)]
+pub struct FailedImport {
+    pub source: String,
+    pub problem: ImportProblem,
+}
+
+/// What happened while importing the export of an application that keeps notes in JSON.
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub notes: Vec<ImportedNote>,
+
+    /// Number of saved notes moved to the trash, as they were in the trash of the application
+    pub trashed: usize,
+
+    pub failures: Vec<FailedImport>,
+}
+
+impl ImportReport {
+    /// Records a note or a file that could not be imported.
+    pub fn fail(&mut self, source: impl Into<String>, problem: ImportProblem) {
+        self.failures.push(FailedImport {
+            source: source.into(),
+            problem,
+        });
+    }
+
+    /// Saves a note read from an export, keeping its dates, and records what happened.
+    ///
+    /// # Errors
+    /// Returns `NoteError::Storage` if the database fails, a note breaking the rules
+    /// of the `Note` entity is recorded as a failure instead.
+    pub fn save(
+        &mut self,
+        note_repository: &dyn NoteRepository,
+        limits: &NoteLimits,
+        source: impl Into<String>,
+        foreign: &ForeignNote,
+    ) -> Result<(), NoteError> {
+        let source = source.into();
+        let note = match foreign.to_note(limits) {
+            Ok(note) => note,
+            Err(err) => {
+                self.fail(source, ImportProblem::Invalid(err));
+                return Ok(());
+            }
+        };
+
+        let id = note_repository.save_with_timestamps(&note).map_err(NoteError::Storage)?;
+        if foreign.trashed {
+            note_repository.delete(id).map_err(NoteError::Storage)?;
+            self.trashed += 1;
+        }
+
+        self.notes.push(ImportedNote {
+            source,
+            title: note.get_title().clone(),
+            id,
+            trashed: foreign.trashed,
+        });
+        Ok(())
+    }
+}
+
+/// A note read from the export of another application, waiting to be saved.
+#[derive(Debug, Clone, Default)]
+pub struct ForeignNote {
+    pub title: String,
+    pub content: String,
+
+    /// Names of the tags as written by the application, spaces are replaced by `-`
+    pub tags: Vec<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// Whether it was in the trash of the application
+    pub trashed: bool,
+
+    pub notebook_id: i64,
+}
+
+impl ForeignNote {
+    /// Title of the notes exported without one
+    pub const UNTITLED: &'static str = "Untitled note";
+
+    /// Builds the note, checking the rules of the `Note` entity. A note without dates
+    /// is dated now, and one without an update date was never updated.
+    fn to_note(&self, limits: &NoteLimits) -> Result<Note, NoteError> {
+        let title = Some(self.title.trim().to_string())
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| Self::UNTITLED.to_string());
+
+        let mut note = Note::create(&title, &self.content.trim().to_string(), limits)?;
+        for tag in &self.tags {
+            note.add_tag(&tag.split_whitespace().collect::<Vec<&str>>().join("-"))?;
+        }
+
+        let created_at = self.created_at.unwrap_or_else(Utc::now);
+        note.set_created_at(created_at);
+        note.set_updated_at(self.updated_at.unwrap_or(created_at).max(created_at));
+
+        Ok(note.with_notebook(self.notebook_id))
+    }
+}
@@ -0,0 +1,477 @@
+use crate::application::front_matter::FrontMatter;
+use crate::application::use_cases::{notebooks::create::CreateNotebook, notes::create::CreateNewNote};
+use crate::domain::entities::{
+    note::{Note, NoteLimits},
+    tag::Tag,
+};
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::{note_repository::NoteRepository, notebook_repository::NotebookRepository};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    path::{Path, PathBuf},
+};
+use walkdir::WalkDir;
+
+/// A note of the vault that was created.
+#[derive(Debug, Clone)]
+pub struct VaultNote {
+    pub path: PathBuf,
+    pub title: String,
+    pub id: i64,
+}
+
+/// A `[[wikilink]]` or `![[embed]]` that does not point to an imported note.
+#[derive(Debug, Clone)]
+pub struct UnresolvedLink {
+    /// File holding the link
+    pub path: PathBuf,
+
+    /// The link as written, e.g. `[[Missing note|alias]]`
+    pub link: String,
+
+    /// Why it was not resolved
+    pub reason: String,
+}
+
+/// What happened while importing a vault.
+#[derive(Debug, Clone, Default)]
+pub struct VaultReport {
+    pub notes: Vec<VaultNote>,
+
+    /// Number of notebooks created for the folders of the vault
+    pub notebooks_created: usize,
+
+    /// Files that could not become a note, and folders that could not become a notebook
+    pub failures: Vec<(PathBuf, NoteError)>,
+
+    /// Links left as they were written
+    pub unresolved: Vec<UnresolvedLink>,
+}
+
+/// A Markdown file of the vault, read and waiting to be created.
+struct VaultFile {
+    path: PathBuf,
+
+    /// Path from the root of the vault, without the extension, e.g. `Projects/Plan`
+    key: String,
+
+    front_matter: FrontMatter,
+    body: String,
+}
+
+pub struct ImportObsidianVault<'a> {
+    note_repository: &'a dyn NoteRepository,
+    notebook_repository: &'a dyn NotebookRepository,
+    limits: NoteLimits,
+}
+
+///
+/// The `ImportObsidianVault` struct provides a use case for creating notes from an Obsidian vault.
+///
+/// Every Markdown file becomes a note titled after its name, created through `CreateNewNote`.
+/// Folders become notebooks nested like them, reusing the notebooks that already exist
+/// (the files at the root of the vault go to the default notebook). The tags of the front matter
+/// and the inline `#tags` are attached to the notes.
+///
+/// Once every note exists, `[[wikilinks]]` and `![[embeds]]` are replaced by Markdown links
+/// to the note they point to, e.g. `[[Plan|the plan]]` becomes `[the plan](note:12)`.
+/// Links to a file that is not a note, or to no file at all, are kept as they were and reported.
+/// The hidden folders, such as `.obsidian` and `.trash`, are left out.
+///
+impl<'a> ImportObsidianVault<'a> {
+    ///
+    /// Creates a new instance of `ImportObsidianVault`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: The repository the notes are saved to.
+    /// * `notebook_repository`: The repository the notebooks are read from and saved to.
+    /// * `limits`: The maximum lengths of the title and content, from the settings.
+    ///
+    /// # Returns
+    /// A new `ImportObsidianVault` instance.
+    ///
+    pub fn new(
+        note_repository: &'a dyn NoteRepository,
+        notebook_repository: &'a dyn NotebookRepository,
+        limits: NoteLimits,
+    ) -> Self {
+        ImportObsidianVault {
+            note_repository,
+            notebook_repository,
+            limits,
+        }
+    }
+
+    ///
+    /// Executes the use case to import a vault.
+    ///
+    /// # Arguments
+    /// * `vault`: The root directory of the vault.
+    /// * `notebook_id`: The notebook of the files at the root of the vault.
+    ///
+    /// # Returns
+    /// * `Ok(VaultReport)`: The created notes, the files that failed and the unresolved links.
+    /// * `Err(NoteError)`: If the vault cannot be read.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::Storage` if the vault cannot be read or the notebooks cannot be listed.
+    ///   Problems with single files and folders are part of the report instead.
+    ///
+    pub fn execute(&self, vault: &Path, notebook_id: i64) -> Result<VaultReport, NoteError> {
+        if !vault.is_dir() {
+            return Err(NoteError::Storage(format!("{} is not a directory", vault.display())));
+        }
+
+        let mut report = VaultReport::default();
+        let (files, attachments) = self.read_vault(vault, &mut report)?;
+
+        // Notes are created first, so every link can be resolved afterwards
+        let mut folders: HashMap<String, i64> = HashMap::new();
+        let mut created: Vec<(Note, &VaultFile)> = Vec::new();
+        for file in &files {
+            let title = file.key.rsplit('/').next().unwrap_or_default().to_string();
+
+            // Checked before creating the notebook, so no notebook is left empty
+            if let Err(err) = Note::create(&title, &file.body, &self.limits) {
+                report.failures.push((file.path.clone(), err));
+                continue;
+            }
+
+            let folder = file.key.rsplit_once('/').map(|(folder, _)| folder).unwrap_or_default();
+            let notebook = match self.notebook_for(folder, notebook_id, &mut folders, &mut report) {
+                Ok(notebook) => notebook,
+                Err(err) => {
+                    report.failures.push((vault.join(folder), err));
+                    notebook_id
+                }
+            };
+
+            let tags = ImportObsidianVault::tags(file);
+
+            match CreateNewNote::new(self.note_repository, self.limits).execute(&title, &file.body, &tags, notebook) {
+                Ok(note) => created.push((note, file)),
+                Err(err) => report.failures.push((file.path.clone(), err)),
+            }
+        }
+
+        let index = ImportObsidianVault::index(&created);
+        for (mut note, file) in created {
+            let id = note.get_id().unwrap_or_default();
+            let title = note.get_title().clone();
+            let content = self.convert_links(file, &index, &attachments, &mut report);
+
+            if content != *note.get_content() {
+                let updated = note
+                    .set_content(content, &self.limits)
+                    .and_then(|_| self.note_repository.update(note).map_err(NoteError::Storage));
+                if let Err(err) = updated {
+                    report.failures.push((file.path.clone(), err));
+                }
+            }
+
+            report.notes.push(VaultNote {
+                path: file.path.clone(),
+                title,
+                id,
+            });
+        }
+
+        Ok(report)
+    }
+
+    /// Reads every Markdown file of the vault and lists the rest of the files, which can be embedded.
+    fn read_vault(&self, vault: &Path, report: &mut VaultReport) -> Result<(Vec<VaultFile>, BTreeSet<String>), NoteError> {
+        let mut files = Vec::new();
+        let mut attachments = BTreeSet::new();
+
+        let entries = WalkDir::new(vault)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.'));
+
+        for entry in entries {
+            let entry = entry.map_err(|err| NoteError::Storage(format!("cannot read {}: {}", vault.display(), err)))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let relative = entry
+                .path()
+                .strip_prefix(vault)
+                .unwrap_or(entry.path())
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            let is_markdown = entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("md"));
+            if !is_markdown {
+                attachments.insert(relative.to_lowercase());
+                continue;
+            }
+
+            let parsed = fs::read_to_string(entry.path())
+                .map_err(|err| format!("cannot read the file: {}", err))
+                .and_then(|text| {
+                    FrontMatter::parse(&text)
+                        .map(|(front_matter, body)| (front_matter.unwrap_or_default(), body.trim().to_string()))
+                });
+
+            match parsed {
+                Ok((front_matter, body)) => files.push(VaultFile {
+                    path: entry.path().to_path_buf(),
+                    key: relative[..relative.len() - ".md".len()].to_string(),
+                    front_matter,
+                    body,
+                }),
+                Err(reason) => report.failures.push((entry.path().to_path_buf(), NoteError::Storage(reason))),
+            }
+        }
+
+        Ok((files, attachments))
+    }
+
+    /// Finds or creates the notebooks of a folder and its parents, e.g. `Projects/Rust`.
+    fn notebook_for(
+        &self,
+        folder: &str,
+        root: i64,
+        folders: &mut HashMap<String, i64>,
+        report: &mut VaultReport,
+    ) -> Result<i64, NoteError> {
+        if folder.is_empty() {
+            return Ok(root);
+        }
+        if let Some(id) = folders.get(folder) {
+            return Ok(*id);
+        }
+
+        let (parent, name) = match folder.rsplit_once('/') {
+            Some((parent, name)) => (Some(self.notebook_for(parent, root, folders, report)?), name),
+            None => (None, folder),
+        };
+
+        let notebooks = self.notebook_repository.find_all_notebooks().map_err(NoteError::Storage)?;
+        let existing = notebooks.iter().find(|notebook| {
+            notebook.get_parent_id() == parent && notebook.get_name().to_lowercase() == name.trim().to_lowercase()
+        });
+
+        let id = match existing.and_then(|notebook| notebook.get_id()) {
+            Some(id) => id,
+            None => {
+                let notebook = CreateNotebook::new(self.notebook_repository).execute(name, parent)?;
+                report.notebooks_created += 1;
+                notebook.get_id().unwrap_or(root)
+            }
+        };
+
+        folders.insert(folder.to_string(), id);
+        Ok(id)
+    }
+
+    /// The tags of the front matter and the inline `#tags` of the body, leaving out the invalid ones.
+    fn tags(file: &VaultFile) -> Vec<String> {
+        let mut tags = BTreeSet::new();
+        let inline = ImportObsidianVault::outside_code(&file.body)
+            .into_iter()
+            .flat_map(ImportObsidianVault::inline_tags);
+
+        for tag in file.front_matter.tags.iter().cloned().chain(inline) {
+            if let Ok(tag) = Tag::normalize(&tag) {
+                tags.insert(tag);
+            }
+        }
+
+        tags.into_iter().collect()
+    }
+
+    /// Finds the `#tags` of a text. Obsidian tags hold letters, digits, `_`, `-` and `/`,
+    /// and at least one character that is not a digit; `#` must follow a space or the start of the line.
+    fn inline_tags(text: &str) -> Vec<String> {
+        let mut tags = Vec::new();
+        let mut previous = ' ';
+        let mut chars = text.char_indices().peekable();
+
+        while let Some((start, c)) = chars.next() {
+            if c == '#' && previous.is_whitespace() {
+                let mut end = start + 1;
+                while let Some((index, next)) = chars.peek() {
+                    if next.is_alphanumeric() || matches!(next, '_' | '-' | '/') {
+                        end = index + next.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                let tag = &text[start + 1..end];
+                if !tag.is_empty() && !tag.chars().all(|c| c.is_ascii_digit()) {
+                    tags.push(tag.to_string());
+                }
+                previous = text[..end].chars().last().unwrap_or(' ');
+                continue;
+            }
+            previous = c;
+        }
+
+        tags
+    }
+
+    /// The pieces of a Markdown text outside code blocks and inline code.
+    fn outside_code(text: &str) -> Vec<&str> {
+        let mut pieces = Vec::new();
+        let mut in_block = false;
+
+        for line in text.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_block = !in_block;
+                continue;
+            }
+            if in_block {
+                continue;
+            }
+
+            pieces.extend(line.split('`').step_by(2));
+        }
+
+        pieces
+    }
+
+    /// Maps the names a note can be linked with, in lowercase, to its ID:
+    /// its path in the vault, its file name and its aliases.
+    /// When several notes share a name, the one closest to the root wins, as in Obsidian.
+    fn index(created: &[(Note, &VaultFile)]) -> HashMap<String, i64> {
+        let mut sorted: Vec<&(Note, &VaultFile)> = created.iter().collect();
+        sorted.sort_by_key(|(_, file)| (file.key.matches('/').count(), file.key.clone()));
+
+        let mut index = HashMap::new();
+        for (note, file) in sorted {
+            let id = note.get_id().unwrap_or_default();
+            let name = file.key.rsplit('/').next().unwrap_or_default();
+
+            index.insert(file.key.to_lowercase(), id);
+            index.entry(name.to_lowercase()).or_insert(id);
+            for alias in &file.front_matter.aliases {
+                index.entry(alias.to_lowercase()).or_insert(id);
+            }
+        }
+
+        index
+    }
+
+    /// Replaces the links of a file by Markdown links to the notes, reporting the ones left as they were.
+    fn convert_links(
+        &self,
+        file: &VaultFile,
+        index: &HashMap<String, i64>,
+        attachments: &BTreeSet<String>,
+        report: &mut VaultReport,
+    ) -> String {
+        let mut converted = String::with_capacity(file.body.len());
+        let mut in_block = false;
+
+        // Each line keeps its own ending, `\n` or `\r\n` or none at the end, so only the links change
+        for line_with_ending in file.body.split_inclusive('\n') {
+            let line = line_with_ending.trim_end_matches(['\n', '\r']);
+            let ending = &line_with_ending[line.len()..];
+
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_block = !in_block;
+            }
+            if in_block || trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                converted.push_str(line_with_ending);
+                continue;
+            }
+
+            // Inline code is every other piece between backticks
+            for (piece_number, piece) in line.split('`').enumerate() {
+                if piece_number > 0 {
+                    converted.push('`');
+                }
+                if piece_number % 2 == 1 {
+                    converted.push_str(piece);
+                } else {
+                    converted.push_str(&self.convert_piece(file, piece, index, attachments, report));
+                }
+            }
+            converted.push_str(ending);
+        }
+
+        converted
+    }
+
+    fn convert_piece(
+        &self,
+        file: &VaultFile,
+        text: &str,
+        index: &HashMap<String, i64>,
+        attachments: &BTreeSet<String>,
+        report: &mut VaultReport,
+    ) -> String {
+        let mut converted = String::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find("[[") {
+            let end = match rest[start..].find("]]") {
+                Some(end) => start + end,
+                None => break,
+            };
+
+            let embed = rest[..start].ends_with('!');
+            let before = if embed { &rest[..start - 1] } else { &rest[..start] };
+            let link = &rest[if embed { start - 1 } else { start }..end + 2];
+            converted.push_str(before);
+
+            let inner = &rest[start + 2..end];
+            let (target, alias) = match inner.split_once('|') {
+                Some((target, alias)) => (target, Some(alias.trim())),
+                None => (inner, None),
+            };
+            let (page, heading) = match target.split_once('#') {
+                Some((page, heading)) => (page.trim(), Some(heading.trim().trim_start_matches('^'))),
+                None => (target.trim(), None),
+            };
+
+            let display = match (alias, heading) {
+                (Some(alias), _) if !alias.is_empty() => alias.to_string(),
+                (_, Some(heading)) if page.is_empty() => heading.to_string(),
+                (_, Some(heading)) => format!("{} > {}", page, heading),
+                _ => page.to_string(),
+            };
+
+            if page.is_empty() {
+                // A link to a heading of the same note
+                converted.push_str(&display);
+            } else {
+                let key = page.strip_suffix(".md").unwrap_or(page).to_lowercase();
+                match index.get(&key) {
+                    Some(id) => converted.push_str(&format!("[{}]({}{})", display, Note::LINK_SCHEME, id)),
+                    None => {
+                        let is_attachment = attachments
+                            .iter()
+                            .any(|attachment| *attachment == key || attachment.ends_with(&format!("/{}", key)));
+                        report.unresolved.push(UnresolvedLink {
+                            path: file.path.clone(),
+                            link: link.to_string(),
+                            reason: if is_attachment {
+                                String::from("attachments are not imported")
+                            } else {
+                                String::from("no imported note has this name")
+                            },
+                        });
+                        converted.push_str(link);
+                    }
+                }
+            }
+
+            rest = &rest[end + 2..];
+        }
+
+        converted.push_str(rest);
+        converted
+    }
+}
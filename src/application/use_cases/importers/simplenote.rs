@@ -0,0 +1,141 @@
+use crate::application::use_cases::importers::{ForeignNote, ImportReport};
+use crate::domain::entities::note::NoteLimits;
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::note_repository::NoteRepository;
+use chrono::prelude::*;
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+/// The `notes.json` file of a Simplenote export.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct SimplenoteExport {
+    active_notes: Vec<SimplenoteNote>,
+    trashed_notes: Vec<SimplenoteNote>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct SimplenoteNote {
+    id: String,
+
+    /// The whole text, its first line being the title
+    content: String,
+
+    creation_date: Option<DateTime<Utc>>,
+    last_modified: Option<DateTime<Utc>>,
+    tags: Vec<String>,
+    pinned: bool,
+}
+
+pub struct ImportSimplenoteNotes<'a> {
+    note_repository: &'a dyn NoteRepository,
+    limits: NoteLimits,
+}
+
+///
+/// The `ImportSimplenoteNotes` struct provides a use case for creating notes from the `notes.json` file
+/// of a Simplenote export.
+///
+/// Simplenote takes the first line of a note as its title, so it becomes the title of the note
+/// and the rest of the lines its content. Tags and dates are kept, the pinned notes get the
+/// `pinned` tag, as notes have no pinned state, and the notes in the trash of Simplenote are moved to the trash.
+///
+impl<'a> ImportSimplenoteNotes<'a> {
+    /// Name of the file in the export
+    pub const FILE_NAME: &'static str = "notes.json";
+
+    /// Tag of the notes pinned in Simplenote
+    pub const PINNED_TAG: &'static str = "pinned";
+
+    ///
+    /// Creates a new instance of `ImportSimplenoteNotes`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: The repository the notes are saved to.
+    /// * `limits`: The maximum lengths of the title and content, from the settings.
+    ///
+    /// # Returns
+    /// A new `ImportSimplenoteNotes` instance.
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository, limits: NoteLimits) -> Self {
+        ImportSimplenoteNotes { note_repository, limits }
+    }
+
+    ///
+    /// Executes the use case to import a Simplenote export.
+    ///
+    /// # Arguments
+    /// * `path`: The `notes.json` file, or the directory of the export holding it (directly or in `source`).
+    /// * `notebook_id`: The notebook the notes are saved to, as Simplenote has no notebooks.
+    ///
+    /// # Returns
+    /// * `Ok(ImportReport)`: The saved notes and the notes that failed.
+    /// * `Err(NoteError)`: If the file cannot be read or a note cannot be saved.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::Storage` if the file cannot be read or is not a Simplenote export,
+    ///   or if the database fails while saving. The notes saved before the failure are kept.
+    ///
+    pub fn execute(&self, path: &Path, notebook_id: i64) -> Result<ImportReport, NoteError> {
+        let file = if path.is_dir() {
+            [path.join(Self::FILE_NAME), path.join("source").join(Self::FILE_NAME)]
+                .into_iter()
+                .find(|file| file.is_file())
+                .ok_or_else(|| NoteError::Storage(format!("{} holds no {} file", path.display(), Self::FILE_NAME)))?
+        } else {
+            path.to_path_buf()
+        };
+
+        let text = fs::read_to_string(&file)
+            .map_err(|err| NoteError::Storage(format!("cannot read {}: {}", file.display(), err)))?;
+        let export: SimplenoteExport = serde_json::from_str(&text)
+            .map_err(|err| NoteError::Storage(format!("{} is not a Simplenote export: {}", file.display(), err)))?;
+
+        let mut report = ImportReport::default();
+        let notes = export
+            .active_notes
+            .into_iter()
+            .map(|note| (note, false))
+            .chain(export.trashed_notes.into_iter().map(|note| (note, true)));
+
+        for (note, trashed) in notes {
+            let source = format!("{}: {}", file.display(), note.id);
+            let foreign = self.foreign_note(note, trashed, notebook_id);
+            report.save(self.note_repository, &self.limits, source, &foreign)?;
+        }
+
+        Ok(report)
+    }
+
+    fn foreign_note(&self, note: SimplenoteNote, trashed: bool, notebook_id: i64) -> ForeignNote {
+        let text = note.content.replace("\r\n", "\n");
+        let text = text.trim();
+        let (first_line, rest) = text.split_once('\n').unwrap_or((text, ""));
+
+        // Markdown notes usually start with a heading
+        let title = first_line
+            .trim()
+            .trim_start_matches('#')
+            .trim()
+            .chars()
+            .take(self.limits.max_title_length)
+            .collect::<String>();
+        let content = if rest.trim().is_empty() { text } else { rest.trim() };
+
+        let mut tags = note.tags;
+        if note.pinned {
+            tags.push(Self::PINNED_TAG.to_string());
+        }
+
+        ForeignNote {
+            title,
+            content: content.to_string(),
+            tags,
+            created_at: note.creation_date,
+            updated_at: note.last_modified,
+            trashed,
+            notebook_id,
+        }
+    }
+}
@@ -1 +1,6 @@
-pub mod notes;
\ No newline at end of file
+pub mod importers;
+pub mod notebooks;
+pub mod notes;
+pub mod revisions;
+pub mod tags;
+pub mod trash;
\ No newline at end of file
@@ -0,0 +1,95 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::notebook::Notebook, errors::note_error::NoteError,
+    repositories::notebook_repository::NotebookRepository,
+};
+
+pub struct CreateNotebook<'a> {
+    notebook_repository: &'a dyn NotebookRepository,
+}
+
+///
+/// The `CreateNotebook` struct provides a use case for creating a notebook, optionally nested in another one.
+///
+impl<'a> CreateNotebook<'a> {
+    ///
+    /// Creates a new instance of `CreateNotebook`.
+    ///
+    /// # Arguments
+    /// * `notebook_repository`: A reference to any implementation of `NotebookRepository` to interact with the notebook storage.
+    ///
+    /// # Returns
+    /// A new `CreateNotebook` instance.
+    ///
+    /// # Example
+    /// ```
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
+    /// let create_notebook_use_case = CreateNotebook::new(&note_repository);
+    /// ```
+    ///
+    pub fn new(notebook_repository: &'a dyn NotebookRepository) -> Self {
+        CreateNotebook { notebook_repository }
+    }
+
+    ///
+    /// Executes the use case to create a notebook.
+    ///
+    /// # Arguments
+    /// * `name`: The name of the notebook. Must not be empty and cannot exceed 50 characters.
+    /// * `parent_id`: The ID of the parent notebook, `None` to create a root notebook.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `Notebook`, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidNotebook` if the name is not valid or a sibling notebook already has it.
+    /// * Returns `NoteError::NotFound` if the parent notebook does not exist.
+    /// * Returns `NoteError::Storage` if the notebook cannot be saved.
+    ///
+    /// # Example
+    /// ```
+    /// let notebook = create_notebook_use_case.execute("Projects", None)?;
+    /// create_notebook_use_case.execute("Rust", notebook.get_id())?;
+    /// ```
+    ///
+    pub fn execute(&self, name: &str, parent_id: Option<i64>) -> Result<Notebook, NoteError> {
+        let mut notebook = Notebook::create(name, parent_id)?;
+
+        let notebooks = self
+            .notebook_repository
+            .find_all_notebooks()
+            .map_err(NoteError::Storage)?;
+
+        if let Some(parent_id) = parent_id {
+            if !notebooks.iter().any(|notebook| notebook.get_id() == Some(parent_id)) {
+                return Err(NoteError::NotFound(format!("notebook id {}", parent_id)));
+            }
+        }
+
+        let duplicated = notebooks.iter().any(|sibling| {
+            sibling.get_parent_id() == parent_id
+                && sibling.get_name().to_lowercase() == notebook.get_name().to_lowercase()
+        });
+        if duplicated {
+            return Err(NoteError::InvalidNotebook(format!(
+                "there is already a notebook called `{}` there",
+                notebook.get_name()
+            )));
+        }
+
+        let id = self
+            .notebook_repository
+            .save_notebook(&notebook)
+            .map_err(NoteError::Storage)?;
+        notebook.id = Some(id);
+
+        println!(
+            "{} Created notebook `{}` with id: {}",
+            Palette::current().success(">"),
+            notebook.get_name(),
+            Palette::current().accent(id.to_string())
+        );
+
+        Ok(notebook)
+    }
+}
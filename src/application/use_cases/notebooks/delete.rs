@@ -0,0 +1,79 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::notebook::{Notebook, NotebookDeletion},
+    errors::note_error::NoteError,
+    repositories::notebook_repository::NotebookRepository,
+};
+
+pub struct DeleteNotebook<'a> {
+    notebook_repository: &'a dyn NotebookRepository,
+}
+
+///
+/// The `DeleteNotebook` struct provides a use case for deleting a notebook.
+/// Its notes and sub-notebooks are either deleted with it or moved to its parent,
+/// depending on the chosen `NotebookDeletion` strategy. The Inbox cannot be deleted.
+///
+impl<'a> DeleteNotebook<'a> {
+    ///
+    /// Creates a new instance of `DeleteNotebook`.
+    ///
+    /// # Arguments
+    /// * `notebook_repository`: A reference to any implementation of `NotebookRepository` to interact with the notebook storage.
+    ///
+    /// # Returns
+    /// A new `DeleteNotebook` instance.
+    ///
+    pub fn new(notebook_repository: &'a dyn NotebookRepository) -> Self {
+        DeleteNotebook { notebook_repository }
+    }
+
+    ///
+    /// Executes the use case to delete a notebook.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the notebook to delete.
+    /// * `strategy`: `NotebookDeletion::Cascade` to delete its content (its notes go to the trash), `NotebookDeletion::Reparent` to keep it.
+    ///
+    /// # Returns
+    /// A `Result` containing the number of notes trashed or moved, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidNotebook` when trying to delete the Inbox.
+    /// * Returns `NoteError::NotFound` if the notebook does not exist.
+    /// * Returns `NoteError::Storage` if the notebook cannot be deleted.
+    ///
+    pub fn execute(&self, id: i64, strategy: NotebookDeletion) -> Result<usize, NoteError> {
+        if id == Notebook::INBOX_ID {
+            return Err(NoteError::InvalidNotebook(String::from("the Inbox cannot be deleted")));
+        }
+
+        let notebooks = self
+            .notebook_repository
+            .find_all_notebooks()
+            .map_err(NoteError::Storage)?;
+        let notebook = match notebooks.iter().find(|notebook| notebook.get_id() == Some(id)) {
+            Some(notebook) => notebook,
+            None => return Err(NoteError::NotFound(format!("notebook id {}", id))),
+        };
+
+        let affected = self
+            .notebook_repository
+            .delete_notebook(id, strategy)
+            .map_err(NoteError::Storage)?;
+
+        let outcome = match strategy {
+            NotebookDeletion::Cascade => "moved to the trash",
+            NotebookDeletion::Reparent => "moved to the parent notebook",
+        };
+        println!(
+            "{} Deleted notebook `{}`, {} notes {}",
+            Palette::current().success(">"),
+            notebook.get_name(),
+            Palette::current().accent(affected.to_string()),
+            outcome
+        );
+
+        Ok(affected)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::domain::{
+    entities::notebook::Notebook, errors::note_error::NoteError,
+    repositories::notebook_repository::NotebookRepository,
+};
+
+pub struct FindAllNotebooks<'a> {
+    notebook_repository: &'a dyn NotebookRepository,
+}
+
+impl<'a> FindAllNotebooks<'a> {
+    /// Creates a new instance of `FindAllNotebooks`.
+    ///
+    /// # Arguments
+    /// * `notebook_repository`: A reference to any implementation of `NotebookRepository` to interact with the notebook storage.
+    ///
+    /// # Returns
+    /// A new `FindAllNotebooks` instance.
+    ///
+    pub fn new(notebook_repository: &'a dyn NotebookRepository) -> Self {
+        FindAllNotebooks { notebook_repository }
+    }
+
+    /// Executes the use case to find every notebook.
+    ///
+    /// # Returns
+    /// A `Result` containing every notebook with its note count, the Inbox first,
+    /// or a `NoteError::Storage` if the notebooks cannot be read.
+    ///
+    pub fn execute(&self) -> Result<Vec<Notebook>, NoteError> {
+        self.notebook_repository
+            .find_all_notebooks()
+            .map_err(NoteError::Storage)
+    }
+}
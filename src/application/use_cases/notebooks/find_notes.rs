@@ -0,0 +1,48 @@
+use crate::domain::{
+    entities::note::Note, errors::note_error::NoteError,
+    repositories::notebook_repository::NotebookRepository,
+};
+
+pub struct FindNotesInNotebook<'a> {
+    notebook_repository: &'a dyn NotebookRepository,
+}
+
+impl<'a> FindNotesInNotebook<'a> {
+    /// Creates a new instance of `FindNotesInNotebook`.
+    ///
+    /// # Arguments
+    /// * `notebook_repository`: A reference to any implementation of `NotebookRepository` to interact with the notebook storage.
+    ///
+    /// # Returns
+    /// A new `FindNotesInNotebook` instance.
+    ///
+    pub fn new(notebook_repository: &'a dyn NotebookRepository) -> Self {
+        FindNotesInNotebook { notebook_repository }
+    }
+
+    /// Executes the use case to find the notes stored directly in a notebook.
+    ///
+    /// # Arguments
+    /// * `notebook_id`: The ID of the notebook. Must be greater than 0.
+    ///
+    /// # Returns
+    /// A `Result` containing the notes, most recently updated first, or a `NoteError` if the ID is invalid,
+    /// the notebook has no notes or the storage fails.
+    ///
+    pub fn execute(&self, notebook_id: i64) -> Result<Vec<Note>, NoteError> {
+        if notebook_id <= 0 {
+            return Err(NoteError::InvalidId(notebook_id));
+        }
+
+        let notes = self
+            .notebook_repository
+            .find_notes_in_notebook(notebook_id)
+            .map_err(NoteError::Storage)?;
+
+        if notes.is_empty() {
+            return Err(NoteError::NotFound(format!("notebook id {}", notebook_id)));
+        }
+
+        Ok(notes)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create;
+pub mod delete;
+pub mod find_all;
+pub mod find_notes;
+pub mod move_note;
\ No newline at end of file
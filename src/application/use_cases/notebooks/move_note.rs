@@ -0,0 +1,85 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    errors::note_error::NoteError,
+    repositories::{note_repository::NoteRepository, notebook_repository::NotebookRepository},
+};
+
+pub struct MoveNote<'a> {
+    note_repository: &'a dyn NoteRepository,
+    notebook_repository: &'a dyn NotebookRepository,
+}
+
+///
+/// The `MoveNote` struct provides a use case for moving a note to another notebook.
+/// Moving a note does not change its content nor its `updated_at` timestamp.
+///
+impl<'a> MoveNote<'a> {
+    ///
+    /// Creates a new instance of `MoveNote`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to look up the note.
+    /// * `notebook_repository`: A reference to any implementation of `NotebookRepository` to move the note.
+    ///
+    /// # Returns
+    /// A new `MoveNote` instance.
+    ///
+    /// # Example
+    /// ```
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
+    /// let move_note_use_case = MoveNote::new(&note_repository, &note_repository);
+    /// ```
+    ///
+    pub fn new(
+        note_repository: &'a dyn NoteRepository,
+        notebook_repository: &'a dyn NotebookRepository,
+    ) -> Self {
+        MoveNote {
+            note_repository,
+            notebook_repository,
+        }
+    }
+
+    ///
+    /// Executes the use case to move a note.
+    ///
+    /// # Arguments
+    /// * `note_id`: The ID of the note to move. Must be greater than 0.
+    /// * `notebook_id`: The ID of the destination notebook.
+    ///
+    /// # Returns
+    /// * `Ok(())`: If the note was moved.
+    /// * `Err(NoteError)`: If the note or the notebook do not exist or the storage fails.
+    ///
+    pub fn execute(&self, note_id: i64, notebook_id: i64) -> Result<(), NoteError> {
+        if note_id <= 0 {
+            return Err(NoteError::InvalidId(note_id));
+        }
+
+        if self.note_repository.find_by_id(note_id).is_none() {
+            return Err(NoteError::NotFound(format!("id {}", note_id)));
+        }
+
+        let notebooks = self
+            .notebook_repository
+            .find_all_notebooks()
+            .map_err(NoteError::Storage)?;
+        let notebook = match notebooks.iter().find(|notebook| notebook.get_id() == Some(notebook_id)) {
+            Some(notebook) => notebook,
+            None => return Err(NoteError::NotFound(format!("notebook id {}", notebook_id))),
+        };
+
+        self.notebook_repository
+            .move_note(note_id, notebook_id)
+            .map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Moved note {} to `{}`",
+            Palette::current().success(">"),
+            Palette::current().accent(note_id.to_string()),
+            notebook.get_name()
+        );
+
+        Ok(())
+    }
+}
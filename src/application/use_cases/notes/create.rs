@@ -1,8 +1,12 @@
-use crate::domain::{entities::note::Note, repositories::note_repository::NoteRepository};
-use ansi_term::Colour;
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::note::{Note, NoteLimits}, errors::note_error::NoteError,
+    repositories::note_repository::NoteRepository,
+};
 
 pub struct CreateNewNote<'a> {
-    note_repository: &'a NoteRepository,
+    note_repository: &'a dyn NoteRepository,
+    limits: NoteLimits,
 }
 
 ///
@@ -14,19 +18,23 @@ impl<'a> CreateNewNote<'a> {
     /// Creates a new instance of `CreateNewNote`.
     ///
     /// # Arguments
-    /// * `note_repository`: A reference to an instance of `NoteRepository` to interact with the note storage.
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
+    /// * `limits`: The maximum lengths of the title and content, from the settings.
     ///
     /// # Returns
     /// A new `CreateNewNote` instance.
     ///
     /// # Example
     /// ```
-    /// let note_repository = NoteRepository::new();
-    /// let create_use_case = CreateNewNote::new(&note_repository);
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
+    /// let create_use_case = CreateNewNote::new(&note_repository, NoteLimits::default());
     /// ```
     ///
-    pub fn new(note_repository: &'a NoteRepository) -> Self {
-        CreateNewNote { note_repository }
+    pub fn new(note_repository: &'a dyn NoteRepository, limits: NoteLimits) -> Self {
+        CreateNewNote {
+            note_repository,
+            limits,
+        }
     }
 
     ///
@@ -35,33 +43,50 @@ impl<'a> CreateNewNote<'a> {
     /// # Arguments
     /// * `title`: A reference to a `String` containing the title of the note to be created.
     /// * `content`: A reference to a `String` containing the content of the note to be created.
+    /// * `tags`: The names of the tags to attach to the note, can be empty.
+    /// * `notebook_id`: The ID of the notebook where the note is stored, `Notebook::INBOX_ID` by default.
     ///
     /// # Returns
-    /// A `Result` containing the created `Note` on success, or an error message on failure.
+    /// A `Result` containing the created `Note` on success, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns a validation error if the `title` or `content` are empty or too long, or a tag name is not valid.
+    /// * Returns `NoteError::Storage` if the note cannot be saved.
     ///
     /// # Example
     /// ```
     /// let title = String::from("My Note");
     /// let content = String::from("This is the content of my note.");
-    /// let result = create_use_case.execute(&title, &content);
+    /// let tags = vec![String::from("personal")];
+    /// let result = create_use_case.execute(&title, &content, &tags, Notebook::INBOX_ID);
     /// match result {
     ///     Ok(note) => println!("Note created with ID: {}", note.get_id().unwrap()),
     ///     Err(err) => println!("Failed to create note: {}", err),
     /// }
     /// ```
     ///
-    pub fn execute(&self, title: &String, content: &String) -> Result<Note, String> {
-        let mut note = Note::create(title, content);
+    pub fn execute(
+        &self,
+        title: &String,
+        content: &String,
+        tags: &[String],
+        notebook_id: i64,
+    ) -> Result<Note, NoteError> {
+        let mut note = Note::create(title, content, &self.limits)?.with_notebook(notebook_id);
+
+        for tag in tags {
+            note.add_tag(tag)?;
+        }
 
         let id: i64 = self
             .note_repository
             .save(&note)
-            .map_err(|e| e.to_string())?;
+            .map_err(NoteError::Storage)?;
 
         println!(
             "{} Created note with id: {}",
-            Colour::Green.paint(">"),
-            Colour::Blue.paint(id.to_string())
+            Palette::current().success(">"),
+            Palette::current().accent(id.to_string())
         );
 
         note.set_id(id);
@@ -1,12 +1,16 @@
-use crate::domain::{entities::note::Note, repositories::note_repository::NoteRepository};
-use ansi_term::Colour;
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::note::Note, errors::note_error::NoteError,
+    repositories::note_repository::NoteRepository,
+};
 
 pub struct DeletedNote<'a> {
-    note_repository: &'a NoteRepository,
+    note_repository: &'a dyn NoteRepository,
 }
 
 ///
 /// The `DeletedNote` struct provides a use case for deleting a note.
+/// Deleted notes are moved to the trash, where they can be restored until they are purged.
 /// It encapsulates the logic for deleting a note and interacting with the `NoteRepository`.
 /// This use case is responsible for validating the input, ensuring that the note exists, and handling any errors
 /// that may occur during the deletion process, such as invalid input or database errors.
@@ -16,30 +20,30 @@ impl<'a> DeletedNote<'a> {
     /// Creates a new instance of `DeletedNote`.
     ///
     /// # Arguments
-    /// * `note_repository`: A reference to an instance of `NoteRepository` to interact with the note storage.
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
     ///
     /// # Returns
     /// A new `DeletedNote` instance.
     ///
     /// # Example
     /// ```
-    /// let note_repository = NoteRepository::new();
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
     /// let delete_use_case = DeletedNote::new(&note_repository);
     /// ```
     ///
-    pub fn new(note_repository: &'a NoteRepository) -> Self {
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
         DeletedNote { note_repository }
     }
 
     ///
-    /// Executes the use case to delete a note.
+    /// Executes the use case to delete a note, moving it to the trash.
     ///
     /// # Arguments
     /// * `id`: The ID of the note to be deleted. Must be greater than 0.
     ///
     /// # Returns
-    /// * `Ok(())`: If the note is successfully deleted.
-    /// * `Err(String)`: If there is an error during the deletion process, such as invalid input or note not found.
+    /// * `Ok(())`: If the note is successfully moved to the trash.
+    /// * `Err(NoteError)`: If there is an error during the deletion process, such as invalid input or a storage failure.
     ///
     /// # Example
     /// ```
@@ -50,21 +54,25 @@ impl<'a> DeletedNote<'a> {
     /// }
     /// ```
     ///
-    pub fn execute(&self, id: i64) -> Result<(), String> {
+    pub fn execute(&self, id: i64) -> Result<(), NoteError> {
         if id <= 0 {
-            return Err("Invalid note ID".to_string());
+            return Err(NoteError::InvalidId(id));
+        }
+
+        if self.note_repository.find_by_id(id).is_none() {
+            return Err(NoteError::NotFound(format!("id {}", id)));
         }
 
         match self.note_repository.delete(id) {
             Ok(()) => {
                 println!(
-                    "{} Deleted note with id: {}",
-                    Colour::Green.paint(">"),
-                    Colour::Blue.paint(id.to_string())
+                    "{} Moved note with id {} to the trash",
+                    Palette::current().success(">"),
+                    Palette::current().accent(id.to_string())
                 );
                 Ok(())
             }
-            Err(err) => Err(err),
+            Err(err) => Err(NoteError::Storage(err)),
         }
     }
 }
@@ -0,0 +1,294 @@
+use crate::application::front_matter::FrontMatter;
+use crate::domain::entities::{note::Note, notebook::Notebook, tag::Tag};
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::{note_repository::NoteRepository, notebook_repository::NotebookRepository};
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Which notes are exported. An empty filter exports every note that is not in the trash.
+#[derive(Debug, Clone, Default)]
+pub struct ExportFilter {
+    /// Only these notes, when not empty
+    pub ids: Vec<i64>,
+
+    /// Only the notes carrying all these tags, when not empty
+    pub tags: Vec<String>,
+}
+
+/// A note written to the export directory.
+#[derive(Debug, Clone)]
+pub struct ExportedNote {
+    pub id: i64,
+    pub path: PathBuf,
+}
+
+/// The `manifest.json` file of an export directory: which note every file holds
+/// and the hash of the content it was exported with, so an import can recognize them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportManifest {
+    pub version: u32,
+    pub exported_at: Option<DateTime<Utc>>,
+    pub notes: Vec<ManifestEntry>,
+}
+
+/// One exported note in the manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub id: i64,
+
+    /// Name of the file, relative to the export directory
+    pub file: String,
+
+    /// `Note::content_hash` of the exported content
+    pub content_hash: String,
+
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExportManifest {
+    /// Name of the manifest in the export directory
+    pub const FILE_NAME: &'static str = "manifest.json";
+
+    /// Version of the manifest format
+    pub const VERSION: u32 = 1;
+
+    /// Reads the manifest of a directory.
+    ///
+    /// # Returns
+    /// - `Ok(None)` if the directory has no manifest.
+    /// - `Err(String)` if it cannot be read or is not a valid manifest.
+    pub fn load(directory: &Path) -> Result<Option<ExportManifest>, String> {
+        let path = directory.join(Self::FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let text = fs::read_to_string(&path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+        serde_json::from_str(&text)
+            .map(Some)
+            .map_err(|err| format!("{} is not a valid manifest: {}", path.display(), err))
+    }
+
+    /// Finds the entry of a note.
+    pub fn entry_for(&self, id: i64) -> Option<&ManifestEntry> {
+        self.notes.iter().find(|entry| entry.id == id)
+    }
+
+    fn save(&self, directory: &Path) -> Result<(), String> {
+        let path = directory.join(Self::FILE_NAME);
+        let text = serde_json::to_string_pretty(self).map_err(|err| err.to_string())?;
+
+        fs::write(&path, text + "\n").map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+}
+
+pub struct ExportNotes<'a> {
+    note_repository: &'a dyn NoteRepository,
+    notebook_repository: &'a dyn NotebookRepository,
+}
+
+///
+/// The `ExportNotes` struct provides a use case for writing notes to a directory as Markdown files.
+/// Every note is written to `<slug>.md`, the slug being its title in lowercase ASCII with any other
+/// character replaced by `-`, and starts with a YAML front matter holding its ID, title, dates, tags and notebook.
+/// A `manifest.json` file lists the exported notes, so exporting again to the same directory
+/// overwrites the files of the same notes instead of creating new ones.
+/// Locked notes are written with their sealed content, their plain content never reaches the files.
+///
+impl<'a> ExportNotes<'a> {
+    /// Longest slug, without the `-2` suffix added on collisions
+    const MAX_SLUG_LENGTH: usize = 60;
+
+    /// Names Windows does not allow as file names, whatever the extension
+    const RESERVED_NAMES: [&'static str; 22] = [
+        "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8", "com9", "lpt1",
+        "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+    ];
+
+    ///
+    /// Creates a new instance of `ExportNotes`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: The repository the notes are read from.
+    /// * `notebook_repository`: The repository the notebooks are read from, to write the notebook of every note.
+    ///
+    /// # Returns
+    /// A new `ExportNotes` instance.
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository, notebook_repository: &'a dyn NotebookRepository) -> Self {
+        ExportNotes {
+            note_repository,
+            notebook_repository,
+        }
+    }
+
+    ///
+    /// Executes the use case to export notes.
+    ///
+    /// # Arguments
+    /// * `directory`: The directory the files are written to, created if missing.
+    /// * `filter`: Which notes are exported.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ExportedNote>)`: The exported notes and the file each one was written to.
+    /// * `Err(NoteError)`: If no note matches the filter or a file cannot be written.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidTag` if a tag of the filter is not valid.
+    /// * Returns `NoteError::NotFound` if no note matches the filter.
+    /// * Returns `NoteError::Storage` if the notes cannot be read or the files cannot be written.
+    ///
+    /// # Example
+    /// ```
+    /// let filter = ExportFilter { tags: vec![String::from("work")], ..ExportFilter::default() };
+    /// match export_use_case.execute(Path::new("export"), &filter) {
+    ///     Ok(exported) => println!("Exported {} notes", exported.len()),
+    ///     Err(err) => println!("Failed to export the notes: {}", err),
+    /// }
+    /// ```
+    ///
+    pub fn execute(&self, directory: &Path, filter: &ExportFilter) -> Result<Vec<ExportedNote>, NoteError> {
+        let tags = filter
+            .tags
+            .iter()
+            .map(|tag| Tag::normalize(tag))
+            .collect::<Result<Vec<String>, NoteError>>()?;
+
+        let mut notes: Vec<Note> = self
+            .note_repository
+            .find_all()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|note| filter.ids.is_empty() || filter.ids.contains(&note.get_id().unwrap_or_default()))
+            .filter(|note| tags.iter().all(|tag| note.has_tag(tag)))
+            .collect();
+        notes.sort_by_key(|note| note.get_id());
+
+        if notes.is_empty() {
+            return Err(NoteError::NotFound(String::from("the given filter")));
+        }
+
+        let notebooks = self.notebook_repository.find_all_notebooks().map_err(NoteError::Storage)?;
+
+        fs::create_dir_all(directory)
+            .map_err(|err| NoteError::Storage(format!("cannot create {}: {}", directory.display(), err)))?;
+        let mut manifest = ExportManifest::load(directory).map_err(NoteError::Storage)?.unwrap_or_default();
+
+        let file_names = ExportNotes::file_names(directory, &notes, &manifest)?;
+
+        let mut exported = Vec::new();
+        for (note, file_name) in notes.iter().zip(file_names) {
+            let id = note.get_id().unwrap_or_default();
+            let front_matter = FrontMatter {
+                id: Some(id),
+                title: Some(note.get_title().clone()),
+                created_at: Some(note.get_created_at()),
+                updated_at: Some(note.get_updated_at()),
+                tags: note.get_tags().iter().cloned().collect(),
+                notebook: Some(Notebook::path_of(&notebooks, note.get_notebook_id())).filter(|path| !path.is_empty()),
+                locked: note.is_locked(),
+                ..FrontMatter::default()
+            };
+
+            let path = directory.join(&file_name);
+            let text = front_matter.render(note.get_content()).map_err(NoteError::Storage)?;
+            fs::write(&path, text).map_err(|err| NoteError::Storage(format!("cannot write {}: {}", path.display(), err)))?;
+
+            manifest.notes.retain(|entry| entry.id != id);
+            manifest.notes.push(ManifestEntry {
+                id,
+                file: file_name,
+                content_hash: Note::content_hash(note.get_content()),
+                updated_at: note.get_updated_at(),
+            });
+            exported.push(ExportedNote { id, path });
+        }
+
+        // Entries of notes exported before are kept while their file is still there
+        manifest.notes.retain(|entry| directory.join(&entry.file).exists());
+        manifest.notes.sort_by_key(|entry| entry.id);
+        manifest.version = ExportManifest::VERSION;
+        manifest.exported_at = Some(Utc::now());
+        manifest.save(directory).map_err(NoteError::Storage)?;
+
+        Ok(exported)
+    }
+
+    /// Picks the file name of every note. A note exported before keeps its file,
+    /// the rest get their slug, numbered when it is already taken.
+    fn file_names(directory: &Path, notes: &[Note], manifest: &ExportManifest) -> Result<Vec<String>, NoteError> {
+        let previous: BTreeMap<i64, String> = notes
+            .iter()
+            .filter_map(|note| {
+                let id = note.get_id()?;
+                manifest.entry_for(id).map(|entry| (id, entry.file.clone()))
+            })
+            .collect();
+
+        // Names are compared in lowercase, as some filesystems ignore the case
+        let mut taken: HashSet<String> = fs::read_dir(directory)
+            .map_err(|err| NoteError::Storage(format!("cannot read {}: {}", directory.display(), err)))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_lowercase())
+            .filter(|name| !previous.values().any(|file| file.to_lowercase() == *name))
+            .collect();
+        taken.insert(ExportManifest::FILE_NAME.to_string());
+
+        // Files of notes exported before are claimed first, so no new slug takes them
+        let mut names: BTreeMap<i64, String> = BTreeMap::new();
+        for (id, file) in &previous {
+            if taken.insert(file.to_lowercase()) {
+                names.insert(*id, file.clone());
+            }
+        }
+
+        for note in notes {
+            let id = note.get_id().unwrap_or_default();
+            if names.contains_key(&id) {
+                continue;
+            }
+
+            let slug = ExportNotes::slug(note.get_title());
+            let mut name = format!("{}.md", slug);
+            let mut counter = 2;
+            while !taken.insert(name.clone()) {
+                name = format!("{}-{}.md", slug, counter);
+                counter += 1;
+            }
+            names.insert(id, name);
+        }
+
+        Ok(notes
+            .iter()
+            .map(|note| names.remove(&note.get_id().unwrap_or_default()).unwrap_or_default())
+            .collect())
+    }
+
+    /// Turns a title into a name that is safe on every filesystem, e.g. `Reunión: plan/2025` into `reunion-plan-2025`.
+    fn slug(title: &str) -> String {
+        let mut slug = String::new();
+        for c in deunicode::deunicode(title).to_lowercase().chars() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c);
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+
+        slug.truncate(Self::MAX_SLUG_LENGTH);
+        let slug = slug.trim_end_matches('-');
+
+        if slug.is_empty() {
+            String::from("note")
+        } else if Self::RESERVED_NAMES.contains(&slug) {
+            format!("{}-note", slug)
+        } else {
+            slug.to_string()
+        }
+    }
+}
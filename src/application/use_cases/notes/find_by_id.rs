@@ -1,8 +1,9 @@
 use crate::domain::entities::note::Note;
+use crate::domain::errors::note_error::NoteError;
 use crate::domain::repositories::note_repository::NoteRepository;
 
 pub struct FindById<'a> {
-    note_repository: &'a NoteRepository,
+    note_repository: &'a dyn NoteRepository,
 }
 
 ///
@@ -16,18 +17,18 @@ impl<'a> FindById<'a> {
     /// Creates a new instance of `FindById`.
     /// 
     /// # Arguments
-    /// * `note_repository` - A reference to an instance of `NoteRepository` to interact with the note storage.
+    /// * `note_repository` - A reference to any implementation of `NoteRepository` to interact with the note storage.
     /// 
     /// # Returns
     /// A new instance of `FindById`.
     /// 
     /// # Example
     /// ```
-    /// let note_repository = NoteRepository::new();
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
     /// let find_by_id_use_case = FindById::new(&note_repository);
     /// ```
     /// 
-    pub fn new(note_repository: &'a NoteRepository) -> Self {
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
         FindById { note_repository }
     }
 
@@ -38,11 +39,11 @@ impl<'a> FindById<'a> {
     /// * `id` - The ID of the note to find. Must be greater than 0.
     /// 
     /// # Returns
-    /// A `Result` containing the found `Note` if successful, or an error message if the note is not found or the ID is invalid.
+    /// A `Result` containing the found `Note` if successful, or a `NoteError` if the note is not found or the ID is invalid.
     /// 
     /// # Errors
-    /// * Returns an error if the ID is less than or equal to zero.
-    /// * Returns an error if the note with the specified ID does not exist.
+    /// * Returns `NoteError::InvalidId` if the ID is less than or equal to zero.
+    /// * Returns `NoteError::NotFound` if the note with the specified ID does not exist.
     /// 
     /// # Example
     /// ```
@@ -53,16 +54,16 @@ impl<'a> FindById<'a> {
     /// }
     /// ```
     /// 
-    pub fn execute(&self, id: i64) -> Result<Note, String> {
+    pub fn execute(&self, id: i64) -> Result<Note, NoteError> {
         if id <= 0 {
-            return Err("Invalid note ID".to_string());
+            return Err(NoteError::InvalidId(id));
         }
 
         match self.note_repository.find_by_id(id) {
             Some(note) => {
                 Ok(note)
             }
-            None => Err(format!("Note with id {} not found", id)),
+            None => Err(NoteError::NotFound(format!("id {}", id))),
         }
     }
 }
\ No newline at end of file
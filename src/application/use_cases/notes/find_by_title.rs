@@ -1,7 +1,10 @@
-use crate::domain::{entities::note::Note, repositories::note_repository::NoteRepository};
+use crate::domain::{
+    entities::note::Note, errors::note_error::NoteError,
+    repositories::note_repository::NoteRepository,
+};
 
 pub struct FindByTitle<'a> {
-    note_repository: &'a NoteRepository,
+    note_repository: &'a dyn NoteRepository,
 }
 
 ///
@@ -15,18 +18,18 @@ impl<'a> FindByTitle<'a> {
     /// Creates a new instance of `FindByTitle`.
     /// 
     /// # Arguments
-    /// * `note_repository`: A reference to an instance of `NoteRepository` to interact with the note storage.
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
     /// 
     /// # Returns
     /// A new `FindByTitle` instance.
     /// 
     /// # Example
     /// ```
-    /// let note_repository = NoteRepository::new();
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
     /// let find_by_title_use_case = FindByTitle::new(&note_repository);
     /// ```
     /// 
-    pub fn new(note_repository: &'a NoteRepository) -> Self {
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
         FindByTitle { note_repository }
     }
 
@@ -37,11 +40,11 @@ impl<'a> FindByTitle<'a> {
     /// * `title`: A reference to a `String` containing the title of the notes to be found. Must not be empty.
     /// 
     /// # Returns
-    /// A `Result` containing a vector of found `Note` objects if successful, or an error message if no notes are found or the title is invalid.
+    /// A `Result` containing a vector of found `Note` objects if successful, or a `NoteError` if no notes are found or the title is invalid.
     /// 
     /// # Errors
-    /// * Returns an error if the `title` is empty.
-    /// * Returns an error if no notes with the specified title are found.
+    /// * Returns `NoteError::EmptyTitle` if the `title` is empty.
+    /// * Returns `NoteError::NotFound` if no notes with the specified title are found.
     /// 
     /// # Example
     /// ```
@@ -52,14 +55,14 @@ impl<'a> FindByTitle<'a> {
     /// }
     /// ```
     /// 
-    pub fn execute(&self, title: &String) -> Result<Vec<Note>, String> {
+    pub fn execute(&self, title: &String) -> Result<Vec<Note>, NoteError> {
         if title.is_empty() {
-            return Err("Title cannot be empty".to_string());
+            return Err(NoteError::EmptyTitle);
         }
 
         match self.note_repository.find_by_title(&title) {
             Some(notes) => Ok(notes),
-            None => Err(format!("No notes found with title containing `{:?}`", &title)),
+            None => Err(NoteError::NotFound(format!("title containing `{}`", &title))),
         }
     }
 }
\ No newline at end of file
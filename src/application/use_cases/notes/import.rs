@@ -0,0 +1,297 @@
+use crate::application::front_matter::FrontMatter;
+use crate::application::note_cipher::NoteCipher;
+use crate::application::use_cases::notes::export::ExportManifest;
+use crate::domain::entities::{
+    note::{Note, NoteLimits},
+    notebook::Notebook,
+};
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::{note_repository::NoteRepository, notebook_repository::NotebookRepository};
+use chrono::prelude::*;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use walkdir::WalkDir;
+
+/// How the files are imported.
+#[derive(Debug, Clone, Copy)]
+pub struct ImportOptions {
+    /// Read and check the files without saving anything
+    pub dry_run: bool,
+
+    /// Leave out the files whose content is already held by a note
+    pub skip_duplicates: bool,
+
+    /// Notebook of the notes whose front matter does not name an existing one
+    pub notebook_id: i64,
+}
+
+/// What happened to a file.
+#[derive(Debug, Clone)]
+pub enum ImportOutcome {
+    /// The note was saved with this ID
+    Imported(i64),
+
+    /// The note would be saved, in a dry run
+    WouldImport,
+
+    /// The content is already held by a note, `None` when it is a file imported in this same run
+    Duplicate(Option<i64>),
+
+    /// The file was exported from this note, which has not changed since
+    AlreadyImported(i64),
+
+    /// The note breaks a rule of the `Note` entity, e.g. a content longer than the limit
+    Invalid(NoteError),
+
+    /// The file cannot be read or its front matter is malformed
+    Unreadable(String),
+}
+
+/// A file found in the directory and what happened to it.
+#[derive(Debug, Clone)]
+pub struct ImportedFile {
+    pub path: PathBuf,
+
+    /// Title of the note, empty when the file could not be read
+    pub title: String,
+
+    pub outcome: ImportOutcome,
+}
+
+pub struct ImportNotes<'a> {
+    note_repository: &'a dyn NoteRepository,
+    notebook_repository: &'a dyn NotebookRepository,
+    limits: NoteLimits,
+}
+
+///
+/// The `ImportNotes` struct provides a use case for creating notes from a directory of `.md` and `.txt` files,
+/// including its subdirectories. Hidden files and directories are left out.
+///
+/// The title is taken from the front matter, the first `# ` heading or the file name, in that order.
+/// The dates come from the front matter or, when missing, from the modification time of the file.
+/// Files that cannot become a note are reported instead of stopping the import, and the files
+/// of a directory written by `ExportNotes` are recognized through its manifest, so importing
+/// an export back into the same database does not duplicate the notes. Locked notes of an export
+/// are imported locked, with the same passphrase.
+///
+impl<'a> ImportNotes<'a> {
+    /// Extensions of the files that are imported
+    const EXTENSIONS: [&'static str; 3] = ["md", "markdown", "txt"];
+
+    ///
+    /// Creates a new instance of `ImportNotes`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: The repository the notes are saved to.
+    /// * `notebook_repository`: The repository the notebooks are read from, to place the notes in the notebook named by their front matter.
+    /// * `limits`: The maximum lengths of the title and content, from the settings.
+    ///
+    /// # Returns
+    /// A new `ImportNotes` instance.
+    ///
+    pub fn new(
+        note_repository: &'a dyn NoteRepository,
+        notebook_repository: &'a dyn NotebookRepository,
+        limits: NoteLimits,
+    ) -> Self {
+        ImportNotes {
+            note_repository,
+            notebook_repository,
+            limits,
+        }
+    }
+
+    ///
+    /// Executes the use case to import the files of a directory.
+    ///
+    /// # Arguments
+    /// * `directory`: The directory holding the files.
+    /// * `options`: Whether it is a dry run, whether duplicates are skipped and the default notebook.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ImportedFile>)`: Every file found, sorted by path, with what happened to it.
+    /// * `Err(NoteError)`: If the directory cannot be read or a note cannot be saved.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::Storage` if the directory or its manifest cannot be read,
+    ///   or if the database fails while saving. The notes saved before the failure are kept.
+    ///
+    /// # Example
+    /// ```
+    /// let options = ImportOptions { dry_run: true, skip_duplicates: true, notebook_id: Notebook::INBOX_ID };
+    /// match import_use_case.execute(Path::new("notes"), &options) {
+    ///     Ok(files) => println!("Found {} files", files.len()),
+    ///     Err(err) => println!("Failed to import the notes: {}", err),
+    /// }
+    /// ```
+    ///
+    pub fn execute(&self, directory: &Path, options: &ImportOptions) -> Result<Vec<ImportedFile>, NoteError> {
+        if !directory.is_dir() {
+            return Err(NoteError::Storage(format!("{} is not a directory", directory.display())));
+        }
+
+        let manifest = ExportManifest::load(directory).map_err(NoteError::Storage)?.unwrap_or_default();
+        let notebooks = self.notebook_repository.find_all_notebooks().map_err(NoteError::Storage)?;
+
+        // Fingerprint of every stored content, to spot the duplicates
+        let mut known: HashMap<String, Option<i64>> = HashMap::new();
+        if options.skip_duplicates {
+            for note in self.note_repository.find_all().unwrap_or_default() {
+                known.insert(ImportNotes::fingerprint(note.get_content()), note.get_id());
+            }
+        }
+
+        let mut files = Vec::new();
+        for path in ImportNotes::find_files(directory)? {
+            let relative = path.strip_prefix(directory).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+
+            // A file exported from a note that is still there and unchanged
+            let exported = manifest.notes.iter().find(|entry| entry.file == relative).and_then(|entry| {
+                self.note_repository
+                    .find_by_id(entry.id)
+                    .filter(|note| Note::content_hash(note.get_content()) == entry.content_hash)
+            });
+            if let Some(note) = exported {
+                files.push(ImportedFile {
+                    title: note.get_title().clone(),
+                    outcome: ImportOutcome::AlreadyImported(note.get_id().unwrap_or_default()),
+                    path,
+                });
+                continue;
+            }
+
+            let note = match self.read_note(&path, &notebooks, options.notebook_id) {
+                Ok(note) => note,
+                Err((title, outcome)) => {
+                    files.push(ImportedFile { path, title, outcome });
+                    continue;
+                }
+            };
+
+            let fingerprint = ImportNotes::fingerprint(note.get_content());
+            let outcome = if let Some(id) = known.get(&fingerprint).filter(|_| options.skip_duplicates) {
+                ImportOutcome::Duplicate(*id)
+            } else if options.dry_run {
+                known.insert(fingerprint, None);
+                ImportOutcome::WouldImport
+            } else {
+                let id = self.note_repository.save_with_timestamps(&note).map_err(NoteError::Storage)?;
+                known.insert(fingerprint, Some(id));
+                ImportOutcome::Imported(id)
+            };
+
+            files.push(ImportedFile {
+                path,
+                title: note.get_title().clone(),
+                outcome,
+            });
+        }
+
+        Ok(files)
+    }
+
+    /// Lists the files to import, sorted so the import is repeatable.
+    fn find_files(directory: &Path) -> Result<Vec<PathBuf>, NoteError> {
+        let mut files = Vec::new();
+        let entries = WalkDir::new(directory)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.'));
+
+        for entry in entries {
+            let entry = entry.map_err(|err| NoteError::Storage(format!("cannot read {}: {}", directory.display(), err)))?;
+            let extension = entry
+                .path()
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+
+            if entry.file_type().is_file() && Self::EXTENSIONS.contains(&extension.as_str()) {
+                files.push(entry.into_path());
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Builds the note held by a file.
+    ///
+    /// # Returns
+    /// The note, or the title found so far and the outcome explaining why it cannot be imported.
+    fn read_note(&self, path: &Path, notebooks: &[Notebook], notebook_id: i64) -> Result<Note, (String, ImportOutcome)> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| (String::new(), ImportOutcome::Unreadable(format!("cannot read the file: {}", err))))?;
+        let (front_matter, body) =
+            FrontMatter::parse(&text).map_err(|err| (String::new(), ImportOutcome::Unreadable(err)))?;
+        let front_matter = front_matter.unwrap_or_default();
+
+        let content = body.trim().to_string();
+        let title = front_matter
+            .title
+            .clone()
+            .or_else(|| ImportNotes::heading(&content))
+            .unwrap_or_else(|| path.file_stem().unwrap_or_default().to_string_lossy().trim().to_string());
+
+        let invalid = |err: NoteError| (title.clone(), ImportOutcome::Invalid(err));
+        let mut note = if front_matter.locked {
+            // A locked note exported by `ExportNotes` keeps its sealed content, and stays locked
+            if !NoteCipher::is_sealed(&content) {
+                let reason = String::from("the note is marked as locked, but its content is not sealed");
+                return Err((title, ImportOutcome::Unreadable(reason)));
+            }
+            Note::create_locked(&title, content, &self.limits).map_err(invalid)?
+        } else {
+            Note::create(&title, &content, &self.limits).map_err(invalid)?
+        };
+        for tag in &front_matter.tags {
+            note.add_tag(tag).map_err(invalid)?;
+        }
+
+        let modified = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .map(DateTime::<Utc>::from)
+            .unwrap_or_else(|_| Utc::now());
+        let updated_at = front_matter.updated_at.unwrap_or(modified);
+        note.set_created_at(front_matter.created_at.unwrap_or(updated_at));
+        note.set_updated_at(updated_at);
+
+        let notebook_id = front_matter
+            .notebook
+            .as_deref()
+            .and_then(|name| {
+                notebooks
+                    .iter()
+                    .filter_map(Notebook::get_id)
+                    .find(|id| Notebook::path_of(notebooks, *id) == name)
+            })
+            .unwrap_or(notebook_id);
+
+        Ok(note.with_notebook(notebook_id))
+    }
+
+    /// The text of the first `# ` heading, outside the code blocks.
+    fn heading(content: &str) -> Option<String> {
+        let mut in_code = false;
+        for line in content.lines() {
+            let line = line.trim_start();
+            if line.starts_with("```") || line.starts_with("~~~") {
+                in_code = !in_code;
+            } else if !in_code {
+                if let Some(heading) = line.strip_prefix("# ") {
+                    return Some(heading.trim().trim_end_matches('#').trim().to_string()).filter(|heading| !heading.is_empty());
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Fingerprint of a content, leaving out the blank lines and spaces around it.
+    fn fingerprint(content: &str) -> String {
+        Note::content_hash(content.trim())
+    }
+}
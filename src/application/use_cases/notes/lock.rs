@@ -0,0 +1,87 @@
+use crate::application::note_cipher::NoteCipher;
+use crate::application::palette::Palette;
+use crate::domain::entities::note::Note;
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::note_repository::NoteRepository;
+
+pub struct LockNote<'a> {
+    note_repository: &'a dyn NoteRepository,
+}
+
+///
+/// The `LockNote` struct provides a use case for locking a note: its content is sealed with a passphrase,
+/// see `NoteCipher`, before it is written back through `NoteRepository::update`, so the plain content
+/// never reaches the storage again. The title stays readable to find the note.
+///
+/// Locked notes are left out of the full-text search, and the storage forgets the previous
+/// revisions of the note, as they hold the plain content.
+///
+impl<'a> LockNote<'a> {
+    ///
+    /// Creates a new instance of `LockNote`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
+    ///
+    /// # Returns
+    /// A new `LockNote` instance.
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
+        LockNote { note_repository }
+    }
+
+    ///
+    /// Executes the use case to lock a note.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the note to lock. Must be greater than 0.
+    /// * `passphrase`: The passphrase that will open the note, of at least `NoteCipher::MIN_PASSPHRASE_LENGTH` characters.
+    ///
+    /// # Returns
+    /// * `Ok(Note)`: The locked note, holding the sealed content.
+    /// * `Err(NoteError)`: If the note cannot be locked.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidId` if the `id` is less than or equal to 0.
+    /// * Returns `NoteError::InvalidPassphrase` if the passphrase is too short.
+    /// * Returns `NoteError::NotFound` if the note with the specified `id` does not exist.
+    /// * Returns `NoteError::Locked` if the note is already locked.
+    /// * Returns `NoteError::Storage` if the content cannot be sealed or the note cannot be saved.
+    ///
+    /// # Example
+    /// ```
+    /// match lock_use_case.execute(3, "correct horse battery") {
+    ///     Ok(note) => assert!(note.is_locked()),
+    ///     Err(err) => println!("Failed to lock the note: {}", err),
+    /// }
+    /// ```
+    ///
+    pub fn execute(&self, id: i64, passphrase: &str) -> Result<Note, NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
+        }
+
+        NoteCipher::validate_passphrase(passphrase)?;
+
+        let mut note = match self.note_repository.find_by_id(id) {
+            Some(note) => note,
+            None => return Err(NoteError::NotFound(format!("id {}", id))),
+        };
+        if note.is_locked() {
+            return Err(NoteError::Locked(id));
+        }
+
+        let sealed = NoteCipher::seal(note.get_content(), passphrase)?;
+        note.lock(sealed);
+
+        let locked = self.note_repository.update(note).map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Locked note with id {}",
+            Palette::current().success(">"),
+            Palette::current().accent(id.to_string())
+        );
+
+        Ok(locked)
+    }
+}
@@ -3,4 +3,10 @@ pub mod create;
 pub mod delete;
 pub mod find_by_id;
 pub mod update;
-pub mod find_all;
\ No newline at end of file
+pub mod find_all;
+pub mod search;
+pub mod export;
+pub mod import;
+pub mod lock;
+pub mod reveal;
+pub mod unlock;
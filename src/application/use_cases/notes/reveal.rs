@@ -0,0 +1,71 @@
+use crate::application::note_cipher::NoteCipher;
+use crate::domain::entities::note::Note;
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::note_repository::NoteRepository;
+
+pub struct RevealNote<'a> {
+    note_repository: &'a dyn NoteRepository,
+}
+
+///
+/// The `RevealNote` struct provides a use case for reading a locked note: its content is opened
+/// with the passphrase in memory only, the stored note stays locked.
+///
+impl<'a> RevealNote<'a> {
+    ///
+    /// Creates a new instance of `RevealNote`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
+    ///
+    /// # Returns
+    /// A new `RevealNote` instance.
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
+        RevealNote { note_repository }
+    }
+
+    ///
+    /// Executes the use case to reveal the content of a locked note.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the note. Must be greater than 0.
+    /// * `passphrase`: The passphrase the note was locked with.
+    ///
+    /// # Returns
+    /// * `Ok(Note)`: A copy of the note holding the plain content, no longer marked as locked.
+    ///   Saving it would unlock the stored note, see `UnlockNote`.
+    /// * `Err(NoteError)`: If the note cannot be opened.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidId` if the `id` is less than or equal to 0.
+    /// * Returns `NoteError::NotFound` if the note with the specified `id` does not exist.
+    /// * Returns `NoteError::NotLocked` if the note is not locked.
+    /// * Returns `NoteError::WrongPassphrase` if the passphrase does not open the note.
+    /// * Returns `NoteError::Storage` if the sealed content is damaged.
+    ///
+    /// # Example
+    /// ```
+    /// let note = RevealNote::new(&note_repository).execute(3, "correct horse battery")?;
+    /// println!("{}", note.get_content());
+    /// ```
+    ///
+    pub fn execute(&self, id: i64, passphrase: &str) -> Result<Note, NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
+        }
+
+        let mut note = match self.note_repository.find_by_id(id) {
+            Some(note) => note,
+            None => return Err(NoteError::NotFound(format!("id {}", id))),
+        };
+        if !note.is_locked() {
+            return Err(NoteError::NotLocked(id));
+        }
+
+        let content = NoteCipher::open(note.get_content(), passphrase)?;
+        note.unlock(content);
+
+        Ok(note)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::domain::{
+    entities::search_result::SearchResult, errors::note_error::NoteError,
+    repositories::note_repository::NoteRepository,
+};
+
+pub struct SearchNotes<'a> {
+    note_repository: &'a dyn NoteRepository,
+}
+
+///
+/// The `SearchNotes` struct provides a use case for full-text searching over the title and content of the notes.
+/// Queries support phrases (`"release notes"`), prefix matching (`deploy*`) and the boolean operators
+/// `AND`, `OR` and `NOT`. Results are ranked by relevance.
+///
+/// Any other word is searched as it is written, so `hello-world`, `c++` or `don't` need no quoting:
+/// every word is quoted before reaching FTS5, which would otherwise read `-`, `+` or `'` as syntax.
+///
+impl<'a> SearchNotes<'a> {
+    /// Maximum number of results returned when no limit is given
+    pub const DEFAULT_LIMIT: usize = 50;
+
+    ///
+    /// Creates a new instance of `SearchNotes`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
+    ///
+    /// # Returns
+    /// A new `SearchNotes` instance.
+    ///
+    /// # Example
+    /// ```
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
+    /// let search_use_case = SearchNotes::new(&note_repository);
+    /// ```
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
+        SearchNotes { note_repository }
+    }
+
+    ///
+    /// Executes the use case to search notes.
+    ///
+    /// # Arguments
+    /// * `query`: The search query. Must not be empty.
+    ///
+    /// # Returns
+    /// A `Result` containing the matching notes, best matches first, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidQuery` if the query is empty, has unbalanced quotes, starts or ends
+    ///   with an operator, has two operators in a row, or cannot be understood by the storage.
+    /// * Returns `NoteError::NotFound` if no notes match the query.
+    /// * Returns `NoteError::Storage` if the search fails.
+    ///
+    /// # Example
+    /// ```
+    /// match search_use_case.execute("\"release notes\" OR deploy*") {
+    ///     Ok(results) => println!("Found {} notes", results.len()),
+    ///     Err(err) => println!("Failed to search notes: {}", err),
+    /// }
+    /// ```
+    ///
+    pub fn execute(&self, query: &str) -> Result<Vec<SearchResult>, NoteError> {
+        let query = query.trim();
+        SearchNotes::validate(query)?;
+
+        let results = self
+            .note_repository
+            .search(&SearchNotes::escape(query), SearchNotes::DEFAULT_LIMIT)
+            .map_err(|err| match err.starts_with(SearchResult::INVALID_QUERY_ERROR) {
+                true => NoteError::InvalidQuery(String::from(
+                    "check its quotes and its AND / OR / NOT operators",
+                )),
+                false => NoteError::Storage(err),
+            })?;
+
+        if results.is_empty() {
+            return Err(NoteError::NotFound(format!("text matching `{}`", query)));
+        }
+
+        Ok(results)
+    }
+
+    ///
+    /// Checks the most common mistakes in a query before sending it to the storage.
+    ///
+    fn validate(query: &str) -> Result<(), NoteError> {
+        if query.is_empty() {
+            return Err(NoteError::InvalidQuery(String::from("the query is empty")));
+        }
+
+        if query.matches('"').count() % 2 != 0 {
+            return Err(NoteError::InvalidQuery(String::from("a quote is not closed")));
+        }
+
+        let words: Vec<&str> = query.split_whitespace().collect();
+        let is_operator = |word: &&str| matches!(*word, "AND" | "OR" | "NOT");
+
+        if words.first().is_some_and(is_operator) || words.last().is_some_and(is_operator) {
+            return Err(NoteError::InvalidQuery(String::from(
+                "the query cannot start or end with AND, OR or NOT",
+            )));
+        }
+
+        if words.windows(2).any(|pair| is_operator(&pair[0]) && is_operator(&pair[1])) {
+            return Err(NoteError::InvalidQuery(String::from(
+                "AND, OR and NOT must be followed by a word or a phrase",
+            )));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Rewrites a query validated by `validate` in the syntax of FTS5: phrases and the operators
+    /// are kept, and every other word is quoted so its punctuation is taken as text, keeping
+    /// a trailing `*` outside the quotes as a prefix search (`deploy*` becomes `"deploy"*`).
+    ///
+    fn escape(query: &str) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let mut chars = query.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            if c == '"' {
+                let phrase: String = chars.by_ref().take_while(|c| *c != '"').collect();
+                let prefix = chars.next_if_eq(&'*').is_some();
+                parts.push(format!("\"{}\"{}", phrase, if prefix { "*" } else { "" }));
+                continue;
+            }
+
+            let mut word = String::from(c);
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace() && *c != '"') {
+                word.push(c);
+            }
+
+            if matches!(word.as_str(), "AND" | "OR" | "NOT") {
+                parts.push(word);
+                continue;
+            }
+
+            let stem = word.trim_end_matches('*');
+            if !stem.is_empty() {
+                let prefix = stem.len() < word.len();
+                parts.push(format!("\"{}\"{}", stem, if prefix { "*" } else { "" }));
+            }
+        }
+
+        parts.join(" ")
+    }
+}
@@ -0,0 +1,74 @@
+use crate::application::note_cipher::NoteCipher;
+use crate::application::palette::Palette;
+use crate::domain::entities::note::Note;
+use crate::domain::errors::note_error::NoteError;
+use crate::domain::repositories::note_repository::NoteRepository;
+
+pub struct UnlockNote<'a> {
+    note_repository: &'a dyn NoteRepository,
+}
+
+///
+/// The `UnlockNote` struct provides a use case for removing the lock of a note for good:
+/// its content is opened with the passphrase and saved back in plain text, so it can be
+/// edited and found by the full-text search again. To read a locked note without unlocking it, see `RevealNote`.
+///
+impl<'a> UnlockNote<'a> {
+    ///
+    /// Creates a new instance of `UnlockNote`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
+    ///
+    /// # Returns
+    /// A new `UnlockNote` instance.
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
+        UnlockNote { note_repository }
+    }
+
+    ///
+    /// Executes the use case to unlock a note.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the note to unlock. Must be greater than 0.
+    /// * `passphrase`: The passphrase the note was locked with.
+    ///
+    /// # Returns
+    /// * `Ok(Note)`: The unlocked note, holding the plain content.
+    /// * `Err(NoteError)`: If the note cannot be unlocked.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidId` if the `id` is less than or equal to 0.
+    /// * Returns `NoteError::NotFound` if the note with the specified `id` does not exist.
+    /// * Returns `NoteError::NotLocked` if the note is not locked.
+    /// * Returns `NoteError::WrongPassphrase` if the passphrase does not open the note.
+    /// * Returns `NoteError::Storage` if the sealed content is damaged or the note cannot be saved.
+    ///
+    pub fn execute(&self, id: i64, passphrase: &str) -> Result<Note, NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
+        }
+
+        let mut note = match self.note_repository.find_by_id(id) {
+            Some(note) => note,
+            None => return Err(NoteError::NotFound(format!("id {}", id))),
+        };
+        if !note.is_locked() {
+            return Err(NoteError::NotLocked(id));
+        }
+
+        let content = NoteCipher::open(note.get_content(), passphrase)?;
+        note.unlock(content);
+
+        let unlocked = self.note_repository.update(note).map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Unlocked note with id {}",
+            Palette::current().success(">"),
+            Palette::current().accent(id.to_string())
+        );
+
+        Ok(unlocked)
+    }
+}
@@ -1,9 +1,12 @@
-use crate::domain::entities::note::Note;
+use crate::application::palette::Palette;
+use crate::domain::entities::{note::{Note, NoteLimits}, tag::Tag};
+use crate::domain::errors::note_error::NoteError;
 use crate::domain::repositories::note_repository::NoteRepository;
-use ansi_term::Colour;
+use std::collections::BTreeSet;
 
 pub struct UpdateNote<'a> {
-    note_repository: &'a NoteRepository,
+    note_repository: &'a dyn NoteRepository,
+    limits: NoteLimits,
 }
 
 ///
@@ -16,19 +19,23 @@ impl<'a> UpdateNote<'a> {
     /// Creates a new instance of `UpdateNote`.
     ///
     /// # Arguments
-    /// * `note_repository`: A reference to an instance of `NoteRepository` to interact with the note storage.
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
+    /// * `limits`: The maximum lengths of the title and content, from the settings.
     ///
     /// # Returns
     /// A new `UpdateNote` instance.
     ///
     /// # Example
     /// ```
-    /// let note_repository = NoteRepository::new();
-    /// let update_use_case = UpdateNote::new(&note_repository);
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
+    /// let update_use_case = UpdateNote::new(&note_repository, NoteLimits::default());
     /// ```
     ///
-    pub fn new(note_repository: &'a NoteRepository) -> Self {
-        UpdateNote { note_repository }
+    pub fn new(note_repository: &'a dyn NoteRepository, limits: NoteLimits) -> Self {
+        UpdateNote {
+            note_repository,
+            limits,
+        }
     }
 
     ///
@@ -36,19 +43,20 @@ impl<'a> UpdateNote<'a> {
     ///
     /// # Arguments
     /// * `id`: The ID of the note to be updated. Must be greater than 0.
-    /// * `title`: A reference to a `String` containing the new title for the note. Must not be empty and cannot exceed 100 characters.
-    /// * `content`: A reference to a `String` containing the new content for the note. Must not be empty and cannot exceed 1000 characters.
+    /// * `title`: A reference to a `String` containing the new title for the note. Must not be empty and cannot exceed the title limit.
+    /// * `content`: A reference to a `String` containing the new content for the note. Must not be empty and cannot exceed the content limit.
     ///
     /// # Returns
     /// * `Ok(Note)`: If the note is successfully updated.
-    /// * `Err(String)`: If there is an error during the update process, such as invalid input or note not found.
+    /// * `Err(NoteError)`: If there is an error during the update process, such as invalid input or note not found.
     ///
     /// # Errors
-    /// * Returns an error if the `id` is less than or equal to 0.
-    /// * Returns an error if the `title` or `content` is empty.
-    /// * Panics if the `title` exceeds 100 characters or the `content` exceeds 1000 characters.
-    /// * Returns an error if the note with the specified `id` does not exist.
-    /// * Returns an error if there is an issue updating the note in the repository.
+    /// * Returns `NoteError::InvalidId` if the `id` is less than or equal to 0.
+    /// * Returns `NoteError::EmptyTitle` or `NoteError::EmptyContent` if the `title` or `content` is empty.
+    /// * Returns `NoteError::TitleTooLong` or `NoteError::ContentTooLong` if the `title` or the `content` exceed their limit.
+    /// * Returns `NoteError::NotFound` if the note with the specified `id` does not exist.
+    /// * Returns `NoteError::Locked` if the note is locked and `content` is not its sealed content.
+    /// * Returns `NoteError::Storage` if there is an issue updating the note in the repository.
     ///
     /// # Example
     /// ```
@@ -61,42 +69,84 @@ impl<'a> UpdateNote<'a> {
     /// }
     /// ```
     ///
-    pub fn execute(&self, id: i64, title: &String, content: &String) -> Result<Note, String> {
-        if id <= 0 {
-            return Err("Invalid note ID".to_string());
-        }
+    pub fn execute(&self, id: i64, title: &String, content: &String) -> Result<Note, NoteError> {
+        self.update(id, title, content, None)
+    }
 
-        if title.is_empty() || content.is_empty() {
-            return Err("Title and content cannot be empty".to_string());
-        }
+    ///
+    /// Executes the use case to update a note, replacing its tags as well.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the note to be updated. Must be greater than 0.
+    /// * `title`: The new title for the note.
+    /// * `content`: The new content for the note.
+    /// * `tags`: The names of every tag the note must carry, the rest are detached.
+    ///
+    /// # Returns
+    /// * `Ok(Note)`: If the note is successfully updated.
+    /// * `Err(NoteError)`: If there is an error during the update process.
+    ///
+    /// # Errors
+    /// The same as `execute`, plus `NoteError::InvalidTag` if a tag name is not valid.
+    /// Nothing is saved when any value is not valid.
+    ///
+    pub fn execute_with_tags(
+        &self,
+        id: i64,
+        title: &String,
+        content: &String,
+        tags: &[String],
+    ) -> Result<Note, NoteError> {
+        let tags = tags
+            .iter()
+            .map(|tag| Tag::normalize(tag))
+            .collect::<Result<BTreeSet<String>, NoteError>>()?;
 
-        if title.len() > 100 {
-            panic!("Title cannot exceed 100 characters");
-        }
+        self.update(id, title, content, Some(tags))
+    }
 
-        if content.len() > 1000 {
-            panic!("Content cannot exceed 1000 characters");
+    fn update(
+        &self,
+        id: i64,
+        title: &String,
+        content: &String,
+        tags: Option<BTreeSet<String>>,
+    ) -> Result<Note, NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
         }
 
+        Note::validate_title(title, &self.limits)?;
+
         let mut note = match self.note_repository.find_by_id(id) {
             Some(note) => note,
-            None => return Err(format!("Note with id {} not found", id)),
+            None => return Err(NoteError::NotFound(format!("id {}", id))),
         };
 
-        note.set_title(title.clone());
-        note.set_content(content.clone());
+        // The sealed content of a locked note is kept as it is, only its title and tags can change
+        if note.is_locked() && content != note.get_content() {
+            return Err(NoteError::Locked(id));
+        }
+
+        note.set_title(title.clone(), &self.limits)?;
+        if !note.is_locked() {
+            note.set_content(content.clone(), &self.limits)?;
+        }
         note.set_updated_at(chrono::Utc::now());
+        if let Some(tags) = tags {
+            note = note.with_tags(tags);
+        }
 
         match self.note_repository.update(note) {
-            updated_note => {
+            Ok(updated_note) => {
                 println!(
                     "{} Note with id '{}' updated successfully",
-                    Colour::Green.paint(">"),
-                    Colour::Blue.paint(id.to_string())
+                    Palette::current().success(">"),
+                    Palette::current().accent(id.to_string())
                 );
-                updated_note
+                Ok(updated_note)
             }
-            e => Err(format!("Error updating note: {:?}", e)),
+            Err(e) => Err(NoteError::Storage(e)),
         }
     }
 }
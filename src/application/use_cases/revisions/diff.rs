@@ -0,0 +1,112 @@
+use crate::domain::{
+    entities::revision::Revision, errors::note_error::NoteError,
+    repositories::revision_repository::RevisionRepository,
+};
+use chrono::prelude::*;
+use similar::TextDiff;
+
+pub struct DiffRevisions<'a> {
+    revision_repository: &'a dyn RevisionRepository,
+}
+
+///
+/// The `DiffRevisions` struct provides a use case for comparing two revisions of a note.
+/// The title and content of each revision are compared line by line and the result
+/// is returned as a unified diff, the older revision always being the original side.
+///
+impl<'a> DiffRevisions<'a> {
+    /// Number of unchanged lines shown around every change
+    pub const CONTEXT_LINES: usize = 3;
+
+    ///
+    /// Creates a new instance of `DiffRevisions`.
+    ///
+    /// # Arguments
+    /// * `revision_repository`: A reference to any implementation of `RevisionRepository` to interact with the revision history.
+    ///
+    /// # Returns
+    /// A new `DiffRevisions` instance.
+    ///
+    pub fn new(revision_repository: &'a dyn RevisionRepository) -> Self {
+        DiffRevisions { revision_repository }
+    }
+
+    ///
+    /// Executes the use case to compare two revisions.
+    ///
+    /// # Arguments
+    /// * `from_id`: The ID of one of the revisions.
+    /// * `to_id`: The ID of the other revision. Both must belong to the same note.
+    ///
+    /// # Returns
+    /// A `Result` containing the unified diff, empty if both revisions are identical.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidId` if any ID is less than or equal to 0.
+    /// * Returns `NoteError::NotFound` if a revision does not exist or they belong to different notes.
+    /// * Returns `NoteError::Storage` if the revisions cannot be read.
+    ///
+    /// # Example
+    /// ```
+    /// let diff = DiffRevisions::new(&note_repository).execute(3, 7)?;
+    /// println!("{}", diff);
+    /// ```
+    ///
+    pub fn execute(&self, from_id: i64, to_id: i64) -> Result<String, NoteError> {
+        let from = self.find(from_id)?;
+        let to = self.find(to_id)?;
+
+        if from.get_note_id() != to.get_note_id() {
+            return Err(NoteError::NotFound(format!(
+                "revisions {} and {} in the same note",
+                from_id, to_id
+            )));
+        }
+
+        // Always show the changes from the older revision to the newer one
+        let (old, new) = if from.get_id() <= to.get_id() { (from, to) } else { (to, from) };
+
+        let old_text = DiffRevisions::as_text(&old);
+        let new_text = DiffRevisions::as_text(&new);
+        if old_text == new_text {
+            return Ok(String::new());
+        }
+
+        let diff = TextDiff::from_lines(old_text.as_str(), new_text.as_str());
+        Ok(diff
+            .unified_diff()
+            .context_radius(DiffRevisions::CONTEXT_LINES)
+            .header(&DiffRevisions::header(&old), &DiffRevisions::header(&new))
+            .to_string())
+    }
+
+    /// Finds a revision, turning a missing one into `NoteError::NotFound`.
+    fn find(&self, id: i64) -> Result<Revision, NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
+        }
+
+        self.revision_repository
+            .find_revision(id)
+            .map_err(NoteError::Storage)?
+            .ok_or_else(|| NoteError::NotFound(format!("revision {}", id)))
+    }
+
+    /// Lays out a revision as the text being compared: the title, a blank line and the content.
+    fn as_text(revision: &Revision) -> String {
+        let mut text = format!("{}\n\n{}", revision.get_title(), revision.get_content());
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Builds the file header of a revision in the unified diff.
+    fn header(revision: &Revision) -> String {
+        format!(
+            "revision {} ({})",
+            revision.get_id(),
+            revision.get_created_at().with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
+        )
+    }
+}
@@ -0,0 +1,48 @@
+use crate::domain::{
+    entities::revision::Revision, errors::note_error::NoteError,
+    repositories::revision_repository::RevisionRepository,
+};
+
+pub struct FindRevisions<'a> {
+    revision_repository: &'a dyn RevisionRepository,
+}
+
+impl<'a> FindRevisions<'a> {
+    /// Creates a new instance of `FindRevisions`.
+    ///
+    /// # Arguments
+    /// * `revision_repository`: A reference to any implementation of `RevisionRepository` to interact with the revision history.
+    ///
+    /// # Returns
+    /// A new `FindRevisions` instance.
+    ///
+    pub fn new(revision_repository: &'a dyn RevisionRepository) -> Self {
+        FindRevisions { revision_repository }
+    }
+
+    /// Executes the use case to list the revisions of a note.
+    ///
+    /// # Arguments
+    /// * `note_id`: The ID of the note. Must be greater than 0.
+    ///
+    /// # Returns
+    /// A `Result` containing the revisions, most recent first, or a `NoteError` if the ID is invalid,
+    /// the note has no revisions or the storage fails.
+    ///
+    pub fn execute(&self, note_id: i64) -> Result<Vec<Revision>, NoteError> {
+        if note_id <= 0 {
+            return Err(NoteError::InvalidId(note_id));
+        }
+
+        let revisions = self
+            .revision_repository
+            .find_revisions(note_id)
+            .map_err(NoteError::Storage)?;
+
+        if revisions.is_empty() {
+            return Err(NoteError::NotFound(format!("revisions for id {}", note_id)));
+        }
+
+        Ok(revisions)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod diff;
+pub mod find_all;
+pub mod restore;
\ No newline at end of file
@@ -0,0 +1,104 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::note::{Note, NoteLimits},
+    errors::note_error::NoteError,
+    repositories::{note_repository::NoteRepository, revision_repository::RevisionRepository},
+};
+
+pub struct RestoreRevision<'a> {
+    note_repository: &'a dyn NoteRepository,
+    revision_repository: &'a dyn RevisionRepository,
+    limits: NoteLimits,
+}
+
+///
+/// The `RestoreRevision` struct provides a use case for rolling a note back to an earlier revision.
+/// The title and content of the revision are saved as the current version of the note,
+/// which records a new revision, so the rollback itself can be undone.
+///
+impl<'a> RestoreRevision<'a> {
+    ///
+    /// Creates a new instance of `RestoreRevision`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to update the note.
+    /// * `revision_repository`: A reference to any implementation of `RevisionRepository` to read the revision.
+    /// * `limits`: The maximum lengths of the title and content, from the settings.
+    ///
+    /// # Returns
+    /// A new `RestoreRevision` instance.
+    ///
+    pub fn new(
+        note_repository: &'a dyn NoteRepository,
+        revision_repository: &'a dyn RevisionRepository,
+        limits: NoteLimits,
+    ) -> Self {
+        RestoreRevision {
+            note_repository,
+            revision_repository,
+            limits,
+        }
+    }
+
+    ///
+    /// Executes the use case to restore a note to one of its revisions.
+    ///
+    /// # Arguments
+    /// * `note_id`: The ID of the note. Must be greater than 0.
+    /// * `revision_id`: The ID of the revision to restore. Must belong to the note.
+    ///
+    /// # Returns
+    /// A `Result` containing the restored `Note`, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidId` if any ID is less than or equal to 0.
+    /// * Returns `NoteError::NotFound` if the note or the revision does not exist, or the revision belongs to another note.
+    /// * Returns `NoteError::TitleTooLong` or `NoteError::ContentTooLong` if the revision no longer fits the limits.
+    /// * Returns `NoteError::Locked` if the note is locked, its content can only change once unlocked.
+    /// * Returns `NoteError::Storage` if the note cannot be updated.
+    ///
+    pub fn execute(&self, note_id: i64, revision_id: i64) -> Result<Note, NoteError> {
+        if note_id <= 0 {
+            return Err(NoteError::InvalidId(note_id));
+        }
+        if revision_id <= 0 {
+            return Err(NoteError::InvalidId(revision_id));
+        }
+
+        let revision = match self
+            .revision_repository
+            .find_revision(revision_id)
+            .map_err(NoteError::Storage)?
+        {
+            Some(revision) if revision.get_note_id() == note_id => revision,
+            _ => {
+                return Err(NoteError::NotFound(format!(
+                    "revision {} for id {}",
+                    revision_id, note_id
+                )));
+            }
+        };
+
+        let mut note = match self.note_repository.find_by_id(note_id) {
+            Some(note) => note,
+            None => return Err(NoteError::NotFound(format!("id {}", note_id))),
+        };
+        if note.is_locked() {
+            return Err(NoteError::Locked(note_id));
+        }
+
+        note.set_title(revision.get_title().clone(), &self.limits)?;
+        note.set_content(revision.get_content().clone(), &self.limits)?;
+
+        let restored = self.note_repository.update(note).map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Restored note with id {} to revision {}",
+            Palette::current().success(">"),
+            Palette::current().accent(note_id.to_string()),
+            Palette::current().accent(revision_id.to_string())
+        );
+
+        Ok(restored)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::note::Note, errors::note_error::NoteError,
+    repositories::note_repository::NoteRepository,
+};
+
+pub struct AddTag<'a> {
+    note_repository: &'a dyn NoteRepository,
+}
+
+///
+/// The `AddTag` struct provides a use case for attaching a tag to an existing note.
+/// The tag is created on the fly if no other note uses it yet.
+///
+impl<'a> AddTag<'a> {
+    ///
+    /// Creates a new instance of `AddTag`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
+    ///
+    /// # Returns
+    /// A new `AddTag` instance.
+    ///
+    /// # Example
+    /// ```
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", None)?;
+    /// let add_tag_use_case = AddTag::new(&note_repository);
+    /// ```
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
+        AddTag { note_repository }
+    }
+
+    ///
+    /// Executes the use case to attach a tag to a note.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the note. Must be greater than 0.
+    /// * `tag`: The name of the tag, e.g. `#work`. It is normalized before being stored.
+    ///
+    /// # Returns
+    /// A `Result` containing the updated `Note`, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidId` if the `id` is less than or equal to 0.
+    /// * Returns `NoteError::InvalidTag` if the tag name is not valid.
+    /// * Returns `NoteError::NotFound` if the note does not exist.
+    /// * Returns `NoteError::Storage` if the note cannot be saved.
+    ///
+    /// # Example
+    /// ```
+    /// match add_tag_use_case.execute(1, "work") {
+    ///     Ok(note) => println!("Tags: {:?}", note.get_tags()),
+    ///     Err(err) => println!("Failed to tag note: {}", err),
+    /// }
+    /// ```
+    ///
+    pub fn execute(&self, id: i64, tag: &str) -> Result<Note, NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
+        }
+
+        let mut note = match self.note_repository.find_by_id(id) {
+            Some(note) => note,
+            None => return Err(NoteError::NotFound(format!("id {}", id))),
+        };
+
+        if !note.add_tag(tag)? {
+            return Ok(note);
+        }
+
+        let note = self.note_repository.update(note).map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Tagged note {} with `{}`",
+            Palette::current().success(">"),
+            Palette::current().accent(id.to_string()),
+            tag
+        );
+
+        Ok(note)
+    }
+}
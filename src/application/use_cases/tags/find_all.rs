@@ -0,0 +1,41 @@
+use crate::domain::{
+    entities::tag::Tag, errors::note_error::NoteError,
+    repositories::tag_repository::TagRepository,
+};
+
+pub struct FindAllTags<'a> {
+    tag_repository: &'a dyn TagRepository,
+}
+
+impl<'a> FindAllTags<'a> {
+    /// Creates a new instance of `FindAllTags`.
+    ///
+    /// # Arguments
+    /// * `tag_repository`: A reference to any implementation of `TagRepository` to interact with the tag storage.
+    ///
+    /// # Returns
+    /// A new `FindAllTags` instance.
+    ///
+    pub fn new(tag_repository: &'a dyn TagRepository) -> Self {
+        FindAllTags { tag_repository }
+    }
+
+    /// Executes the use case to find every tag in use.
+    ///
+    /// # Returns
+    /// A `Result` containing the tags sorted by name with their note counts,
+    /// or a `NoteError::NotFound` if no note is tagged.
+    ///
+    pub fn execute(&self) -> Result<Vec<Tag>, NoteError> {
+        let tags = self
+            .tag_repository
+            .find_all_tags()
+            .map_err(NoteError::Storage)?;
+
+        if tags.is_empty() {
+            return Err(NoteError::NotFound(String::from("tags")));
+        }
+
+        Ok(tags)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::domain::{
+    entities::{
+        note::Note,
+        tag::{Tag, TagMatch},
+    },
+    errors::note_error::NoteError,
+    repositories::tag_repository::TagRepository,
+};
+
+pub struct FindNotesByTags<'a> {
+    tag_repository: &'a dyn TagRepository,
+}
+
+///
+/// The `FindNotesByTags` struct provides a use case for listing the notes carrying some tags.
+/// Tags can be combined so the notes must carry all of them (AND) or any of them (OR).
+///
+impl<'a> FindNotesByTags<'a> {
+    ///
+    /// Creates a new instance of `FindNotesByTags`.
+    ///
+    /// # Arguments
+    /// * `tag_repository`: A reference to any implementation of `TagRepository` to interact with the tag storage.
+    ///
+    /// # Returns
+    /// A new `FindNotesByTags` instance.
+    ///
+    pub fn new(tag_repository: &'a dyn TagRepository) -> Self {
+        FindNotesByTags { tag_repository }
+    }
+
+    ///
+    /// Executes the use case to find notes by tags.
+    ///
+    /// # Arguments
+    /// * `tags`: The names of the tags. Must not be empty.
+    /// * `mode`: `TagMatch::All` to require every tag, `TagMatch::Any` to require at least one.
+    ///
+    /// # Returns
+    /// A `Result` containing the matching notes, most recently updated first, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidTag` if no tag is given or a tag name is not valid.
+    /// * Returns `NoteError::NotFound` if no notes match.
+    /// * Returns `NoteError::Storage` if the notes cannot be read.
+    ///
+    /// # Example
+    /// ```
+    /// let tags = vec![String::from("work"), String::from("urgent")];
+    /// match find_notes_use_case.execute(&tags, TagMatch::All) {
+    ///     Ok(notes) => println!("Found {} notes", notes.len()),
+    ///     Err(err) => println!("Failed to find notes: {}", err),
+    /// }
+    /// ```
+    ///
+    pub fn execute(&self, tags: &[String], mode: TagMatch) -> Result<Vec<Note>, NoteError> {
+        if tags.is_empty() {
+            return Err(NoteError::InvalidTag(String::from("pick at least one tag")));
+        }
+
+        let tags = tags
+            .iter()
+            .map(|tag| Tag::normalize(tag))
+            .collect::<Result<Vec<String>, NoteError>>()?;
+
+        let notes = self
+            .tag_repository
+            .find_notes_by_tags(&tags, mode)
+            .map_err(NoteError::Storage)?;
+
+        if notes.is_empty() {
+            let separator = match mode {
+                TagMatch::All => " AND ",
+                TagMatch::Any => " OR ",
+            };
+            return Err(NoteError::NotFound(format!("tags {}", tags.join(separator))));
+        }
+
+        Ok(notes)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::tag::Tag, errors::note_error::NoteError,
+    repositories::tag_repository::TagRepository,
+};
+
+pub struct MergeTags<'a> {
+    tag_repository: &'a dyn TagRepository,
+}
+
+///
+/// The `MergeTags` struct provides a use case for merging several tags into a single one.
+/// Every note carrying any of the merged tags ends up carrying the target tag.
+///
+impl<'a> MergeTags<'a> {
+    ///
+    /// Creates a new instance of `MergeTags`.
+    ///
+    /// # Arguments
+    /// * `tag_repository`: A reference to any implementation of `TagRepository` to interact with the tag storage.
+    ///
+    /// # Returns
+    /// A new `MergeTags` instance.
+    ///
+    pub fn new(tag_repository: &'a dyn TagRepository) -> Self {
+        MergeTags { tag_repository }
+    }
+
+    ///
+    /// Executes the use case to merge tags.
+    ///
+    /// # Arguments
+    /// * `sources`: The names of the tags to merge. Must not be empty.
+    /// * `target`: The name of the resulting tag. It can be one of the sources or a new name.
+    ///
+    /// # Returns
+    /// A `Result` containing the number of notes affected, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidTag` if no source is given or any of the names is not valid.
+    /// * Returns `NoteError::NotFound` if no note carries any of the source tags.
+    /// * Returns `NoteError::Storage` if the merge fails.
+    ///
+    /// # Example
+    /// ```
+    /// let sources = vec![String::from("todo"), String::from("to-do")];
+    /// merge_tags_use_case.execute(&sources, "todo")?;
+    /// ```
+    ///
+    pub fn execute(&self, sources: &[String], target: &str) -> Result<usize, NoteError> {
+        if sources.is_empty() {
+            return Err(NoteError::InvalidTag(String::from("pick at least one tag to merge")));
+        }
+
+        let target = Tag::normalize(target)?;
+        let sources = sources
+            .iter()
+            .map(|source| Tag::normalize(source))
+            .collect::<Result<Vec<String>, NoteError>>()?;
+
+        let affected = self
+            .tag_repository
+            .merge_tags(&sources, &target)
+            .map_err(NoteError::Storage)?;
+
+        if affected == 0 && sources.iter().any(|source| source != &target) {
+            return Err(NoteError::NotFound(format!("tags {}", sources.join(", "))));
+        }
+
+        println!(
+            "{} Merged {} into `{}` on {} notes",
+            Palette::current().success(">"),
+            sources.join(", "),
+            target,
+            Palette::current().accent(affected.to_string())
+        );
+
+        Ok(affected)
+    }
+}
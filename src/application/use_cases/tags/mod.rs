@@ -0,0 +1,6 @@
+pub mod add;
+pub mod find_all;
+pub mod find_notes;
+pub mod merge;
+pub mod remove;
+pub mod rename;
\ No newline at end of file
@@ -0,0 +1,68 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::note::Note, errors::note_error::NoteError,
+    repositories::note_repository::NoteRepository,
+};
+
+pub struct RemoveTag<'a> {
+    note_repository: &'a dyn NoteRepository,
+}
+
+///
+/// The `RemoveTag` struct provides a use case for detaching a tag from an existing note.
+///
+impl<'a> RemoveTag<'a> {
+    ///
+    /// Creates a new instance of `RemoveTag`.
+    ///
+    /// # Arguments
+    /// * `note_repository`: A reference to any implementation of `NoteRepository` to interact with the note storage.
+    ///
+    /// # Returns
+    /// A new `RemoveTag` instance.
+    ///
+    pub fn new(note_repository: &'a dyn NoteRepository) -> Self {
+        RemoveTag { note_repository }
+    }
+
+    ///
+    /// Executes the use case to detach a tag from a note.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the note. Must be greater than 0.
+    /// * `tag`: The name of the tag to remove.
+    ///
+    /// # Returns
+    /// A `Result` containing the updated `Note`, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidId` if the `id` is less than or equal to 0.
+    /// * Returns `NoteError::NotFound` if the note does not exist or does not carry the tag.
+    /// * Returns `NoteError::Storage` if the note cannot be saved.
+    ///
+    pub fn execute(&self, id: i64, tag: &str) -> Result<Note, NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
+        }
+
+        let mut note = match self.note_repository.find_by_id(id) {
+            Some(note) => note,
+            None => return Err(NoteError::NotFound(format!("id {}", id))),
+        };
+
+        if !note.remove_tag(tag) {
+            return Err(NoteError::NotFound(format!("id {} and tag `{}`", id, tag)));
+        }
+
+        let note = self.note_repository.update(note).map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Removed tag `{}` from note {}",
+            Palette::current().success(">"),
+            tag,
+            Palette::current().accent(id.to_string())
+        );
+
+        Ok(note)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    entities::tag::Tag, errors::note_error::NoteError,
+    repositories::tag_repository::TagRepository,
+};
+
+pub struct RenameTag<'a> {
+    tag_repository: &'a dyn TagRepository,
+}
+
+///
+/// The `RenameTag` struct provides a use case for renaming a tag on every note carrying it.
+/// Renaming a tag to the name of another existing tag merges both.
+///
+impl<'a> RenameTag<'a> {
+    ///
+    /// Creates a new instance of `RenameTag`.
+    ///
+    /// # Arguments
+    /// * `tag_repository`: A reference to any implementation of `TagRepository` to interact with the tag storage.
+    ///
+    /// # Returns
+    /// A new `RenameTag` instance.
+    ///
+    pub fn new(tag_repository: &'a dyn TagRepository) -> Self {
+        RenameTag { tag_repository }
+    }
+
+    ///
+    /// Executes the use case to rename a tag.
+    ///
+    /// # Arguments
+    /// * `from`: The current name of the tag.
+    /// * `to`: The new name of the tag.
+    ///
+    /// # Returns
+    /// A `Result` containing the number of notes affected, or a `NoteError` on failure.
+    ///
+    /// # Errors
+    /// * Returns `NoteError::InvalidTag` if any of the names is not valid.
+    /// * Returns `NoteError::NotFound` if no note carries the tag.
+    /// * Returns `NoteError::Storage` if the rename fails.
+    ///
+    pub fn execute(&self, from: &str, to: &str) -> Result<usize, NoteError> {
+        let from = Tag::normalize(from)?;
+        let to = Tag::normalize(to)?;
+
+        if from == to {
+            return Ok(0);
+        }
+
+        let affected = self
+            .tag_repository
+            .rename_tag(&from, &to)
+            .map_err(NoteError::Storage)?;
+
+        if affected == 0 {
+            return Err(NoteError::NotFound(format!("tag `{}`", from)));
+        }
+
+        println!(
+            "{} Renamed tag `{}` to `{}` on {} notes",
+            Palette::current().success(">"),
+            from,
+            to,
+            Palette::current().accent(affected.to_string())
+        );
+
+        Ok(affected)
+    }
+}
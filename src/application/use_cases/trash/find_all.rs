@@ -0,0 +1,41 @@
+use crate::domain::{
+    entities::note::Note, errors::note_error::NoteError,
+    repositories::trash_repository::TrashRepository,
+};
+
+pub struct FindTrashedNotes<'a> {
+    trash_repository: &'a dyn TrashRepository,
+}
+
+impl<'a> FindTrashedNotes<'a> {
+    /// Creates a new instance of `FindTrashedNotes`.
+    ///
+    /// # Arguments
+    /// * `trash_repository`: A reference to any implementation of `TrashRepository` to interact with the trash.
+    ///
+    /// # Returns
+    /// A new `FindTrashedNotes` instance.
+    ///
+    pub fn new(trash_repository: &'a dyn TrashRepository) -> Self {
+        FindTrashedNotes { trash_repository }
+    }
+
+    /// Executes the use case to find every note in the trash.
+    ///
+    /// # Returns
+    /// A `Result` containing the trashed notes, most recently deleted first,
+    /// or a `NoteError::NotFound` if the trash is empty.
+    ///
+    pub fn execute(&self) -> Result<Vec<Note>, NoteError> {
+        let notes = self
+            .trash_repository
+            .find_trashed()
+            .map_err(NoteError::Storage)?;
+
+        if notes.is_empty() {
+            return Err(NoteError::NotFound(String::from("notes in the trash")));
+        }
+
+        Ok(notes)
+    }
+}
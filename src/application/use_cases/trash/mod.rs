@@ -0,0 +1,4 @@
+pub mod find_all;
+pub mod purge;
+pub mod purge_expired;
+pub mod restore;
\ No newline at end of file
@@ -0,0 +1,83 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    errors::note_error::NoteError, repositories::trash_repository::TrashRepository,
+};
+use chrono::{Duration, prelude::*};
+
+pub struct PurgeNote<'a> {
+    trash_repository: &'a dyn TrashRepository,
+}
+
+///
+/// The `PurgeNote` struct provides a use case for permanently deleting notes from the trash.
+/// Only notes already in the trash can be purged; a purged note cannot be recovered.
+///
+impl<'a> PurgeNote<'a> {
+    ///
+    /// Creates a new instance of `PurgeNote`.
+    ///
+    /// # Arguments
+    /// * `trash_repository`: A reference to any implementation of `TrashRepository` to interact with the trash.
+    ///
+    /// # Returns
+    /// A new `PurgeNote` instance.
+    ///
+    pub fn new(trash_repository: &'a dyn TrashRepository) -> Self {
+        PurgeNote { trash_repository }
+    }
+
+    ///
+    /// Executes the use case to permanently delete a note from the trash.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the trashed note. Must be greater than 0.
+    ///
+    /// # Returns
+    /// * `Ok(())`: If the note was deleted for good.
+    /// * `Err(NoteError)`: If the ID is invalid, the note is not in the trash or the storage fails.
+    ///
+    pub fn execute(&self, id: i64) -> Result<(), NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
+        }
+
+        let trashed = self
+            .trash_repository
+            .find_trashed()
+            .map_err(NoteError::Storage)?;
+        if !trashed.iter().any(|note| note.get_id() == Some(id)) {
+            return Err(NoteError::NotFound(format!("id {} in the trash", id)));
+        }
+
+        self.trash_repository.purge(id).map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Permanently deleted note with id: {}",
+            Palette::current().success(">"),
+            Palette::current().accent(id.to_string())
+        );
+
+        Ok(())
+    }
+
+    ///
+    /// Permanently deletes every note in the trash.
+    ///
+    /// # Returns
+    /// A `Result` containing the number of notes deleted, or a `NoteError::Storage` if the storage fails.
+    ///
+    pub fn execute_all(&self) -> Result<usize, NoteError> {
+        let purged = self
+            .trash_repository
+            .purge_deleted_before(Utc::now() + Duration::seconds(1))
+            .map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Emptied the trash, {} notes permanently deleted",
+            Palette::current().success(">"),
+            Palette::current().accent(purged.to_string())
+        );
+
+        Ok(purged)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    errors::note_error::NoteError, repositories::trash_repository::TrashRepository,
+};
+use chrono::{TimeDelta, prelude::*};
+
+pub struct PurgeExpiredNotes<'a> {
+    trash_repository: &'a dyn TrashRepository,
+}
+
+///
+/// The `PurgeExpiredNotes` struct provides a use case for emptying the trash automatically.
+/// Notes that have been in the trash for longer than the retention period are permanently deleted.
+/// It is meant to run once at startup.
+///
+impl<'a> PurgeExpiredNotes<'a> {
+    /// Number of days a note is kept in the trash when no other retention period is configured
+    pub const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+    ///
+    /// Creates a new instance of `PurgeExpiredNotes`.
+    ///
+    /// # Arguments
+    /// * `trash_repository`: A reference to any implementation of `TrashRepository` to interact with the trash.
+    ///
+    /// # Returns
+    /// A new `PurgeExpiredNotes` instance.
+    ///
+    pub fn new(trash_repository: &'a dyn TrashRepository) -> Self {
+        PurgeExpiredNotes { trash_repository }
+    }
+
+    ///
+    /// Executes the use case to purge the notes whose retention period is over.
+    ///
+    /// # Arguments
+    /// * `retention_days`: The number of days a note is kept in the trash. `0` keeps the notes forever,
+    ///   and so does a period reaching before the earliest date that can be represented.
+    ///
+    /// # Returns
+    /// A `Result` containing the number of notes purged, or a `NoteError::Storage` if the storage fails.
+    ///
+    pub fn execute(&self, retention_days: i64) -> Result<usize, NoteError> {
+        if retention_days <= 0 {
+            return Ok(0);
+        }
+
+        let Some(cutoff) = TimeDelta::try_days(retention_days).and_then(|period| Utc::now().checked_sub_signed(period))
+        else {
+            return Ok(0);
+        };
+
+        let purged = self
+            .trash_repository
+            .purge_deleted_before(cutoff)
+            .map_err(NoteError::Storage)?;
+
+        // Reported on stderr so it never mixes with the output of a command
+        if purged > 0 {
+            eprintln!(
+                "{} Permanently deleted {} notes kept in the trash for more than {} days",
+                Palette::current().success(">"),
+                Palette::current().accent(purged.to_string()),
+                retention_days
+            );
+        }
+
+        Ok(purged)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::application::palette::Palette;
+use crate::domain::{
+    errors::note_error::NoteError, repositories::trash_repository::TrashRepository,
+};
+
+pub struct RestoreNote<'a> {
+    trash_repository: &'a dyn TrashRepository,
+}
+
+///
+/// The `RestoreNote` struct provides a use case for taking a note out of the trash.
+/// The note goes back to its notebook, or to the Inbox if that notebook was deleted meanwhile.
+///
+impl<'a> RestoreNote<'a> {
+    ///
+    /// Creates a new instance of `RestoreNote`.
+    ///
+    /// # Arguments
+    /// * `trash_repository`: A reference to any implementation of `TrashRepository` to interact with the trash.
+    ///
+    /// # Returns
+    /// A new `RestoreNote` instance.
+    ///
+    pub fn new(trash_repository: &'a dyn TrashRepository) -> Self {
+        RestoreNote { trash_repository }
+    }
+
+    ///
+    /// Executes the use case to restore a note.
+    ///
+    /// # Arguments
+    /// * `id`: The ID of the trashed note. Must be greater than 0.
+    ///
+    /// # Returns
+    /// * `Ok(())`: If the note is back among the other notes.
+    /// * `Err(NoteError)`: If the ID is invalid, the note is not in the trash or the storage fails.
+    ///
+    pub fn execute(&self, id: i64) -> Result<(), NoteError> {
+        if id <= 0 {
+            return Err(NoteError::InvalidId(id));
+        }
+
+        let trashed = self
+            .trash_repository
+            .find_trashed()
+            .map_err(NoteError::Storage)?;
+        if !trashed.iter().any(|note| note.get_id() == Some(id)) {
+            return Err(NoteError::NotFound(format!("id {} in the trash", id)));
+        }
+
+        self.trash_repository.restore(id).map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Restored note with id: {}",
+            Palette::current().success(">"),
+            Palette::current().accent(id.to_string())
+        );
+
+        Ok(())
+    }
+}
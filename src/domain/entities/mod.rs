@@ -1 +1,5 @@
-pub mod note;
\ No newline at end of file
+pub mod note;
+pub mod notebook;
+pub mod revision;
+pub mod search_result;
+pub mod tag;
\ No newline at end of file
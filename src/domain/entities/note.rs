@@ -1,18 +1,28 @@
+use crate::domain::{
+    entities::{notebook::Notebook, tag::Tag},
+    errors::note_error::NoteError,
+};
 use chrono::prelude::*;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
 
 /// Represents a note in the system.
 /// A note consists of a title, content, and a timestamp indicating when it was created.
-/// The title must be non-empty and up to 100 characters, while the content must be non-empty and up to 1000 characters.
+/// The title and content must be non-empty and fit the configured `NoteLimits` (100 and 1000 characters by default).
 /// The `id` field is optional and can be set when the note is created or updated.
+/// A note can be categorized with any number of tags, stored as a set of normalized tag names,
+/// and always belongs to a notebook (the Inbox by default).
+/// Deleted notes are kept in the trash, marked with the time they were deleted, until they are purged.
+/// A locked note holds its content encrypted with a passphrase; its title stays readable.
 #[derive(Debug, Clone)]
 pub struct Note {
     /// Unique identifier for the note, optional for creation
     pub(crate) id: Option<i64>,
 
-    /// Title of the note, must be non-empty and up to 100 characters
+    /// Title of the note, must be non-empty and fit `NoteLimits::max_title_length`
     pub(crate) title: String,
 
-    /// Content of the note, must be non-empty and up to 1000 characters
+    /// Content of the note, must be non-empty and fit `NoteLimits::max_content_length`
     pub(crate) content: String,
 
     /// Timestamp of when the note was created, automatically set to the current UTC time
@@ -20,58 +30,183 @@ pub struct Note {
 
     /// Timestamp of when the note was last updated, automatically set to the current UTC time
     pub(crate) updated_at: DateTime<Utc>,
+
+    /// Normalized names of the tags attached to the note
+    pub(crate) tags: BTreeSet<String>,
+
+    /// Identifier of the notebook the note belongs to
+    pub(crate) notebook_id: i64,
+
+    /// Timestamp of when the note was moved to the trash, `None` for notes not in the trash
+    pub(crate) deleted_at: Option<DateTime<Utc>>,
+
+    /// Whether the content is sealed with a passphrase, see `NoteCipher`
+    pub(crate) locked: bool,
+}
+
+/// Maximum number of characters allowed in the title and content of a note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteLimits {
+    /// Maximum number of characters allowed in a title
+    pub max_title_length: usize,
+
+    /// Maximum number of characters allowed in the content
+    pub max_content_length: usize,
+}
+
+impl Default for NoteLimits {
+    fn default() -> Self {
+        NoteLimits {
+            max_title_length: Note::MAX_TITLE_LENGTH,
+            max_content_length: Note::MAX_CONTENT_LENGTH,
+        }
+    }
 }
 
 impl Note {
+    /// Default maximum number of characters allowed in a title
+    pub const MAX_TITLE_LENGTH: usize = 100;
+
+    /// Default maximum number of characters allowed in the content
+    pub const MAX_CONTENT_LENGTH: usize = 1000;
+
+    /// Scheme of the Markdown links from a note to another one, e.g. `[Meeting](note:3)`
+    pub const LINK_SCHEME: &'static str = "note:";
+
     /// Creates a new Note instance with the provided title and content.
     ///
     /// # Arguments
-    /// * `title` - The title of the note, must be non-empty and up to 100 characters.
-    /// * `content` - The content of the note, must be non-empty and up to 1000 characters.
+    /// * `title` - The title of the note, must be non-empty and fit `limits`.
+    /// * `content` - The content of the note, must be non-empty and fit `limits`.
+    /// * `limits` - The maximum lengths of the title and content.
     /// # Returns
     /// A new `Note` instance with the current UTC timestamp.
     /// # Examples
     /// ```
     /// let note = Note::create(
-    ///     String::from("My First Note"),
-    ///     String::from("This is the content of my first note."),
-    /// );
+    ///     &String::from("My First Note"),
+    ///     &String::from("This is the content of my first note."),
+    ///     &NoteLimits::default(),
+    /// )?;
     /// assert_eq!(note.get_title(), "My First Note");
     /// assert_eq!(note.get_content(), "This is the content of my first note.");
     /// ```
     /// # Errors
-    /// Panics if the title is empty, exceeds 100 characters, or if the content is empty or exceeds 1000 characters.
-    pub fn create(title: &String, content: &String) -> Self {
-        if title.is_empty() {
-            panic!("Title cannot be empty");
-        }
-
-        if content.is_empty() {
-            panic!("Content cannot be empty");
-        }
+    /// Returns a `NoteError` if the title or the content are empty or exceed their limit.
+    pub fn create(title: &String, content: &String, limits: &NoteLimits) -> Result<Self, NoteError> {
+        Note::validate_title(title, limits)?;
+        Note::validate_content(content, limits)?;
 
-        if title.len() > 100 {
-            panic!("Title cannot exceed 100 characters");
-        }
+        Ok(Note {
+            id: None,
+            title: title.clone(),
+            content: content.clone(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            tags: BTreeSet::new(),
+            notebook_id: Notebook::INBOX_ID,
+            deleted_at: None,
+            locked: false,
+        })
+    }
 
-        if content.len() > 1000 {
-            panic!("Content cannot exceed 1000 characters");
+    /// Creates a new locked Note from a content already sealed with a passphrase,
+    /// e.g. read from an export. The limits only apply to the title, as they were checked
+    /// on the content before sealing it.
+    /// # Arguments
+    /// * `title` - The title of the note, must be non-empty and fit `limits`.
+    /// * `sealed` - The sealed content, must be non-empty.
+    /// * `limits` - The maximum lengths of the title and content.
+    /// # Returns
+    /// A new locked `Note` instance with the current UTC timestamp.
+    /// # Errors
+    /// Returns a `NoteError` if the title is empty or exceeds its limit, or the content is empty.
+    pub fn create_locked(title: &String, sealed: String, limits: &NoteLimits) -> Result<Self, NoteError> {
+        Note::validate_title(title, limits)?;
+        if sealed.trim().is_empty() {
+            return Err(NoteError::EmptyContent);
         }
 
-        Note {
+        Ok(Note {
             id: None,
             title: title.clone(),
-            content: content.clone(),
+            content: sealed,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            tags: BTreeSet::new(),
+            notebook_id: Notebook::INBOX_ID,
+            deleted_at: None,
+            locked: true,
+        })
+    }
+
+    /// Validates a note title.
+    /// # Arguments
+    /// * `title` - The title to validate.
+    /// * `limits` - The maximum lengths of the title and content.
+    /// # Returns
+    /// `Ok(())` if the title is non-empty and up to `limits.max_title_length` characters.
+    /// # Examples
+    /// ```
+    /// let limits = NoteLimits::default();
+    /// assert!(Note::validate_title("My First Note", &limits).is_ok());
+    /// assert_eq!(Note::validate_title("", &limits), Err(NoteError::EmptyTitle));
+    /// ```
+    /// # Errors
+    /// * `NoteError::EmptyTitle` if the title is empty.
+    /// * `NoteError::TitleTooLong` if the title exceeds `limits.max_title_length` characters.
+    pub fn validate_title(title: &str, limits: &NoteLimits) -> Result<(), NoteError> {
+        if title.trim().is_empty() {
+            return Err(NoteError::EmptyTitle);
+        }
+
+        let length = title.chars().count();
+        if length > limits.max_title_length {
+            return Err(NoteError::TitleTooLong {
+                max: limits.max_title_length,
+                actual: length,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates a note content.
+    /// # Arguments
+    /// * `content` - The content to validate.
+    /// * `limits` - The maximum lengths of the title and content.
+    /// # Returns
+    /// `Ok(())` if the content is non-empty and up to `limits.max_content_length` characters.
+    /// # Examples
+    /// ```
+    /// let limits = NoteLimits::default();
+    /// assert!(Note::validate_content("This is the content of my first note.", &limits).is_ok());
+    /// assert_eq!(Note::validate_content("", &limits), Err(NoteError::EmptyContent));
+    /// ```
+    /// # Errors
+    /// * `NoteError::EmptyContent` if the content is empty.
+    /// * `NoteError::ContentTooLong` if the content exceeds `limits.max_content_length` characters.
+    pub fn validate_content(content: &str, limits: &NoteLimits) -> Result<(), NoteError> {
+        if content.trim().is_empty() {
+            return Err(NoteError::EmptyContent);
+        }
+
+        let length = content.chars().count();
+        if length > limits.max_content_length {
+            return Err(NoteError::ContentTooLong {
+                max: limits.max_content_length,
+                actual: length,
+            });
         }
+
+        Ok(())
     }
 
     /// Creates a new `Note` instance from primitive values.
     /// # Arguments
     /// * `id` - The unique identifier for the note, can be `None` for new notes.
-    /// * `title` - The title of the note, must be non-empty and up to 100 characters.
-    /// * `content` - The content of the note, must be non-empty and up to 1000 characters.
+    /// * `title` - The title of the note.
+    /// * `content` - The content of the note.
     /// * `created_at` - The timestamp of when the note was created, typically set to the current UTC time.
     /// * `updated_at` - The timestamp of when the note was last updated, typically set to the current UTC time.
     /// # Returns
@@ -89,8 +224,8 @@ impl Note {
     /// assert_eq!(note.get_title(), "My First Note");
     /// assert_eq!(note.get_content(), "This is the content of my first note.");
     /// ```
-    /// # Errors
-    /// Panics if the title is empty, exceeds 100 characters, or if the content is empty or exceeds 1000 characters.
+    /// # Note
+    /// The values are trusted as they come from the storage, so no validation is performed.
     pub fn from_primitives(
         id: i64,
         title: String,
@@ -104,9 +239,59 @@ impl Note {
             content,
             created_at,
             updated_at,
+            tags: BTreeSet::new(),
+            notebook_id: Notebook::INBOX_ID,
+            deleted_at: None,
+            locked: false,
         }
     }
 
+    /// Replaces the tags of the note with already normalized tag names.
+    /// # Arguments
+    /// * `tags` - The normalized names of the tags, typically loaded from the storage.
+    /// # Returns
+    /// The same `Note` instance carrying the given tags.
+    /// # Examples
+    /// ```
+    /// let note = Note::from_primitives(1, title, content, Utc::now(), Utc::now())
+    ///     .with_tags(BTreeSet::from([String::from("work")]));
+    /// assert!(note.has_tag("work"));
+    /// ```
+    pub fn with_tags(mut self, tags: BTreeSet<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Places the note in a notebook.
+    /// # Arguments
+    /// * `notebook_id` - The identifier of the notebook.
+    /// # Returns
+    /// The same `Note` instance placed in the given notebook.
+    pub fn with_notebook(mut self, notebook_id: i64) -> Self {
+        self.notebook_id = notebook_id;
+        self
+    }
+
+    /// Marks the note as being in the trash.
+    /// # Arguments
+    /// * `deleted_at` - The timestamp of when the note was moved to the trash.
+    /// # Returns
+    /// The same `Note` instance marked as deleted.
+    pub fn with_deleted_at(mut self, deleted_at: DateTime<Utc>) -> Self {
+        self.deleted_at = Some(deleted_at);
+        self
+    }
+
+    /// Marks whether the content of the note, as loaded from the storage, is sealed.
+    /// # Arguments
+    /// * `locked` - Whether the content is sealed with a passphrase.
+    /// # Returns
+    /// The same `Note` instance, locked or not.
+    pub fn with_locked(mut self, locked: bool) -> Self {
+        self.locked = locked;
+        self
+    }
+
     /// Get the ID of the note.
     ///
     /// # Arguments
@@ -236,68 +421,161 @@ impl Note {
 
     /// Set the title of the note.
     /// # Arguments
-    /// * `title` - The new title for the note, must be non-empty and up to 100 characters.
+    /// * `title` - The new title for the note, must be non-empty and fit `limits`.
+    /// * `limits` - The maximum lengths of the title and content.
     /// # Examples
     /// ```
     /// let mut note = Note::create(
     ///    String::from("My First Note"),
     ///   String::from("This is the content of my first note."),
     /// );
-    /// note.set_title(String::from("Updated Note Title"));
+    /// note.set_title(String::from("Updated Note Title"), &NoteLimits::default())?;
     /// assert_eq!(note.get_title(), "Updated Note Title");
     /// ```
     /// # Errors
-    /// Panics if the title is empty or exceeds 100 characters.
+    /// Returns `NoteError::EmptyTitle` or `NoteError::TitleTooLong` if the title is empty or exceeds `limits.max_title_length` characters.
     ///
     /// # Note
     /// This method updates the `updated_at` field to the current UTC time when the title is changed.
     /// It is typically used when the title of the note is modified.
+    /// The note is left untouched if the title is not valid.
     ///
-    /// # Panics
-    /// This method will panic if the title is empty or exceeds 100 characters.
-    /// It is important to ensure that the title meets these constraints to maintain the integrity of the note.
-    ///
-    pub fn set_title(&mut self, title: String) {
-        if title.is_empty() {
-            panic!("Title cannot be empty");
-        }
-
-        if title.len() > 100 {
-            panic!("Title cannot exceed 100 characters");
-        }
+    pub fn set_title(&mut self, title: String, limits: &NoteLimits) -> Result<(), NoteError> {
+        Note::validate_title(&title, limits)?;
 
         self.title = title;
         self.updated_at = Utc::now();
+
+        Ok(())
     }
 
     /// Set the content of the note
     /// # Arguments
-    /// * `content` - The new content for the note, must be non-empty and up to 1000 characters.
+    /// * `content` - The new content for the note, must be non-empty and fit `limits`.
+    /// * `limits` - The maximum lengths of the title and content.
     /// # Examples
     /// ```
     /// let mut note = Note::create(
     ///     String::from("My First Note"),
     ///     String::from("This is the content of my first note."),
     /// );
-    /// note.set_content(String::from("Updated content for my first note."));
+    /// note.set_content(String::from("Updated content for my first note."), &NoteLimits::default())?;
     /// assert_eq!(note.get_content(), "Updated content for my first note.");
     /// ```
     /// # Errors
-    /// Panics if the content is empty or exceeds 1000 characters.
+    /// Returns `NoteError::EmptyContent` or `NoteError::ContentTooLong` if the content is empty or exceeds `limits.max_content_length` characters.
     /// # Note
     /// This method updates the `updated_at` field to the current UTC time when the content is changed.
     /// It is typically used when the content of the note is modified.
-    pub fn set_content(&mut self, content: String) {
-        if content.is_empty() {
-            panic!("Content cannot be empty");
+    pub fn set_content(&mut self, content: String, limits: &NoteLimits) -> Result<(), NoteError> {
+        Note::validate_content(&content, limits)?;
+
+        self.content = content;
+        self.updated_at = Utc::now();
+
+        Ok(())
+    }
+
+    /// Get the identifier of the notebook the note belongs to
+    pub fn get_notebook_id(&self) -> i64 {
+        self.notebook_id
+    }
+
+    /// Get the timestamp of when the note was moved to the trash, `None` if it is not in the trash
+    pub fn get_deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+
+    /// Tells whether the note is in the trash
+    pub fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Tells whether the content of the note is sealed with a passphrase
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Replaces the content with its sealed version and marks the note as locked.
+    /// # Arguments
+    /// * `sealed` - The content sealed with a passphrase, see `NoteCipher::seal`.
+    /// # Note
+    /// The limits are not checked, a sealed content is longer than the plain one.
+    pub fn lock(&mut self, sealed: String) {
+        self.content = sealed;
+        self.locked = true;
+    }
+
+    /// Replaces the sealed content with the plain one and marks the note as not locked.
+    /// # Arguments
+    /// * `content` - The content once opened, see `NoteCipher::open`.
+    pub fn unlock(&mut self, content: String) {
+        self.content = content;
+        self.locked = false;
+    }
+
+    /// Fingerprint of a content, used to recognize notes that were exported or imported before.
+    /// # Arguments
+    /// * `content` - The content of a note.
+    /// # Returns
+    /// The SHA-256 digest of the content, in lowercase hexadecimal.
+    pub fn content_hash(content: &str) -> String {
+        format!("{:x}", Sha256::digest(content.as_bytes()))
+    }
+
+    /// Get the names of the tags attached to the note
+    ///
+    /// # Returns
+    /// A reference to the set of normalized tag names, sorted alphabetically.
+    pub fn get_tags(&self) -> &BTreeSet<String> {
+        &self.tags
+    }
+
+    /// Tells whether the note carries the given tag.
+    /// # Arguments
+    /// * `name` - The tag name, normalized before comparing.
+    pub fn has_tag(&self, name: &str) -> bool {
+        match Tag::normalize(name) {
+            Ok(name) => self.tags.contains(&name),
+            Err(_) => false,
         }
+    }
+
+    /// Attaches a tag to the note.
+    /// # Arguments
+    /// * `name` - The tag name, e.g. `#Work`. It is normalized before being stored.
+    /// # Returns
+    /// `true` if the tag was added, `false` if the note already had it.
+    /// # Examples
+    /// ```
+    /// note.add_tag("#Work")?;
+    /// assert!(note.has_tag("work"));
+    /// ```
+    /// # Errors
+    /// Returns `NoteError::InvalidTag` if the tag name is not valid.
+    pub fn add_tag(&mut self, name: &str) -> Result<bool, NoteError> {
+        let name = Tag::normalize(name)?;
 
-        if content.len() > 1000 {
-            panic!("Content cannot exceed 1000 characters");
+        Ok(self.tags.insert(name))
+    }
+
+    /// Detaches a tag from the note.
+    /// # Arguments
+    /// * `name` - The tag name, normalized before comparing.
+    /// # Returns
+    /// `true` if the tag was removed, `false` if the note did not have it.
+    pub fn remove_tag(&mut self, name: &str) -> bool {
+        match Tag::normalize(name) {
+            Ok(name) => self.tags.remove(&name),
+            Err(_) => false,
         }
+    }
 
-        self.content = content;
-        self.updated_at = Utc::now();
+    /// Set the `created_at` timestamp, e.g. to keep the date of a note written elsewhere.
+    /// # Arguments
+    /// * `value` - The new timestamp to set for the `created_at` field.
+    pub fn set_created_at(&mut self, value: DateTime<Utc>) {
+        self.created_at = value;
     }
 
     /// Update the `updated_at` timestamp to the current UTC time.
@@ -0,0 +1,162 @@
+use crate::domain::errors::note_error::NoteError;
+
+/// Represents a notebook, a folder used to organize notes.
+/// Notebooks can be nested through `parent_id`; root notebooks have no parent.
+/// Every note belongs to exactly one notebook, the "Inbox" notebook being the default one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notebook {
+    /// Unique identifier for the notebook, optional for notebooks not stored yet
+    pub(crate) id: Option<i64>,
+
+    /// Name of the notebook, must be non-empty and up to 50 characters
+    pub(crate) name: String,
+
+    /// Identifier of the parent notebook, `None` for root notebooks
+    pub(crate) parent_id: Option<i64>,
+
+    /// Number of notes stored directly in the notebook
+    pub(crate) note_count: usize,
+}
+
+/// What happens to the content of a notebook when it is deleted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotebookDeletion {
+    /// Delete the sub-notebooks too, moving their notes to the trash
+    Cascade,
+
+    /// Move the notes and sub-notebooks to the parent notebook (or to the Inbox for root notebooks)
+    Reparent,
+}
+
+impl Notebook {
+    /// Identifier of the default "Inbox" notebook, created with the database
+    pub const INBOX_ID: i64 = 1;
+
+    /// Maximum number of characters allowed in a notebook name
+    pub const MAX_NAME_LENGTH: usize = 50;
+
+    /// Creates a new Notebook instance.
+    /// # Arguments
+    /// * `name` - The name of the notebook, must be non-empty and up to 50 characters.
+    /// * `parent_id` - The identifier of the parent notebook, `None` for a root notebook.
+    /// # Returns
+    /// A new `Notebook` instance without notes.
+    /// # Examples
+    /// ```
+    /// let notebook = Notebook::create("Projects", None)?;
+    /// assert_eq!(notebook.get_name(), "Projects");
+    /// ```
+    /// # Errors
+    /// Returns `NoteError::InvalidNotebook` if the name is empty or exceeds 50 characters.
+    pub fn create(name: &str, parent_id: Option<i64>) -> Result<Self, NoteError> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(NoteError::InvalidNotebook(String::from("the name is empty")));
+        }
+
+        if name.chars().count() > Notebook::MAX_NAME_LENGTH {
+            return Err(NoteError::InvalidNotebook(format!(
+                "the name exceeds {} characters",
+                Notebook::MAX_NAME_LENGTH
+            )));
+        }
+
+        Ok(Notebook {
+            id: None,
+            name: name.to_string(),
+            parent_id,
+            note_count: 0,
+        })
+    }
+
+    /// Creates a new `Notebook` instance from primitive values.
+    /// # Arguments
+    /// * `id` - The unique identifier for the notebook.
+    /// * `name` - The name of the notebook.
+    /// * `parent_id` - The identifier of the parent notebook, `None` for a root notebook.
+    /// * `note_count` - The number of notes stored directly in the notebook.
+    /// # Returns
+    /// A new `Notebook` instance with the provided values.
+    pub fn from_primitives(id: i64, name: String, parent_id: Option<i64>, note_count: usize) -> Self {
+        Notebook {
+            id: Some(id),
+            name,
+            parent_id,
+            note_count,
+        }
+    }
+
+    /// Get the ID of the notebook
+    pub fn get_id(&self) -> Option<i64> {
+        self.id
+    }
+
+    /// Get the name of the notebook
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    /// Get the ID of the parent notebook, `None` for root notebooks
+    pub fn get_parent_id(&self) -> Option<i64> {
+        self.parent_id
+    }
+
+    /// Get the number of notes stored directly in the notebook
+    pub fn get_note_count(&self) -> usize {
+        self.note_count
+    }
+
+    /// Tells whether this is the default "Inbox" notebook
+    pub fn is_inbox(&self) -> bool {
+        self.id == Some(Notebook::INBOX_ID)
+    }
+
+    /// Collects the IDs of every notebook nested under `id`, at any depth.
+    /// # Arguments
+    /// * `notebooks` - Every stored notebook.
+    /// * `id` - The ID of the notebook whose descendants are wanted.
+    /// # Returns
+    /// The IDs of the descendants, not including `id` itself.
+    pub fn descendants_of(notebooks: &[Notebook], id: i64) -> Vec<i64> {
+        let mut descendants = Vec::new();
+        let mut pending = vec![id];
+
+        while let Some(current) = pending.pop() {
+            for child in notebooks.iter().filter(|notebook| notebook.parent_id == Some(current)) {
+                if let Some(child_id) = child.id {
+                    if !descendants.contains(&child_id) {
+                        descendants.push(child_id);
+                        pending.push(child_id);
+                    }
+                }
+            }
+        }
+
+        descendants
+    }
+
+    /// Builds the full path of a notebook, e.g. `Projects / Rust / Notes`.
+    /// # Arguments
+    /// * `notebooks` - Every stored notebook.
+    /// * `id` - The ID of the notebook.
+    /// # Returns
+    /// The names from the root to the notebook, separated by ` / `.
+    pub fn path_of(notebooks: &[Notebook], id: i64) -> String {
+        let mut names = Vec::new();
+        let mut current = notebooks.iter().find(|notebook| notebook.id == Some(id));
+
+        while let Some(notebook) = current {
+            names.push(notebook.name.clone());
+            current = match notebook.parent_id {
+                Some(parent_id) if names.len() <= notebooks.len() => {
+                    notebooks.iter().find(|notebook| notebook.id == Some(parent_id))
+                }
+                _ => None,
+            };
+        }
+
+        names.reverse();
+        names.join(" / ")
+    }
+}
@@ -0,0 +1,74 @@
+use chrono::prelude::*;
+
+/// Represents a saved version of a note.
+/// A revision is recorded every time the title or content of a note is saved,
+/// so the history of a note can be reviewed and any earlier version restored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Revision {
+    /// Unique identifier for the revision, assigned by the storage
+    pub(crate) id: i64,
+
+    /// Identifier of the note the revision belongs to
+    pub(crate) note_id: i64,
+
+    /// Title of the note in this revision
+    pub(crate) title: String,
+
+    /// Content of the note in this revision
+    pub(crate) content: String,
+
+    /// Timestamp of when the revision was saved
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+impl Revision {
+    /// Creates a new `Revision` instance from primitive values.
+    /// # Arguments
+    /// * `id` - The unique identifier for the revision.
+    /// * `note_id` - The identifier of the note.
+    /// * `title` - The title of the note in this revision.
+    /// * `content` - The content of the note in this revision.
+    /// * `created_at` - The timestamp of when the revision was saved.
+    /// # Returns
+    /// A new `Revision` instance with the provided values.
+    pub fn from_primitives(
+        id: i64,
+        note_id: i64,
+        title: String,
+        content: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Revision {
+            id,
+            note_id,
+            title,
+            content,
+            created_at,
+        }
+    }
+
+    /// Get the ID of the revision
+    pub fn get_id(&self) -> i64 {
+        self.id
+    }
+
+    /// Get the ID of the note the revision belongs to
+    pub fn get_note_id(&self) -> i64 {
+        self.note_id
+    }
+
+    /// Get the title of the note in this revision
+    pub fn get_title(&self) -> &String {
+        &self.title
+    }
+
+    /// Get the content of the note in this revision
+    pub fn get_content(&self) -> &String {
+        &self.content
+    }
+
+    /// Get the timestamp of when the revision was saved
+    pub fn get_created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
@@ -0,0 +1,43 @@
+use crate::domain::entities::note::Note;
+
+/// Represents a note matching a full-text search.
+/// Besides the note itself it carries a snippet of the matching text and the relevance of the match.
+/// The matched terms inside the snippet are wrapped between `HIGHLIGHT_START` and `HIGHLIGHT_END`,
+/// so each user interface can decide how to highlight them.
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    /// The note that matched the search
+    pub(crate) note: Note,
+
+    /// Fragment of the note around the matched terms
+    pub(crate) snippet: String,
+
+    /// Relevance of the match, lower values are better (bm25)
+    pub(crate) rank: f64,
+}
+
+impl SearchResult {
+    /// Marker placed before every matched term in the snippet
+    pub const HIGHLIGHT_START: char = '\u{1}';
+
+    /// Marker placed after every matched term in the snippet
+    pub const HIGHLIGHT_END: char = '\u{2}';
+
+    /// Start of the error returned by `NoteRepository::search` when the storage cannot understand the query
+    pub const INVALID_QUERY_ERROR: &'static str = "Consulta de búsqueda no válida";
+
+    /// Creates a new `SearchResult`.
+    /// # Arguments
+    /// * `note` - The note that matched the search.
+    /// * `snippet` - Fragment of the note around the matched terms, with the highlight markers.
+    /// * `rank` - Relevance of the match, lower values are better.
+    /// # Returns
+    /// A new `SearchResult` instance.
+    pub fn new(note: Note, snippet: String, rank: f64) -> Self {
+        SearchResult {
+            note,
+            snippet,
+            rank,
+        }
+    }
+}
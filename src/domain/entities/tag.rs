@@ -0,0 +1,93 @@
+use crate::domain::errors::note_error::NoteError;
+
+/// Represents a tag used to categorize notes.
+/// A note can have many tags and a tag can be attached to many notes.
+/// Tag names are normalized: lowercase, without the leading `#`, without whitespace and up to 50 characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    /// Unique identifier for the tag, optional for tags not stored yet
+    pub(crate) id: Option<i64>,
+
+    /// Normalized name of the tag
+    pub(crate) name: String,
+
+    /// Number of notes carrying the tag
+    pub(crate) note_count: usize,
+}
+
+/// How several tags are combined when filtering notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagMatch {
+    /// The note must have every tag (AND)
+    All,
+
+    /// The note must have at least one of the tags (OR)
+    Any,
+}
+
+impl Tag {
+    /// Maximum number of characters allowed in a tag name
+    pub const MAX_NAME_LENGTH: usize = 50;
+
+    /// Creates a new `Tag` instance from primitive values.
+    /// # Arguments
+    /// * `id` - The unique identifier for the tag.
+    /// * `name` - The name of the tag, already normalized.
+    /// * `note_count` - The number of notes carrying the tag.
+    /// # Returns
+    /// A new `Tag` instance with the provided values.
+    pub fn from_primitives(id: Option<i64>, name: String, note_count: usize) -> Self {
+        Tag {
+            id,
+            name,
+            note_count,
+        }
+    }
+
+    /// Normalizes and validates a tag name.
+    /// # Arguments
+    /// * `name` - The name as written by the user, e.g. `#Work`.
+    /// # Returns
+    /// The normalized name, e.g. `work`.
+    /// # Examples
+    /// ```
+    /// assert_eq!(Tag::normalize(" #Work ")?, "work");
+    /// assert!(Tag::normalize("two words").is_err());
+    /// ```
+    /// # Errors
+    /// Returns `NoteError::InvalidTag` if the name is empty, contains whitespace or exceeds 50 characters.
+    pub fn normalize(name: &str) -> Result<String, NoteError> {
+        let name = name.trim().trim_start_matches('#').to_lowercase();
+
+        if name.is_empty() {
+            return Err(NoteError::InvalidTag(String::from("the tag name is empty")));
+        }
+
+        if name.chars().any(char::is_whitespace) {
+            return Err(NoteError::InvalidTag(format!(
+                "`{}` contains spaces, use `-` or `_` instead",
+                name
+            )));
+        }
+
+        if name.chars().count() > Tag::MAX_NAME_LENGTH {
+            return Err(NoteError::InvalidTag(format!(
+                "`{}` exceeds {} characters",
+                name,
+                Tag::MAX_NAME_LENGTH
+            )));
+        }
+
+        Ok(name)
+    }
+
+    /// Get the name of the tag
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    /// Get the number of notes carrying the tag
+    pub fn get_note_count(&self) -> usize {
+        self.note_count
+    }
+}
@@ -0,0 +1 @@
+pub mod note_error;
\ No newline at end of file
@@ -0,0 +1,116 @@
+use std::fmt;
+
+///
+/// The `NoteError` enum represents every error that can happen while working with notes.
+/// It is returned by the `Note` entity when validating its fields and by the use cases
+/// when a note cannot be found or the storage fails.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteError {
+    /// The title of the note is empty
+    EmptyTitle,
+
+    /// The title of the note exceeds the maximum number of characters
+    TitleTooLong { max: usize, actual: usize },
+
+    /// The content of the note is empty
+    EmptyContent,
+
+    /// The content of the note exceeds the maximum number of characters
+    ContentTooLong { max: usize, actual: usize },
+
+    /// The tag name is not valid, the value explains the problem
+    InvalidTag(String),
+
+    /// The notebook cannot be created, moved or deleted, the value explains the problem
+    InvalidNotebook(String),
+
+    /// The identifier of the note is not valid (must be greater than 0)
+    InvalidId(i64),
+
+    /// The search query is malformed, the value explains the problem
+    InvalidQuery(String),
+
+    /// No note matched the lookup, the value describes what was searched
+    NotFound(String),
+
+    /// There is no note stored at all
+    NoNotes,
+
+    /// The note with this ID is locked, so its content cannot be read or changed without the passphrase
+    Locked(i64),
+
+    /// The note with this ID is not locked, so there is nothing to unlock
+    NotLocked(i64),
+
+    /// The passphrase cannot be used to lock a note, the value explains the problem
+    InvalidPassphrase(String),
+
+    /// The passphrase does not open the note, or its sealed content was tampered with
+    WrongPassphrase,
+
+    /// The storage backend failed, the value contains the underlying error message
+    Storage(String),
+}
+
+impl NoteError {
+    ///
+    /// Tells whether the error was caused by invalid user input.
+    /// Validation errors can be fixed by asking the user for the data again.
+    ///
+    /// # Returns
+    /// `true` if the error is a validation error, `false` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// assert!(NoteError::EmptyTitle.is_validation());
+    /// assert!(!NoteError::Storage(String::from("disk full")).is_validation());
+    /// ```
+    ///
+    pub fn is_validation(&self) -> bool {
+        matches!(
+            self,
+            NoteError::EmptyTitle
+                | NoteError::TitleTooLong { .. }
+                | NoteError::EmptyContent
+                | NoteError::ContentTooLong { .. }
+                | NoteError::InvalidTag(_)
+                | NoteError::InvalidNotebook(_)
+                | NoteError::InvalidQuery(_)
+                | NoteError::InvalidPassphrase(_)
+                | NoteError::WrongPassphrase
+        )
+    }
+}
+
+impl fmt::Display for NoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoteError::EmptyTitle => write!(f, "Title cannot be empty"),
+            NoteError::TitleTooLong { max, actual } => write!(
+                f,
+                "Title cannot exceed {} characters ({} given)",
+                max, actual
+            ),
+            NoteError::EmptyContent => write!(f, "Content cannot be empty"),
+            NoteError::ContentTooLong { max, actual } => write!(
+                f,
+                "Content cannot exceed {} characters ({} given)",
+                max, actual
+            ),
+            NoteError::InvalidTag(reason) => write!(f, "Invalid tag: {}", reason),
+            NoteError::InvalidNotebook(reason) => write!(f, "Invalid notebook: {}", reason),
+            NoteError::InvalidId(id) => write!(f, "Invalid note ID: {}", id),
+            NoteError::InvalidQuery(reason) => write!(f, "Invalid search query: {}", reason),
+            NoteError::NotFound(lookup) => write!(f, "No note found with {}", lookup),
+            NoteError::NoNotes => write!(f, "No notes stored"),
+            NoteError::Locked(id) => write!(f, "Note {} is locked", id),
+            NoteError::NotLocked(id) => write!(f, "Note {} is not locked", id),
+            NoteError::InvalidPassphrase(reason) => write!(f, "Invalid passphrase: {}", reason),
+            NoteError::WrongPassphrase => write!(f, "Wrong passphrase"),
+            NoteError::Storage(message) => write!(f, "Storage error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for NoteError {}
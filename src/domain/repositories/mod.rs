@@ -1 +1,5 @@
 pub mod note_repository;
+pub mod notebook_repository;
+pub mod revision_repository;
+pub mod tag_repository;
+pub mod trash_repository;
\ No newline at end of file
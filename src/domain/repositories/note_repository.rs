@@ -1,300 +1,90 @@
-use crate::domain::entities::note::Note;
-use chrono::prelude::*;
-use rusqlite::{Connection, Result, params}; // Asegúrate de que esta ruta sea correcta
+use crate::domain::entities::{note::Note, search_result::SearchResult};
 
 ///
-/// The `NoteRepository` struct provides an interface for interacting with a SQLite database to manage notes.
-/// It allows for saving, updating, deleting, and finding notes by their ID.
-/// 
-pub struct NoteRepository {
-    connection: Connection,
-}
-
-impl NoteRepository {
-    /// 
-    /// Creates a new instance of `NoteRepository`.
-    /// This function initializes the SQLite database connection and creates the `notes` table if it does not exist.
-    /// # Returns
-    /// A new `NoteRepository` instance with an established connection to the SQLite database.
-    ///
-    /// # Errors
-    /// This function will panic if there is an error opening the database or creating the table.
-    ///
-    /// # Example
-    /// ```
-    /// let note_repository = NoteRepository::new();
-    /// ```
-    /// 
-    pub fn new() -> Self {
-        let connection = Connection::open("notes-rust.db")
-            .map_err(|err| format!("Error al abrir la base de datos: {}", err))
-            .unwrap();
-
-        connection.execute(
-            "CREATE TABLE IF NOT EXISTS notes (
-                id INTEGER PRIMARY KEY,
-                title TEXT NOT NULL,
-                content TEXT NOT NULL,
-                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-                updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
-            )",
-            [],
-        ).expect("Error al crear la tabla");
-
-        NoteRepository { connection }
-    }
-}
-
-impl NoteRepository {
+/// The `NoteRepository` trait defines how notes are persisted and retrieved.
+/// The domain and application layers only depend on this trait, so the storage backend
+/// (SQLite, in memory, ...) can be swapped without touching the business rules.
+///
+pub trait NoteRepository {
     ///
-    /// Returns a reference to the SQLite connection used by the repository.
-    /// This function allows access to the underlying database connection for executing raw SQL queries if needed.
+    /// Saves a new note.
+    /// # Arguments
+    /// * `note`: A reference to the `Note` object to be saved.
     /// # Returns
-    /// A reference to the `Connection` object.
+    /// * `Ok(i64)`: The ID of the newly created note if the operation is successful.
+    /// * `Err(String)`: An error message if there is an issue saving the note.
     ///
-    /// # Example
-    /// ```
-    /// let connection = note_repository.connection();
-    /// ```
-    ///     
-    pub fn connection(&self) -> &Connection {
-        &self.connection
-    }
+    fn save(&self, note: &Note) -> Result<i64, String>;
 
     ///
-    /// Saves a new note to the SQLite database.
+    /// Saves a new note keeping its creation and update timestamps, instead of the current time
+    /// `save` uses. Meant for notes imported from elsewhere.
     /// # Arguments
     /// * `note`: A reference to the `Note` object to be saved.
     /// # Returns
     /// * `Ok(i64)`: The ID of the newly created note if the operation is successful.
-    /// * `Err(String)`: An error message if there is an issue saving the note, such as a database error.
-    /// 
-    pub fn save(&self, note: &Note) -> Result<i64, String> {
-        self.connection
-            .execute(
-                "INSERT INTO notes (title, content, created_at, updated_at) VALUES (?1, ?2, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)",
-                params![
-                    note.get_title(), 
-                    note.get_content()
-                ],
-            )
-            .map_err(|err| format!("Error al guardar la nota: {}", err))?;
-
-        let id = self.connection.last_insert_rowid();
-
-        Ok(id)
-    }
-
-    pub fn find_all(&self) -> Option<Vec<Note>> {
-        let mut stmt = self
-            .connection
-            .prepare(
-                "SELECT 
-                        id,
-                        title, 
-                        content,
-                        created_at,
-                        updated_at
-                    FROM notes;",
-            )
-            .map_err(|err| format!("Error al preparar la consulta: {}", err))
-            .ok()?;
-
-        let notes = stmt
-            .query_map([], |row| {
-                let id: i64 = row.get(0)?;
-                let title: String = row.get(1)?;
-                let content: String = row.get(2)?;
-                let created_at: String = row.get(3)?;
-                let updated_at: String = row.get(4)?;
-
-                let updated_at_date = updated_at.parse::<DateTime<Utc>>()
-                    .map_err(|_| format!("Error parsing updated_at: {}", updated_at))
-                    .unwrap_or_else(|_| Utc::now());
-                let created_at_date = created_at.parse::<DateTime<Utc>>()
-                    .map_err(|_| format!("Error parsing created_at: {}", created_at))
-                    .unwrap_or_else(|_| Utc::now());
-
-                    
-                Ok(Note {
-                        id: Option::Some(id),
-                        title: title,
-                        content: content,
-                        created_at: created_at_date,
-                        updated_at: updated_at_date,
-                    })
-            })
-            .map_err(|err| format!("Error al buscar las notas: {}", err))
-            .ok()?;
-
-        let notes_vec: Vec<Note> = notes.filter_map(Result::ok).collect();
+    /// * `Err(String)`: An error message if there is an issue saving the note.
+    ///
+    fn save_with_timestamps(&self, note: &Note) -> Result<i64, String>;
 
-        if notes_vec.is_empty() {
-            None
-        } else {
-            Some(notes_vec)
-        }
-    }
+    ///
+    /// Finds every stored note, leaving out the notes in the trash.
+    /// # Returns
+    /// * `Option<Vec<Note>>`: An `Option` containing all the notes, or `None` if there are no notes.
+    ///
+    fn find_all(&self) -> Option<Vec<Note>>;
 
-    /// 
-    /// Updates an existing note in the SQLite database.
+    ///
+    /// Updates an existing note.
     /// # Arguments
     /// * `note`: A `Note` object containing the updated information.
     /// # Returns
     /// * `Ok(Note)`: The updated `Note` object if the operation is successful.
-    /// * `Err(String)`: An error message if there is an issue updating the note, such as a database error.
+    /// * `Err(String)`: An error message if there is an issue updating the note.
     ///
-    pub fn update(&self, note: Note) -> Result<Note, String> {
-        self.connection
-            .execute(
-                "UPDATE notes SET title = ?1, content = ?2, updated_at = CURRENT_TIMESTAMP WHERE id = ?3",
-                params![
-                    note.get_title(), 
-                    note.get_content(), 
-                    note.get_id()
-                ],
-            )
-            .map_err(|err| format!("Error al actualizar la nota: {}", err))?;
-        Ok(note.clone())
-    }
+    fn update(&self, note: Note) -> Result<Note, String>;
 
-    /// 
-    /// Deletes a note from the SQLite database by its ID.
+    ///
+    /// Deletes a note by its ID, moving it to the trash.
+    /// The note can be restored or purged later through the `TrashRepository`.
     /// # Arguments
     /// * `id`: The ID of the note to be deleted.
     /// # Returns
-    /// * `Ok(())`: If the note is successfully deleted.
-    /// * `Err(String)`: An error message if there is an issue deleting the note, such as a database error.
-    /// 
-    pub fn delete(&self, id: i64) -> Result<(), String> {
-        self.connection
-            .execute("DELETE FROM notes WHERE id = ?1", params![id])
-            .map_err(|err| format!("Error al eliminar la nota: {}", err))?;
-
-        Ok(())
-    }
+    /// * `Ok(())`: If the note is successfully moved to the trash.
+    /// * `Err(String)`: An error message if there is an issue deleting the note.
+    ///
+    fn delete(&self, id: i64) -> Result<(), String>;
 
-    /// 
-    /// Finds a note by its ID in the SQLite database.
+    ///
+    /// Finds a note by its ID. Notes in the trash are not found.
     /// # Arguments
     /// * `id`: The ID of the note to be found.
     /// # Returns
     /// * `Option<Note>`: An `Option` containing the `Note` if found, or `None` if no note with the given ID exists.
-    /// 
-    pub fn find_by_id(&self, id: i64) -> Option<Note> {
-        let mut stmt = self
-            .connection
-            .prepare(
-                "SELECT 
-                        id,
-                        title, 
-                        content,
-                        created_at,
-                        updated_at
-                    FROM notes 
-                    WHERE id = ?1
-                    LIMIT 1;",
-            )
-            .map_err(|err| format!("Error al preparar la consulta: {}", err))
-            .ok()?;
-
-        let result = stmt
-            .query_row(params![id], |row| {
-                let id: i64 = row.get(0)?;
-                let title: String = row.get(1)?;
-                let content: String = row.get(2)?;
-                let created_at: String = row.get(3)?;
-                let updated_at: String = row.get(4)?;
-
-                let updated_at_date = updated_at.parse::<DateTime<Utc>>()
-                    .map_err(|_| format!("Error parsing updated_at: {}", updated_at))
-                    .unwrap_or_else(|_| Utc::now());
-                let created_at_date = created_at.parse::<DateTime<Utc>>()
-                    .map_err(|_| format!("Error parsing created_at: {}", created_at))
-                    .unwrap_or_else(|_| Utc::now());
-
-                    
-                Ok(Note {
-                        id: Option::Some(id),
-                        title: title,
-                        content: content,
-                        created_at: created_at_date,
-                        updated_at: updated_at_date,
-                    })
-            })
-            .map_err(|err| format!("Error al buscar la nota: {}", err))
-            .ok();
-
-        match result {
-            Some(note) => {
-                Some(note)
-            }
-            _ => {
-                None
-            }
-        }
-    }
+    ///
+    fn find_by_id(&self, id: i64) -> Option<Note>;
 
     ///
-    /// Finds notes by their title in the SQLite database.
+    /// Finds notes whose title contains the given text, ignoring case, leaving out the notes in the trash.
     /// # Arguments
     /// * `title`: The title or partial title of the notes to be found.
     /// # Returns
-    /// * `Option<Vec<Note>>`: An `Option` containing a vector of `Note` objects if found, or `None` if no notes match the given title.
-    ///
-    /// This function performs a case-insensitive search for the title in the notes table.
-    /// It uses a SQL query with a `LIKE` clause to match the title, allowing for partial matches.
-    /// If notes with the specified title are found, it returns them wrapped in `Some(Vec<Note>)`.
-    /// If no notes are found, it returns `None`.
+    /// * `Option<Vec<Note>>`: An `Option` containing the matching notes, or `None` if no notes match the given title.
     ///
-    pub fn find_by_title(&self, title: &str) -> Option<Vec<Note>> {
-        let mut stmt = self
-            .connection
-            .prepare(
-                "SELECT 
-                        id,
-                        title, 
-                        content,
-                        created_at,
-                        updated_at
-                    FROM notes 
-                    WHERE title LIKE '%' || ?1 || '%';",
-            )
-            .map_err(|err| format!("Error al preparar la consulta: {}", err))
-            .ok()?;
-
-        let notes = stmt
-            .query_map(params![title], |row| {
-                let id: i64 = row.get(0)?;
-                let title: String = row.get(1)?;
-                let content: String = row.get(2)?;
-                let created_at: String = row.get(3)?;
-                let updated_at: String = row.get(4)?;
-
-                let updated_at_date = updated_at.parse::<DateTime<Utc>>()
-                    .map_err(|_| format!("Error parsing updated_at: {}", updated_at))
-                    .unwrap_or_else(|_| Utc::now());
-                let created_at_date = created_at.parse::<DateTime<Utc>>()
-                    .map_err(|_| format!("Error parsing created_at: {}", created_at))
-                    .unwrap_or_else(|_| Utc::now());
-
-                Ok(Note {
-                    id: Option::Some(id),
-                    title: title,
-                    content: content,
-                    created_at: created_at_date,
-                    updated_at: updated_at_date,
-                })
-            })
-            .map_err(|err| format!("Error al buscar las notas por título: {}", err))
-            .ok()?;
+    fn find_by_title(&self, title: &str) -> Option<Vec<Note>>;
 
-        let notes_vec: Vec<Note> = notes.filter_map(Result::ok).collect();
-
-        if notes_vec.is_empty() {
-            None
-        } else {
-            Some(notes_vec)
-        }
-    }
+    ///
+    /// Performs a full-text search over the title and content of the notes not in the trash.
+    /// Locked notes are never found, their content is sealed.
+    /// The query supports phrases (`"exact words"`), prefixes (`rust*`) and the boolean
+    /// operators `AND`, `OR` and `NOT`.
+    /// # Arguments
+    /// * `query`: The search query.
+    /// * `limit`: The maximum number of results to return.
+    /// # Returns
+    /// * `Ok(Vec<SearchResult>)`: The matching notes, best matches first.
+    /// * `Err(String)`: An error message if the search fails, starting with
+    ///   `SearchResult::INVALID_QUERY_ERROR` when the query is malformed.
+    ///
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>, String>;
 }
@@ -0,0 +1,60 @@
+use crate::domain::entities::{
+    note::Note,
+    notebook::{Notebook, NotebookDeletion},
+};
+
+///
+/// The `NotebookRepository` trait defines how notebooks are persisted and how notes are organized in them.
+///
+pub trait NotebookRepository {
+    ///
+    /// Saves a new notebook.
+    /// # Arguments
+    /// * `notebook`: A reference to the `Notebook` to be saved.
+    /// # Returns
+    /// * `Ok(i64)`: The ID of the newly created notebook.
+    /// * `Err(String)`: An error message if there is an issue saving the notebook.
+    ///
+    fn save_notebook(&self, notebook: &Notebook) -> Result<i64, String>;
+
+    ///
+    /// Finds every notebook, together with the number of notes (not in the trash) stored directly in each one.
+    /// # Returns
+    /// * `Ok(Vec<Notebook>)`: The notebooks sorted by name, always including the Inbox.
+    /// * `Err(String)`: An error message if the notebooks cannot be read.
+    ///
+    fn find_all_notebooks(&self) -> Result<Vec<Notebook>, String>;
+
+    ///
+    /// Finds the notes stored directly in a notebook, leaving out the notes in the trash.
+    /// # Arguments
+    /// * `notebook_id`: The ID of the notebook.
+    /// # Returns
+    /// * `Ok(Vec<Note>)`: The notes of the notebook, most recently updated first.
+    /// * `Err(String)`: An error message if the notes cannot be read.
+    ///
+    fn find_notes_in_notebook(&self, notebook_id: i64) -> Result<Vec<Note>, String>;
+
+    ///
+    /// Moves a note to another notebook without modifying the note itself.
+    /// # Arguments
+    /// * `note_id`: The ID of the note to move.
+    /// * `notebook_id`: The ID of the destination notebook.
+    /// # Returns
+    /// * `Ok(())`: If the note was moved.
+    /// * `Err(String)`: An error message if the note cannot be moved.
+    ///
+    fn move_note(&self, note_id: i64, notebook_id: i64) -> Result<(), String>;
+
+    ///
+    /// Deletes a notebook.
+    /// # Arguments
+    /// * `id`: The ID of the notebook to delete.
+    /// * `strategy`: Whether its sub-notebooks are deleted too, moving their notes to the trash,
+    ///   or moved to the parent notebook.
+    /// # Returns
+    /// * `Ok(usize)`: The number of notes trashed or moved.
+    /// * `Err(String)`: An error message if the notebook cannot be deleted.
+    ///
+    fn delete_notebook(&self, id: i64, strategy: NotebookDeletion) -> Result<usize, String>;
+}
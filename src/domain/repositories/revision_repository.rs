@@ -0,0 +1,29 @@
+use crate::domain::entities::revision::Revision;
+
+///
+/// The `RevisionRepository` trait defines how the revision history of the notes is read.
+/// Revisions are recorded by the `NoteRepository` itself whenever a note is saved or updated
+/// with a different title or content, so this trait only covers the queries.
+///
+pub trait RevisionRepository {
+    ///
+    /// Finds every revision of a note.
+    /// # Arguments
+    /// * `note_id`: The ID of the note.
+    /// # Returns
+    /// * `Ok(Vec<Revision>)`: The revisions of the note, most recent first.
+    /// * `Err(String)`: An error message if the revisions cannot be read.
+    ///
+    fn find_revisions(&self, note_id: i64) -> Result<Vec<Revision>, String>;
+
+    ///
+    /// Finds a revision by its ID.
+    /// # Arguments
+    /// * `id`: The ID of the revision.
+    /// # Returns
+    /// * `Ok(Some(Revision))`: The revision, if it exists.
+    /// * `Ok(None)`: If there is no revision with the given ID.
+    /// * `Err(String)`: An error message if the revision cannot be read.
+    ///
+    fn find_revision(&self, id: i64) -> Result<Option<Revision>, String>;
+}
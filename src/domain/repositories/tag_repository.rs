@@ -0,0 +1,53 @@
+use crate::domain::entities::{
+    note::Note,
+    tag::{Tag, TagMatch},
+};
+
+///
+/// The `TagRepository` trait defines how tags are queried and reorganized.
+/// Attaching and detaching tags to a single note is done through the `NoteRepository`,
+/// as the tags are part of the `Note`; this trait covers the operations spanning many notes.
+///
+pub trait TagRepository {
+    ///
+    /// Finds every tag attached to at least one note, together with its note count.
+    /// # Returns
+    /// * `Ok(Vec<Tag>)`: The tags sorted by name.
+    /// * `Err(String)`: An error message if the tags cannot be read.
+    ///
+    fn find_all_tags(&self) -> Result<Vec<Tag>, String>;
+
+    ///
+    /// Renames a tag on every note carrying it.
+    /// # Arguments
+    /// * `from`: The normalized name of the tag to rename.
+    /// * `to`: The new normalized name. If it already exists, both tags are merged.
+    /// # Returns
+    /// * `Ok(usize)`: The number of notes affected.
+    /// * `Err(String)`: An error message if the rename fails.
+    ///
+    fn rename_tag(&self, from: &str, to: &str) -> Result<usize, String>;
+
+    ///
+    /// Merges several tags into one: every note carrying any of the `sources` ends up carrying `target`
+    /// and the source tags disappear.
+    /// # Arguments
+    /// * `sources`: The normalized names of the tags to merge.
+    /// * `target`: The normalized name of the resulting tag, created if needed.
+    /// # Returns
+    /// * `Ok(usize)`: The number of notes affected.
+    /// * `Err(String)`: An error message if the merge fails.
+    ///
+    fn merge_tags(&self, sources: &[String], target: &str) -> Result<usize, String>;
+
+    ///
+    /// Finds the notes carrying the given tags.
+    /// # Arguments
+    /// * `tags`: The normalized names of the tags.
+    /// * `mode`: Whether the notes must carry all the tags or any of them.
+    /// # Returns
+    /// * `Ok(Vec<Note>)`: The matching notes.
+    /// * `Err(String)`: An error message if the notes cannot be read.
+    ///
+    fn find_notes_by_tags(&self, tags: &[String], mode: TagMatch) -> Result<Vec<Note>, String>;
+}
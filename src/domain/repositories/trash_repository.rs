@@ -0,0 +1,47 @@
+use crate::domain::entities::note::Note;
+use chrono::prelude::*;
+
+///
+/// The `TrashRepository` trait defines how deleted notes are kept, restored and purged.
+/// Deleting a note through the `NoteRepository` only moves it to the trash; notes in the trash
+/// are hidden from every other query until they are restored or purged for good.
+///
+pub trait TrashRepository {
+    ///
+    /// Finds every note in the trash.
+    /// # Returns
+    /// * `Ok(Vec<Note>)`: The trashed notes, most recently deleted first.
+    /// * `Err(String)`: An error message if the notes cannot be read.
+    ///
+    fn find_trashed(&self) -> Result<Vec<Note>, String>;
+
+    ///
+    /// Takes a note out of the trash. If its notebook no longer exists, the note goes to the Inbox.
+    /// # Arguments
+    /// * `id`: The ID of the trashed note.
+    /// # Returns
+    /// * `Ok(())`: If the note was restored.
+    /// * `Err(String)`: An error message if the note cannot be restored.
+    ///
+    fn restore(&self, id: i64) -> Result<(), String>;
+
+    ///
+    /// Permanently deletes a note from the trash.
+    /// # Arguments
+    /// * `id`: The ID of the trashed note.
+    /// # Returns
+    /// * `Ok(())`: If the note was deleted.
+    /// * `Err(String)`: An error message if the note cannot be deleted.
+    ///
+    fn purge(&self, id: i64) -> Result<(), String>;
+
+    ///
+    /// Permanently deletes every note moved to the trash before the given time.
+    /// # Arguments
+    /// * `before`: The notes deleted earlier than this moment are purged.
+    /// # Returns
+    /// * `Ok(usize)`: The number of notes purged.
+    /// * `Err(String)`: An error message if the notes cannot be deleted.
+    ///
+    fn purge_deleted_before(&self, before: DateTime<Utc>) -> Result<usize, String>;
+}
@@ -0,0 +1,97 @@
+use crate::infrastructure::config::xdg::Xdg;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Where the key of an encrypted database came from, from highest to lowest priority.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySource {
+    /// The `NOTES_DB_KEY` environment variable
+    Environment,
+
+    /// A keyfile, given by the `NOTES_DB_KEYFILE` environment variable or the `[database] keyfile` key
+    Keyfile(PathBuf),
+
+    /// Typed by the user in a terminal
+    Prompt,
+}
+
+/// The passphrase an encrypted database is opened with, and where it came from.
+#[derive(Clone)]
+pub struct DatabaseKey {
+    passphrase: String,
+    source: KeySource,
+}
+
+impl DatabaseKey {
+    /// Environment variable holding the passphrase of the database
+    pub const ENV_VAR: &'static str = "NOTES_DB_KEY";
+
+    /// Environment variable holding the path of a file with the passphrase of the database
+    pub const KEYFILE_ENV_VAR: &'static str = "NOTES_DB_KEYFILE";
+
+    /// Resolves the key of the database without asking for it, taking the first one given of:
+    /// the `NOTES_DB_KEY` environment variable, the file named by `NOTES_DB_KEYFILE`
+    /// and the file named by the `[database] keyfile` key of the configuration file.
+    ///
+    /// # Arguments
+    /// - `configured`: The keyfile given in the configuration file, see `Settings::database_keyfile`.
+    ///
+    /// # Returns
+    /// - `Ok(Some(DatabaseKey))` with the key.
+    /// - `Ok(None)` if no key is given, it has to be asked for.
+    /// - `Err(String)` if the keyfile cannot be read or is empty.
+    pub fn resolve(configured: Option<&Path>) -> Result<Option<Self>, String> {
+        if let Some(passphrase) = std::env::var(DatabaseKey::ENV_VAR).ok().filter(|passphrase| !passphrase.is_empty()) {
+            return Ok(Some(DatabaseKey::new(passphrase, KeySource::Environment)));
+        }
+
+        if let Some(path) = std::env::var_os(DatabaseKey::KEYFILE_ENV_VAR).filter(|path| !path.is_empty()) {
+            let path = Xdg::expand_home(&path.to_string_lossy());
+            return DatabaseKey::from_keyfile(&path).map(Some);
+        }
+
+        configured.map(DatabaseKey::from_keyfile).transpose()
+    }
+
+    /// Reads the key from a file: its whole content, without the trailing line break.
+    ///
+    /// # Errors
+    /// Returns an error message if the file cannot be read or holds nothing.
+    pub fn from_keyfile(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Error al leer el fichero de clave {}: {}", path.display(), err))?;
+
+        let passphrase = content.trim_end_matches(['\n', '\r']);
+        if passphrase.is_empty() {
+            return Err(format!("Error al leer el fichero de clave {}: está vacío", path.display()));
+        }
+
+        Ok(DatabaseKey::new(passphrase.to_string(), KeySource::Keyfile(path.to_path_buf())))
+    }
+
+    /// A key typed by the user.
+    pub fn typed(passphrase: String) -> Self {
+        DatabaseKey::new(passphrase, KeySource::Prompt)
+    }
+
+    fn new(passphrase: String, source: KeySource) -> Self {
+        DatabaseKey { passphrase, source }
+    }
+
+    pub fn get_passphrase(&self) -> &str {
+        &self.passphrase
+    }
+
+    pub fn get_source(&self) -> &KeySource {
+        &self.source
+    }
+}
+
+// The passphrase is never printed, not even in debug output
+impl std::fmt::Debug for DatabaseKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabaseKey").field("source", &self.source).finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,139 @@
+use crate::infrastructure::{config::xdg::Xdg, persistence::sqlite_note_repository::SqliteNoteRepository};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Where the location of the database came from, from highest to lowest priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// The `--db` command line flag
+    Flag,
+
+    /// The `NOTES_DB` environment variable
+    Environment,
+
+    /// The `[database] path` key of the configuration file
+    ConfigFile,
+
+    /// The default location inside the XDG data directory
+    Default,
+}
+
+/// The resolved path of the database file and where it came from.
+#[derive(Debug, Clone)]
+pub struct DatabaseLocation {
+    path: PathBuf,
+    source: Source,
+}
+
+impl DatabaseLocation {
+    /// Environment variable holding the path of the database
+    pub const ENV_VAR: &'static str = "NOTES_DB";
+
+    /// Name of the database file inside the data directory
+    pub const DEFAULT_FILE_NAME: &'static str = "notes.db";
+
+    /// Resolves the path of the database, taking the first one given of:
+    /// the `--db` flag, the `NOTES_DB` environment variable, the `[database] path` key of the
+    /// configuration file and `$XDG_DATA_HOME/notes-rust/notes.db`.
+    ///
+    /// # Arguments
+    /// - `flag`: The value of the `--db` flag, if given.
+    /// - `configured`: The path given in the configuration file, see `Settings::database_path`.
+    ///
+    /// # Returns
+    /// - `Ok(DatabaseLocation)` with the path, which may not exist yet.
+    /// - `Err(String)` if no path is given and the home directory is unknown.
+    pub fn resolve(flag: Option<&Path>, configured: Option<&Path>) -> Result<Self, String> {
+        if let Some(path) = flag {
+            return Ok(DatabaseLocation::new(path.to_path_buf(), Source::Flag));
+        }
+
+        if let Some(path) = std::env::var_os(DatabaseLocation::ENV_VAR).filter(|path| !path.is_empty()) {
+            let path = Xdg::expand_home(&path.to_string_lossy());
+            return Ok(DatabaseLocation::new(path, Source::Environment));
+        }
+
+        if let Some(path) = configured {
+            return Ok(DatabaseLocation::new(path.to_path_buf(), Source::ConfigFile));
+        }
+
+        let dir = Xdg::data_dir().ok_or_else(|| {
+            format!(
+                "Error al localizar la base de datos: no se conoce el directorio personal, usa --db o {}",
+                DatabaseLocation::ENV_VAR
+            )
+        })?;
+
+        Ok(DatabaseLocation::new(dir.join(DatabaseLocation::DEFAULT_FILE_NAME), Source::Default))
+    }
+
+    fn new(path: PathBuf, source: Source) -> Self {
+        DatabaseLocation { path, source }
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Creates the directory of the database file if it does not exist.
+    ///
+    /// # Errors
+    /// Returns an error message if the directory cannot be created.
+    pub fn create_parent_dir(&self) -> Result<(), String> {
+        match self.path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => fs::create_dir_all(dir).map_err(|err| {
+                format!("Error al crear el directorio {}: {}", dir.display(), err)
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Looks for a database left by older versions in the working directory (`./notes-rust.db`)
+    /// that could be moved to the default location.
+    ///
+    /// Only the default location is considered, and only while it does not exist yet, so the
+    /// migration is offered once: after moving the file, or after starting with a new database.
+    ///
+    /// # Returns
+    /// - `Some(PathBuf)` with the path of the old database.
+    /// - `None` if there is nothing to migrate.
+    pub fn legacy_database(&self) -> Option<PathBuf> {
+        let legacy = PathBuf::from(SqliteNoteRepository::DEFAULT_DATABASE_PATH);
+
+        if self.source != Source::Default || self.path.exists() || !legacy.is_file() {
+            return None;
+        }
+
+        Some(legacy)
+    }
+
+    /// Moves the old database to this location, copying it when both are on different file systems.
+    ///
+    /// # Arguments
+    /// - `legacy`: The path of the old database, see `legacy_database`.
+    ///
+    /// # Errors
+    /// Returns an error message if the file cannot be moved; the old database is left untouched.
+    pub fn migrate_from(&self, legacy: &Path) -> Result<(), String> {
+        self.create_parent_dir()?;
+
+        if fs::rename(legacy, &self.path).is_ok() {
+            return Ok(());
+        }
+
+        fs::copy(legacy, &self.path)
+            .and_then(|_| fs::remove_file(legacy))
+            .map_err(|err| {
+                // Do not leave a partial copy behind, it would hide the old database next time
+                let _ = fs::remove_file(&self.path);
+                format!(
+                    "Error al mover la base de datos {} a {}: {}",
+                    legacy.display(),
+                    self.path.display(),
+                    err
+                )
+            })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod database_key;
+pub mod database_location;
+pub mod settings;
+pub mod xdg;
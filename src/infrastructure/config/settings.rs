@@ -0,0 +1,287 @@
+use crate::{
+    application::{palette::Palette, use_cases::trash::purge_expired::PurgeExpiredNotes},
+    domain::entities::note::NoteLimits,
+    infrastructure::config::xdg::Xdg,
+};
+use ansi_term::Colour;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Settings of the whole application, read from `~/.config/notes-rust/config.toml`.
+/// Every key is optional; missing keys keep the defaults.
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    /// Path of the database given by the `[database] path` key, see `DatabaseLocation`
+    pub database_path: Option<PathBuf>,
+
+    /// File holding the passphrase of an encrypted database, given by the `[database] keyfile` key, see `DatabaseKey`
+    pub database_keyfile: Option<PathBuf>,
+
+    /// Maximum lengths of the title and content of the notes
+    pub limits: NoteLimits,
+
+    pub display: DisplaySettings,
+
+    /// Colors of the messages, installed at startup with `Palette::install`
+    pub colors: Palette,
+
+    pub confirm: ConfirmSettings,
+
+    pub trash: TrashSettings,
+}
+
+/// How the interactive menu looks.
+#[derive(Debug, Clone)]
+pub struct DisplaySettings {
+    /// Whether the banner is shown when the menu starts
+    pub banner: bool,
+
+    /// Text shown instead of the default banner
+    pub banner_text: Option<String>,
+
+    /// Number of characters of the content echoed after closing the editor, longer contents are cut with `...`
+    pub preview_length: usize,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        DisplaySettings {
+            banner: true,
+            banner_text: None,
+            preview_length: 20,
+        }
+    }
+}
+
+/// Default answers of the confirmation prompts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConfirmSettings {
+    /// Default answer when saving changes: creating or updating a note, restoring a revision, renaming tags
+    pub save: bool,
+
+    /// Default answer when deleting: moving to the trash, purging, deleting notebooks
+    pub delete: bool,
+}
+
+/// How long deleted notes are kept.
+#[derive(Debug, Clone, Copy)]
+pub struct TrashSettings {
+    /// Days a note is kept in the trash before it is purged at startup, `0` keeps the notes forever.
+    /// The `NOTES_TRASH_RETENTION_DAYS` environment variable takes precedence.
+    pub retention_days: i64,
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        TrashSettings {
+            retention_days: PurgeExpiredNotes::DEFAULT_RETENTION_DAYS,
+        }
+    }
+}
+
+/// The configuration file as written by the user, before validating it.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SettingsFile {
+    database: DatabaseSection,
+    limits: LimitsSection,
+    display: DisplaySection,
+    colors: ColorsSection,
+    confirm: ConfirmSection,
+    trash: TrashSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct DatabaseSection {
+    path: Option<String>,
+    keyfile: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct LimitsSection {
+    max_title_length: Option<usize>,
+    max_content_length: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct DisplaySection {
+    banner: Option<bool>,
+    banner_text: Option<String>,
+    preview_length: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ColorsSection {
+    enabled: Option<bool>,
+    success: Option<String>,
+    warning: Option<String>,
+    error: Option<String>,
+    accent: Option<String>,
+    highlight: Option<String>,
+    secondary: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfirmSection {
+    save: Option<bool>,
+    delete: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TrashSection {
+    retention_days: Option<i64>,
+}
+
+impl Settings {
+    /// Shortest `display.preview_length` allowed, room for one character and `...`
+    pub const MIN_PREVIEW_LENGTH: usize = 4;
+
+    /// Loads the settings from the configuration file, see `Xdg::config_file`.
+    ///
+    /// # Returns
+    /// - `Ok(Settings)` with the settings, the defaults if the file does not exist.
+    /// - `Err(String)` if the file cannot be read, is not valid TOML or has invalid values;
+    ///   the message lists every problem found.
+    pub fn load() -> Result<Self, String> {
+        let Some(file) = Xdg::config_file() else {
+            return Ok(Settings::default());
+        };
+
+        match fs::read_to_string(&file) {
+            Ok(text) => Settings::parse(&text, &file),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Settings::default()),
+            Err(err) => Err(format!(
+                "Error al leer el fichero de configuración {}: {}",
+                file.display(),
+                err
+            )),
+        }
+    }
+
+    /// Parses and validates the contents of a configuration file.
+    ///
+    /// # Arguments
+    /// - `text`: The contents of the file.
+    /// - `file`: The path of the file, used in the error messages.
+    pub fn parse(text: &str, file: &Path) -> Result<Self, String> {
+        let raw: SettingsFile = toml::from_str(text).map_err(|err| {
+            format!(
+                "Error al leer el fichero de configuración {}: {}",
+                file.display(),
+                err
+            )
+        })?;
+
+        let mut problems: Vec<String> = Vec::new();
+        let mut settings = Settings::default();
+
+        settings.database_path = raw
+            .database
+            .path
+            .filter(|path| !path.trim().is_empty())
+            .map(|path| Xdg::expand_home(path.trim()));
+        settings.database_keyfile = raw
+            .database
+            .keyfile
+            .filter(|path| !path.trim().is_empty())
+            .map(|path| Xdg::expand_home(path.trim()));
+
+        for (key, value, target) in [
+            ("limits.max_title_length", raw.limits.max_title_length, &mut settings.limits.max_title_length),
+            ("limits.max_content_length", raw.limits.max_content_length, &mut settings.limits.max_content_length),
+        ] {
+            match value {
+                Some(0) => problems.push(format!("`{}` debe ser mayor que 0", key)),
+                Some(value) => *target = value,
+                None => {}
+            }
+        }
+
+        settings.display.banner = raw.display.banner.unwrap_or(settings.display.banner);
+        match raw.display.banner_text {
+            Some(text) if text.trim().is_empty() => problems.push(String::from(
+                "`display.banner_text` no puede estar vacío, usa `display.banner = false` para ocultar el banner",
+            )),
+            text => settings.display.banner_text = text,
+        }
+        match raw.display.preview_length {
+            Some(length) if length < Settings::MIN_PREVIEW_LENGTH => problems.push(format!(
+                "`display.preview_length` debe ser al menos {}",
+                Settings::MIN_PREVIEW_LENGTH
+            )),
+            Some(length) => settings.display.preview_length = length,
+            None => {}
+        }
+
+        settings.colors.enabled = raw.colors.enabled.unwrap_or(settings.colors.enabled);
+        for (key, value, target) in [
+            ("colors.success", raw.colors.success, &mut settings.colors.success),
+            ("colors.warning", raw.colors.warning, &mut settings.colors.warning),
+            ("colors.error", raw.colors.error, &mut settings.colors.error),
+            ("colors.accent", raw.colors.accent, &mut settings.colors.accent),
+            ("colors.highlight", raw.colors.highlight, &mut settings.colors.highlight),
+            ("colors.secondary", raw.colors.secondary, &mut settings.colors.secondary),
+        ] {
+            if let Some(name) = value {
+                match Settings::parse_colour(&name) {
+                    Some(colour) => *target = colour,
+                    None => problems.push(format!(
+                        "`{}`: color desconocido `{}`, usa black, red, green, yellow, blue, purple, cyan, white o un número de 0 a 255",
+                        key, name
+                    )),
+                }
+            }
+        }
+
+        settings.confirm.save = raw.confirm.save.unwrap_or(settings.confirm.save);
+        settings.confirm.delete = raw.confirm.delete.unwrap_or(settings.confirm.delete);
+
+        match raw.trash.retention_days {
+            Some(days) if days < 0 => problems.push(String::from(
+                "`trash.retention_days` no puede ser negativo, usa 0 para conservar las notas para siempre",
+            )),
+            Some(days) => settings.trash.retention_days = days,
+            None => {}
+        }
+
+        if !problems.is_empty() {
+            return Err(format!(
+                "Error en el fichero de configuración {}:\n  - {}",
+                file.display(),
+                problems.join("\n  - ")
+            ));
+        }
+
+        Ok(settings)
+    }
+
+    /// Turns a color name (`red`, `Blue`...) or a 256-color palette number (`208`) into a `Colour`.
+    fn parse_colour(name: &str) -> Option<Colour> {
+        let name = name.trim().to_lowercase();
+
+        if let Ok(number) = name.parse::<u8>() {
+            return Some(Colour::Fixed(number));
+        }
+
+        match name.as_str() {
+            "black" => Some(Colour::Black),
+            "red" => Some(Colour::Red),
+            "green" => Some(Colour::Green),
+            "yellow" => Some(Colour::Yellow),
+            "blue" => Some(Colour::Blue),
+            "purple" | "magenta" => Some(Colour::Purple),
+            "cyan" => Some(Colour::Cyan),
+            "white" => Some(Colour::White),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+/// Locations of the application files, following the XDG base directory specification.
+pub struct Xdg;
+
+impl Xdg {
+    /// Name of the directory of the application inside the XDG base directories
+    pub const APP_DIR: &'static str = "notes-rust";
+
+    /// Name of the configuration file inside the configuration directory
+    pub const CONFIG_FILE_NAME: &'static str = "config.toml";
+
+    /// Returns the directory where the application keeps its data:
+    /// `$XDG_DATA_HOME/notes-rust`, or `~/.local/share/notes-rust` when the variable is not set.
+    ///
+    /// # Returns
+    /// - `Some(PathBuf)` with the directory, which may not exist yet.
+    /// - `None` if the home directory is unknown.
+    pub fn data_dir() -> Option<PathBuf> {
+        Xdg::base_dir("XDG_DATA_HOME", ".local/share").map(|dir| dir.join(Xdg::APP_DIR))
+    }
+
+    /// Returns the directory where the application keeps state worth surviving a restart,
+    /// such as unsaved edits: `$XDG_STATE_HOME/notes-rust`, or `~/.local/state/notes-rust`
+    /// when the variable is not set.
+    ///
+    /// # Returns
+    /// - `Some(PathBuf)` with the directory, which may not exist yet.
+    /// - `None` if the home directory is unknown.
+    pub fn state_dir() -> Option<PathBuf> {
+        Xdg::base_dir("XDG_STATE_HOME", ".local/state").map(|dir| dir.join(Xdg::APP_DIR))
+    }
+
+    /// Returns the directory where the application looks for its configuration:
+    /// `$XDG_CONFIG_HOME/notes-rust`, or `~/.config/notes-rust` when the variable is not set.
+    ///
+    /// # Returns
+    /// - `Some(PathBuf)` with the directory, which may not exist.
+    /// - `None` if the home directory is unknown.
+    pub fn config_dir() -> Option<PathBuf> {
+        Xdg::base_dir("XDG_CONFIG_HOME", ".config").map(|dir| dir.join(Xdg::APP_DIR))
+    }
+
+    /// Returns the path of the configuration file, see `config_dir`.
+    pub fn config_file() -> Option<PathBuf> {
+        Xdg::config_dir().map(|dir| dir.join(Xdg::CONFIG_FILE_NAME))
+    }
+
+    /// Expands a leading `~` to the home directory, leaving any other path untouched.
+    ///
+    /// # Arguments
+    /// - `path`: The path as written by the user.
+    pub fn expand_home(path: &str) -> PathBuf {
+        match (path.strip_prefix('~'), Xdg::home()) {
+            (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+                home.join(rest.trim_start_matches('/'))
+            }
+            _ => PathBuf::from(path),
+        }
+    }
+
+    /// Reads a base directory from `variable`, ignoring it when empty or relative as the
+    /// specification asks, and falls back to `fallback` inside the home directory.
+    fn base_dir(variable: &str, fallback: &str) -> Option<PathBuf> {
+        std::env::var_os(variable)
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| Xdg::home().map(|home| home.join(fallback)))
+    }
+
+    /// Returns the home directory of the user, from `$HOME`.
+    fn home() -> Option<PathBuf> {
+        std::env::var_os("HOME")
+            .map(PathBuf::from)
+            .filter(|home| !home.as_os_str().is_empty())
+    }
+}
@@ -1 +1,3 @@
+pub mod config;
+pub mod persistence;
 pub mod ui;
\ No newline at end of file
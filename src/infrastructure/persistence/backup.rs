@@ -0,0 +1,473 @@
+use crate::infrastructure::persistence::{database_encryption::DatabaseEncryption, migrations::Migrator};
+use chrono::prelude::*;
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use rusqlite::{Connection, OpenFlags, backup::StepResult};
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+/// How a backup is written.
+#[derive(Clone, Default)]
+pub struct BackupOptions {
+    /// Compress the copy with gzip
+    pub compress: bool,
+
+    /// Number of backups of the database kept in the directory, the older ones are deleted
+    pub keep: Option<usize>,
+
+    /// Passphrase of an encrypted database, its backups are encrypted with it as well
+    pub key: Option<String>,
+}
+
+/// The backup written and the old ones deleted to make room for it.
+#[derive(Debug, Clone)]
+pub struct BackupOutcome {
+    pub path: PathBuf,
+    pub removed: Vec<PathBuf>,
+}
+
+/// The schema versions of the restored database.
+#[derive(Debug, Clone, Copy)]
+pub struct RestoreOutcome {
+    /// Version of the backup
+    pub version: u32,
+
+    /// Version of the database once migrated, the latest one
+    pub migrated_to: u32,
+}
+
+/// Why a backup could not be restored.
+#[derive(Debug, Clone)]
+pub enum RestoreError {
+    /// The file is not a healthy backup of a notes database, the live database was not touched
+    Invalid(String),
+
+    /// The backup could not be read or copied
+    Failed(String),
+}
+
+///
+/// The `DatabaseBackup` struct writes and restores copies of the SQLite database.
+///
+/// Copies are made with the online backup API of SQLite, so they are consistent even while
+/// another process is writing to the database. They are named after the database and the UTC time
+/// they were made, e.g. `notes-20250301-102000.db` (`.db.gz` when compressed), so sorting them
+/// by name sorts them by age. The backups of an encrypted database are encrypted with the same
+/// passphrase, and can only be restored into a database encrypted with it.
+///
+pub struct DatabaseBackup;
+
+impl DatabaseBackup {
+    /// Name of the directory next to the database where backups are written by default
+    pub const DIRECTORY_NAME: &'static str = "backups";
+
+    /// Format of the time in the name of the backups
+    const TIME_FORMAT: &'static str = "%Y%m%d-%H%M%S";
+
+    /// Extension of the backups, followed by `.gz` when compressed
+    const EXTENSION: &'static str = "db";
+
+    /// Pages copied at once, the database is unlocked between steps so other writers are not blocked
+    const PAGES_PER_STEP: i32 = 256;
+
+    /// The directory where the backups of a database are written by default.
+    pub fn default_dir(database: &Path) -> PathBuf {
+        database
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(DatabaseBackup::DIRECTORY_NAME)
+    }
+
+    ///
+    /// Finds the copies of a database that can be read without a passphrase: the backups in
+    /// `default_dir`, compressed or not, and the copies `Migrator` leaves next to the database
+    /// before upgrading its schema (`<file>.v<version>-<timestamp>.bak`).
+    ///
+    /// # Arguments
+    /// * `database`: The path of the database.
+    ///
+    /// # Returns
+    /// The plain copies found, sorted by path. Directories that cannot be read are skipped.
+    ///
+    pub fn plain_copies(database: &Path) -> Vec<PathBuf> {
+        let file_name = database.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let directory = database.parent().filter(|parent| !parent.as_os_str().is_empty()).unwrap_or(Path::new("."));
+
+        let migration_backups: Vec<PathBuf> = fs::read_dir(directory)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        let name = path.file_name().unwrap_or_default().to_string_lossy();
+                        name.strip_prefix(file_name.as_str())
+                            .is_some_and(|rest| rest.starts_with(".v") && rest.ends_with(".bak"))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let backups = DatabaseBackup::list(&DatabaseBackup::default_dir(database), &DatabaseBackup::stem(database))
+            .unwrap_or_default();
+
+        let mut plain: Vec<PathBuf> = migration_backups
+            .into_iter()
+            .chain(backups)
+            .filter(|path| path.is_file() && !DatabaseBackup::is_encrypted(path))
+            .collect();
+        plain.sort();
+
+        plain
+    }
+
+    ///
+    /// Writes a copy of the database to a directory, then deletes the oldest backups
+    /// if more than `options.keep` are left.
+    ///
+    /// The copy is written to a temporary file first and renamed once complete,
+    /// so an interrupted backup never looks like a valid one.
+    ///
+    /// # Arguments
+    /// * `connection`: The connection to the database, opened on a file.
+    /// * `directory`: The directory the backup is written to, created if missing.
+    /// * `options`: Whether the backup is compressed and how many are kept.
+    ///
+    /// # Returns
+    /// * `Ok(BackupOutcome)`: The path of the backup and of the backups deleted.
+    /// * `Err(String)`: An error message if the copy cannot be written.
+    ///
+    /// # Example
+    /// ```
+    /// let options = BackupOptions { compress: true, keep: Some(7), key: None };
+    /// let outcome = DatabaseBackup::create(note_repository.connection(), Path::new("backups"), &options)?;
+    /// ```
+    ///
+    pub fn create(connection: &Connection, directory: &Path, options: &BackupOptions) -> Result<BackupOutcome, String> {
+        let database = DatabaseBackup::database_path(connection)?;
+        let stem = DatabaseBackup::stem(&database);
+
+        fs::create_dir_all(directory)
+            .map_err(|err| format!("Error al crear el directorio {}: {}", directory.display(), err))?;
+
+        let path = DatabaseBackup::free_name(directory, &stem, options.compress);
+        let partial = directory.join(format!(
+            ".{}.partial",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        ));
+
+        let written = DatabaseBackup::copy(connection, &partial, options.key.as_deref())
+            .and_then(|_| {
+                if !options.compress {
+                    return fs::rename(&partial, &path)
+                        .map_err(|err| format!("Error al crear la copia de seguridad: {}", err));
+                }
+                DatabaseBackup::compress(&partial, &path)
+            });
+        // The temporary file is left when the copy fails halfway, or after compressing it
+        let _ = fs::remove_file(&partial);
+        written?;
+
+        let removed = match options.keep {
+            Some(keep) => DatabaseBackup::rotate(directory, &stem, keep)?,
+            None => Vec::new(),
+        };
+
+        Ok(BackupOutcome { path, removed })
+    }
+
+    ///
+    /// Replaces the content of the database with a backup.
+    ///
+    /// The backup is checked before anything is touched: it must pass `PRAGMA integrity_check`
+    /// and hold a schema written by this application, not newer than the one it supports.
+    /// It is then copied page by page into the live database with the online backup API,
+    /// in a single step other connections see at once, and migrated to the latest schema.
+    ///
+    /// # Arguments
+    /// * `database`: The path of the live database.
+    /// * `backup`: The backup file, compressed with gzip or not.
+    /// * `key`: The passphrase of the live database when it is encrypted, which must open the backup too.
+    ///
+    /// # Returns
+    /// * `Ok(RestoreOutcome)`: The schema version of the backup and of the restored database.
+    /// * `Err(RestoreError::Invalid)`: If the file is not a valid backup, the database is left as it was.
+    /// * `Err(RestoreError::Failed)`: If the file cannot be read or the copy fails.
+    ///
+    pub fn restore(database: &Path, backup: &Path, key: Option<&str>) -> Result<RestoreOutcome, RestoreError> {
+        if !backup.is_file() {
+            return Err(RestoreError::Failed(format!("No existe el fichero {}", backup.display())));
+        }
+
+        // A compressed backup is expanded next to the database, SQLite can only read plain files
+        let expanded = DatabaseBackup::is_compressed(backup).then(|| {
+            let file_name = database.file_name().unwrap_or_default().to_string_lossy();
+            database.with_file_name(format!(".{}.restore", file_name))
+        });
+        if let Some(expanded) = &expanded {
+            DatabaseBackup::decompress(backup, expanded).map_err(RestoreError::Failed)?;
+        }
+
+        let result = DatabaseBackup::restore_file(database, expanded.as_deref().unwrap_or(backup), backup, key);
+        if let Some(expanded) = &expanded {
+            let _ = fs::remove_file(expanded);
+        }
+
+        result
+    }
+
+    /// Restores the plain copy `file` of the backup, naming it by `backup` as given by the user
+    /// rather than by the file it was expanded to.
+    fn restore_file(
+        database: &Path,
+        file: &Path,
+        backup: &Path,
+        key: Option<&str>,
+    ) -> Result<RestoreOutcome, RestoreError> {
+        let version = DatabaseBackup::validate(file, backup, key)?;
+
+        let mut connection = Connection::open(database)
+            .map_err(|err| RestoreError::Failed(format!("Error al abrir la base de datos: {}", err)))?;
+        if let Some(key) = key {
+            DatabaseEncryption::apply_key(&connection, key).map_err(RestoreError::Failed)?;
+        }
+
+        // The backup is opened with the key of the database, SQLCipher only copies pages between equal keys
+        let source = Connection::open_with_flags(file, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|err| RestoreError::Failed(format!("Error al abrir la copia de seguridad: {}", err)))?;
+        if let Some(key) = key {
+            DatabaseEncryption::apply_key(&source, key).map_err(RestoreError::Failed)?;
+        }
+
+        // A negative number of pages copies the whole backup in a single step
+        let step = rusqlite::backup::Backup::new(&source, &mut connection).and_then(|copy| copy.step(-1));
+        match step {
+            Ok(StepResult::Done) => {}
+            Ok(_) => {
+                return Err(RestoreError::Failed(String::from(
+                    "Error al restaurar la copia de seguridad: la base de datos está ocupada, inténtalo de nuevo",
+                )));
+            }
+            Err(err) => {
+                return Err(RestoreError::Failed(format!("Error al restaurar la copia de seguridad: {}", err)));
+            }
+        }
+
+        let migrated_to = Migrator::run(&mut connection, Some(database)).map_err(RestoreError::Failed)?;
+
+        Ok(RestoreOutcome { version, migrated_to })
+    }
+
+    /// Checks the plain copy `file` of a backup, returning its schema version.
+    /// Problems are reported on `backup`, the file given by the user.
+    fn validate(file: &Path, backup: &Path, key: Option<&str>) -> Result<u32, RestoreError> {
+        let invalid = |reason: String| RestoreError::Invalid(format!("{} no es una copia válida: {}", backup.display(), reason));
+
+        // Pages are copied as they are, so the backup must be encrypted just like the database
+        match (DatabaseEncryption::is_encrypted(file), key.is_some()) {
+            (true, false) => {
+                return Err(invalid(String::from(
+                    "está cifrada, pero la base de datos no; cífrala antes con `encrypt`",
+                )));
+            }
+            (false, true) => {
+                return Err(invalid(String::from(
+                    "no está cifrada, pero la base de datos sí; descífrala antes con `decrypt`",
+                )));
+            }
+            _ => {}
+        }
+
+        let connection = Connection::open_with_flags(file, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|err| invalid(err.to_string()))?;
+        if let Some(key) = key {
+            DatabaseEncryption::apply_key(&connection, key)
+                .map_err(|_| invalid(String::from("la clave de la base de datos no la abre")))?;
+        }
+
+        let problems: Vec<String> = connection
+            .prepare("PRAGMA integrity_check")
+            .and_then(|mut statement| {
+                statement
+                    .query_map([], |row| row.get::<_, String>(0))?
+                    .collect::<Result<Vec<String>, _>>()
+            })
+            .map_err(|err| invalid(err.to_string()))?;
+        if problems != ["ok"] {
+            return Err(invalid(format!("está dañada ({})", problems.join("; "))));
+        }
+
+        let version = Migrator::current_version(&connection).map_err(invalid)?;
+        let latest = Migrator::latest_version();
+        if version == 0 {
+            return Err(invalid(String::from("no es una base de datos de notas")));
+        }
+        if version > latest {
+            return Err(invalid(format!(
+                "usa la versión {} del esquema, pero esta versión de la aplicación solo soporta hasta la {}",
+                version, latest
+            )));
+        }
+
+        let has_notes = connection
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'notes'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map_err(|err| invalid(err.to_string()))?;
+        if has_notes == 0 {
+            return Err(invalid(String::from("no tiene la tabla de notas")));
+        }
+
+        Ok(version)
+    }
+
+    /// Copies the database to a file with the online backup API, encrypted with `key` when given.
+    fn copy(connection: &Connection, path: &Path, key: Option<&str>) -> Result<(), String> {
+        let mut destination =
+            Connection::open(path).map_err(|err| format!("Error al crear la copia de seguridad: {}", err))?;
+        if let Some(key) = key {
+            DatabaseEncryption::apply_key(&destination, key)?;
+        }
+
+        rusqlite::backup::Backup::new(connection, &mut destination)
+            .and_then(|backup| {
+                backup.run_to_completion(DatabaseBackup::PAGES_PER_STEP, std::time::Duration::from_millis(10), None)
+            })
+            .map_err(|err| format!("Error al crear la copia de seguridad: {}", err))
+    }
+
+    fn compress(source: &Path, destination: &Path) -> Result<(), String> {
+        let error = |err: io::Error| format!("Error al comprimir la copia de seguridad: {}", err);
+
+        let mut input = BufReader::new(File::open(source).map_err(error)?);
+        let output = BufWriter::new(File::create(destination).map_err(error)?);
+        let mut encoder = GzEncoder::new(output, Compression::default());
+
+        let written = io::copy(&mut input, &mut encoder)
+            .and_then(|_| encoder.finish())
+            .and_then(|mut output| output.flush());
+        if let Err(err) = written {
+            let _ = fs::remove_file(destination);
+            return Err(error(err));
+        }
+
+        Ok(())
+    }
+
+    fn decompress(source: &Path, destination: &Path) -> Result<(), String> {
+        let error = |err: io::Error| format!("Error al descomprimir {}: {}", source.display(), err);
+
+        let mut decoder = GzDecoder::new(BufReader::new(File::open(source).map_err(error)?));
+        let mut output = BufWriter::new(File::create(destination).map_err(error)?);
+
+        let written = io::copy(&mut decoder, &mut output).and_then(|_| output.flush());
+        if let Err(err) = written {
+            let _ = fs::remove_file(destination);
+            return Err(error(err));
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the oldest backups of the database, keeping the newest `keep`.
+    fn rotate(directory: &Path, stem: &str, keep: usize) -> Result<Vec<PathBuf>, String> {
+        // Sorted by time, then by number, so `notes-<time>-2.db` comes after `notes-<time>.db`
+        let mut backups = DatabaseBackup::list(directory, stem)?;
+        backups.sort_by_key(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let rest = name[stem.len() + 1..].split('.').next().unwrap_or_default().to_string();
+            let (time, counter) = rest.split_at(rest.len().min(15));
+            (time.to_string(), counter.trim_start_matches('-').parse::<u32>().unwrap_or(1))
+        });
+
+        let excess = backups.len().saturating_sub(keep);
+        let mut removed = Vec::new();
+        for path in backups.into_iter().take(excess) {
+            fs::remove_file(&path)
+                .map_err(|err| format!("Error al borrar la copia de seguridad {}: {}", path.display(), err))?;
+            removed.push(path);
+        }
+
+        Ok(removed)
+    }
+
+    /// The backups of a database found in a directory, recognized by their name.
+    fn list(directory: &Path, stem: &str) -> Result<Vec<PathBuf>, String> {
+        let entries = fs::read_dir(directory)
+            .map_err(|err| format!("Error al leer el directorio {}: {}", directory.display(), err))?;
+
+        Ok(entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                let time = name
+                    .strip_prefix(stem)
+                    .and_then(|rest| rest.strip_prefix('-'))
+                    .and_then(|rest| rest.get(..15))
+                    .unwrap_or_default();
+
+                path.is_file()
+                    && NaiveDateTime::parse_from_str(time, DatabaseBackup::TIME_FORMAT).is_ok()
+                    && (name.ends_with(".db") || name.ends_with(".db.gz"))
+            })
+            .collect())
+    }
+
+    /// A name not taken yet, numbered when two backups are made within the same second.
+    fn free_name(directory: &Path, stem: &str, compress: bool) -> PathBuf {
+        let time = Utc::now().format(DatabaseBackup::TIME_FORMAT);
+        let extension = if compress {
+            format!("{}.gz", DatabaseBackup::EXTENSION)
+        } else {
+            DatabaseBackup::EXTENSION.to_string()
+        };
+
+        // Taken by a backup compressed or not, so no two backups share their time and number
+        let taken = |name: &str| {
+            directory.join(format!("{}.{}", name, DatabaseBackup::EXTENSION)).exists()
+                || directory.join(format!("{}.{}.gz", name, DatabaseBackup::EXTENSION)).exists()
+        };
+
+        let mut name = format!("{}-{}", stem, time);
+        let mut counter = 2;
+        while taken(&name) {
+            name = format!("{}-{}-{}", stem, time, counter);
+            counter += 1;
+        }
+
+        directory.join(format!("{}.{}", name, extension))
+    }
+
+    /// Whether a backup is encrypted, looking inside it when compressed.
+    fn is_encrypted(path: &Path) -> bool {
+        if !DatabaseBackup::is_compressed(path) {
+            return DatabaseEncryption::is_encrypted(path);
+        }
+
+        File::open(path).is_ok_and(|file| DatabaseEncryption::is_encrypted_content(GzDecoder::new(BufReader::new(file))))
+    }
+
+    fn is_compressed(path: &Path) -> bool {
+        path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("gz"))
+    }
+
+    fn stem(database: &Path) -> String {
+        database
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .filter(|stem| !stem.is_empty())
+            .unwrap_or_else(|| String::from("notes"))
+    }
+
+    /// The file of the database, in-memory databases cannot be backed up.
+    fn database_path(connection: &Connection) -> Result<PathBuf, String> {
+        connection
+            .path()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .ok_or_else(|| String::from("Error al crear la copia de seguridad: la base de datos no está en un fichero"))
+    }
+}
@@ -0,0 +1,200 @@
+use crate::infrastructure::persistence::migrations::Migrator;
+use rusqlite::{Connection, params};
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+///
+/// The `DatabaseEncryption` struct encrypts the whole database file with SQLCipher, changes its
+/// passphrase and decrypts it back to a plain SQLite file.
+///
+/// SQLCipher encrypts every page of the file with AES-256, deriving the key from the passphrase
+/// with PBKDF2, so nothing of the database can be read without it: notes, titles, tags and the
+/// search index alike. An encrypted database is opened by giving its passphrase with `apply_key`
+/// before reading anything.
+///
+/// Encrypting and decrypting write a new copy of the database with `sqlcipher_export` next to it,
+/// which replaces the original only once complete, so an interrupted run leaves the database as it was.
+///
+pub struct DatabaseEncryption;
+
+impl DatabaseEncryption {
+    /// First bytes of every plain SQLite file, an encrypted file starts with random bytes instead
+    const PLAIN_HEADER: &'static [u8; 16] = b"SQLite format 3\0";
+
+    /// Name of the database attached to write the new copy into
+    const EXPORT_SCHEMA: &'static str = "exported";
+
+    ///
+    /// Tells whether a database file is encrypted, by looking at its first bytes.
+    ///
+    /// # Returns
+    /// `false` if the file is a plain SQLite database, or does not exist or is empty yet.
+    ///
+    pub fn is_encrypted(path: &Path) -> bool {
+        File::open(path).is_ok_and(DatabaseEncryption::is_encrypted_content)
+    }
+
+    ///
+    /// Tells whether the content of a database file, read from its start, is encrypted;
+    /// used on compressed backups, read through their decoder.
+    ///
+    /// # Returns
+    /// `false` if the content is a plain SQLite database, or is too short to tell.
+    ///
+    pub fn is_encrypted_content(mut content: impl Read) -> bool {
+        let mut header = [0u8; 16];
+        match content.read_exact(&mut header) {
+            Ok(()) => &header != DatabaseEncryption::PLAIN_HEADER,
+            Err(_) => false,
+        }
+    }
+
+    ///
+    /// Gives the passphrase of an encrypted database to a connection just opened, and checks it.
+    ///
+    /// # Arguments
+    /// * `connection`: The connection, before anything is read through it.
+    /// * `passphrase`: The passphrase of the database. A new database is created encrypted with it.
+    ///
+    /// # Errors
+    /// Returns an error message if the passphrase does not open the database.
+    ///
+    pub fn apply_key(connection: &Connection, passphrase: &str) -> Result<(), String> {
+        // SQLCipher logs a wrong key to stderr once the key is given, the error returned says it already
+        connection
+            .pragma_update(None, "key", passphrase)
+            .and_then(|_| connection.pragma_update(None, "cipher_log_level", "NONE"))
+            .map_err(|err| format!("Error al abrir la base de datos: {}", err))?;
+
+        // SQLCipher only checks the key when the first page is read
+        connection
+            .query_row("SELECT COUNT(*) FROM sqlite_master", [], |row| row.get::<_, i64>(0))
+            .map_err(|_| String::from("Error al abrir la base de datos: la clave no es correcta o el fichero no es una base de datos"))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Encrypts a plain database in place.
+    ///
+    /// # Arguments
+    /// * `connection`: The connection to the plain database, opened on a file.
+    /// * `passphrase`: The passphrase the database is encrypted with.
+    ///
+    /// # Errors
+    /// Returns an error message if the database is already encrypted or the copy cannot be written;
+    /// the database is left as it was.
+    ///
+    /// # Example
+    /// ```
+    /// DatabaseEncryption::encrypt(note_repository.connection(), "correct horse battery")?;
+    /// ```
+    ///
+    pub fn encrypt(connection: &Connection, passphrase: &str) -> Result<(), String> {
+        let database = DatabaseEncryption::database_path(connection)?;
+        if DatabaseEncryption::is_encrypted(&database) {
+            return Err(String::from("Error al cifrar la base de datos: ya está cifrada"));
+        }
+
+        DatabaseEncryption::export(connection, &database, passphrase)
+            .map_err(|err| format!("Error al cifrar la base de datos: {}", err))
+    }
+
+    ///
+    /// Decrypts an encrypted database in place, leaving a plain SQLite file.
+    ///
+    /// # Arguments
+    /// * `connection`: The connection to the encrypted database, opened with its passphrase.
+    ///
+    /// # Errors
+    /// Returns an error message if the database is not encrypted or the copy cannot be written;
+    /// the database is left as it was.
+    ///
+    pub fn decrypt(connection: &Connection) -> Result<(), String> {
+        let database = DatabaseEncryption::database_path(connection)?;
+        if !DatabaseEncryption::is_encrypted(&database) {
+            return Err(String::from("Error al descifrar la base de datos: no está cifrada"));
+        }
+
+        // An empty key attaches a plain database
+        DatabaseEncryption::export(connection, &database, "")
+            .map_err(|err| format!("Error al descifrar la base de datos: {}", err))
+    }
+
+    ///
+    /// Changes the passphrase of an encrypted database, re-encrypting every page in place.
+    ///
+    /// # Arguments
+    /// * `connection`: The connection to the encrypted database, opened with its current passphrase.
+    /// * `passphrase`: The new passphrase.
+    ///
+    /// # Errors
+    /// Returns an error message if the database is not encrypted or cannot be re-encrypted.
+    ///
+    pub fn rekey(connection: &Connection, passphrase: &str) -> Result<(), String> {
+        let database = DatabaseEncryption::database_path(connection)?;
+        if !DatabaseEncryption::is_encrypted(&database) {
+            return Err(String::from(
+                "Error al cambiar la clave de la base de datos: no está cifrada",
+            ));
+        }
+
+        connection
+            .pragma_update(None, "rekey", passphrase)
+            .map_err(|err| format!("Error al cambiar la clave de la base de datos: {}", err))
+    }
+
+    /// Writes a copy of the database encrypted with `passphrase` (plain when empty),
+    /// then puts it in place of the database.
+    fn export(connection: &Connection, database: &Path, passphrase: &str) -> Result<(), String> {
+        let file_name = database.file_name().unwrap_or_default().to_string_lossy();
+        let partial = database.with_file_name(format!(".{}.partial", file_name));
+        let _ = fs::remove_file(&partial);
+
+        let exported = DatabaseEncryption::export_to(connection, &partial, passphrase)
+            .and_then(|_| fs::rename(&partial, database).map_err(|err| err.to_string()));
+        if exported.is_err() {
+            let _ = fs::remove_file(&partial);
+        }
+
+        exported
+    }
+
+    fn export_to(connection: &Connection, partial: &Path, passphrase: &str) -> Result<(), String> {
+        let schema = DatabaseEncryption::EXPORT_SCHEMA;
+
+        connection
+            .execute(
+                &format!("ATTACH DATABASE ?1 AS {} KEY ?2", schema),
+                params![partial.to_string_lossy(), passphrase],
+            )
+            .map_err(|err| err.to_string())?;
+
+        // `sqlcipher_export` copies the schema and the rows, but not the version of the schema
+        let exported = Migrator::current_version(connection).and_then(|version| {
+            connection
+                .query_row("SELECT sqlcipher_export(?1)", [schema], |_| Ok(()))
+                .and_then(|_| connection.pragma_update(Some(schema), "user_version", version))
+                .map_err(|err| err.to_string())
+        });
+
+        let detached = connection
+            .execute(&format!("DETACH DATABASE {}", schema), [])
+            .map(|_| ())
+            .map_err(|err| err.to_string());
+
+        exported.and(detached)
+    }
+
+    /// The file of the database, in-memory databases cannot be encrypted.
+    fn database_path(connection: &Connection) -> Result<PathBuf, String> {
+        connection
+            .path()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .ok_or_else(|| String::from("Error al cifrar la base de datos: la base de datos no está en un fichero"))
+    }
+}
@@ -0,0 +1,584 @@
+use crate::domain::{
+    entities::{
+        note::Note,
+        notebook::{Notebook, NotebookDeletion},
+        revision::Revision,
+        search_result::SearchResult,
+        tag::{Tag, TagMatch},
+    },
+    repositories::{
+        note_repository::NoteRepository, notebook_repository::NotebookRepository,
+        revision_repository::RevisionRepository, tag_repository::TagRepository,
+        trash_repository::TrashRepository,
+    },
+};
+use chrono::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+
+///
+/// The `InMemoryNoteRepository` struct implements the `NoteRepository` trait keeping the notes in memory.
+/// Nothing is persisted, which makes it useful to exercise the use cases in isolation
+/// without touching the SQLite database file.
+///
+pub struct InMemoryNoteRepository {
+    notes: RefCell<Vec<Note>>,
+    next_id: Cell<i64>,
+    notebooks: RefCell<Vec<Notebook>>,
+    next_notebook_id: Cell<i64>,
+    revisions: RefCell<Vec<Revision>>,
+    next_revision_id: Cell<i64>,
+}
+
+impl InMemoryNoteRepository {
+    ///
+    /// Creates a new, empty instance of `InMemoryNoteRepository`.
+    /// # Returns
+    /// A new `InMemoryNoteRepository` instance without notes and with only the Inbox notebook.
+    ///
+    /// # Example
+    /// ```
+    /// let note_repository = InMemoryNoteRepository::new();
+    /// let create_use_case = CreateNewNote::new(&note_repository);
+    /// ```
+    ///
+    pub fn new() -> Self {
+        InMemoryNoteRepository {
+            notes: RefCell::new(Vec::new()),
+            next_id: Cell::new(1),
+            notebooks: RefCell::new(vec![Notebook::from_primitives(
+                Notebook::INBOX_ID,
+                String::from("Inbox"),
+                None,
+                0,
+            )]),
+            next_notebook_id: Cell::new(Notebook::INBOX_ID + 1),
+            revisions: RefCell::new(Vec::new()),
+            next_revision_id: Cell::new(1),
+        }
+    }
+
+    ///
+    /// Records the title and content of a stored note as a new revision,
+    /// unless they are the same as in its latest revision.
+    /// A locked note keeps no revisions, the ones it had are forgotten.
+    ///
+    fn record_revision(&self, note: &Note) {
+        let id = match note.id {
+            Some(id) => id,
+            None => return,
+        };
+
+        let mut revisions = self.revisions.borrow_mut();
+        if note.locked {
+            revisions.retain(|revision| revision.note_id != id);
+            return;
+        }
+        let unchanged = revisions
+            .iter()
+            .rev()
+            .find(|revision| revision.note_id == id)
+            .is_some_and(|revision| revision.title == note.title && revision.content == note.content);
+        if unchanged {
+            return;
+        }
+
+        let revision_id = self.next_revision_id.get();
+        self.next_revision_id.set(revision_id + 1);
+        revisions.push(Revision::from_primitives(
+            revision_id,
+            id,
+            note.title.clone(),
+            note.content.clone(),
+            Utc::now(),
+        ));
+    }
+
+    ///
+    /// Wraps the first occurrence of `term` in `text` with the highlight markers.
+    ///
+    fn highlight(text: &str, term: &str) -> String {
+        match text.to_lowercase().find(term) {
+            Some(start) if text.is_char_boundary(start) && text.is_char_boundary(start + term.len()) => format!(
+                "{}{}{}{}{}",
+                &text[..start],
+                SearchResult::HIGHLIGHT_START,
+                &text[start..start + term.len()],
+                SearchResult::HIGHLIGHT_END,
+                &text[start + term.len()..]
+            ),
+            _ => text.to_string(),
+        }
+    }
+}
+
+impl NoteRepository for InMemoryNoteRepository {
+    fn save(&self, note: &Note) -> Result<i64, String> {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+
+        let mut stored = note.clone();
+        stored.set_id(id);
+        stored.created_at = Utc::now();
+        stored.updated_at = Utc::now();
+        self.record_revision(&stored);
+        self.notes.borrow_mut().push(stored);
+
+        Ok(id)
+    }
+
+    fn save_with_timestamps(&self, note: &Note) -> Result<i64, String> {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+
+        let mut stored = note.clone();
+        stored.set_id(id);
+        self.record_revision(&stored);
+        self.notes.borrow_mut().push(stored);
+
+        Ok(id)
+    }
+
+    fn find_all(&self) -> Option<Vec<Note>> {
+        let notes: Vec<Note> = self
+            .notes
+            .borrow()
+            .iter()
+            .filter(|note| !note.is_trashed())
+            .cloned()
+            .collect();
+
+        if notes.is_empty() { None } else { Some(notes) }
+    }
+
+    fn update(&self, note: Note) -> Result<Note, String> {
+        let mut notes = self.notes.borrow_mut();
+
+        match notes.iter_mut().find(|stored| stored.id == note.id && !stored.is_trashed()) {
+            Some(stored) => {
+                stored.title = note.title.clone();
+                stored.content = note.content.clone();
+                stored.tags = note.tags.clone();
+                stored.notebook_id = note.notebook_id;
+                stored.locked = note.locked;
+                stored.updated_at = Utc::now();
+                self.record_revision(stored);
+                Ok(note)
+            }
+            None => Err(format!("Note with id {:?} does not exist", note.id)),
+        }
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        for note in self.notes.borrow_mut().iter_mut() {
+            if note.id == Some(id) && !note.is_trashed() {
+                note.deleted_at = Some(Utc::now());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: i64) -> Option<Note> {
+        self.notes
+            .borrow()
+            .iter()
+            .find(|note| note.id == Some(id) && !note.is_trashed())
+            .cloned()
+    }
+
+    fn find_by_title(&self, title: &str) -> Option<Vec<Note>> {
+        let needle = title.to_lowercase();
+        let notes: Vec<Note> = self
+            .notes
+            .borrow()
+            .iter()
+            .filter(|note| !note.is_trashed() && note.title.to_lowercase().contains(&needle))
+            .cloned()
+            .collect();
+
+        if notes.is_empty() { None } else { Some(notes) }
+    }
+
+    ///
+    /// Performs a naive search: a note matches when its title or content contains every term
+    /// of the query, ignoring case, quotes, `*` and the boolean operators.
+    /// Notes with more occurrences rank first, locked notes are never found.
+    ///
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>, String> {
+        let terms: Vec<String> = query
+            .split_whitespace()
+            .filter(|term| !matches!(*term, "AND" | "OR" | "NOT"))
+            .map(|term| term.trim_matches(|c| c == '"' || c == '*').to_lowercase())
+            .filter(|term| !term.is_empty())
+            .collect();
+
+        let mut results: Vec<SearchResult> = self
+            .notes
+            .borrow()
+            .iter()
+            .filter(|note| !note.is_trashed() && !note.is_locked())
+            .filter_map(|note| {
+                let haystack = format!("{} {}", note.title, note.content).to_lowercase();
+                if !terms.iter().all(|term| haystack.contains(term.as_str())) {
+                    return None;
+                }
+
+                let hits = terms
+                    .iter()
+                    .map(|term| haystack.matches(term.as_str()).count())
+                    .sum::<usize>();
+                let snippet = match terms.first() {
+                    Some(term) => InMemoryNoteRepository::highlight(&note.content, term),
+                    None => note.content.clone(),
+                };
+
+                Some(SearchResult::new(note.clone(), snippet, -(hits as f64)))
+            })
+            .collect();
+
+        results.sort_by(|a, b| a.rank.total_cmp(&b.rank));
+        results.truncate(limit);
+
+        Ok(results)
+    }
+}
+
+impl TagRepository for InMemoryNoteRepository {
+    fn find_all_tags(&self) -> Result<Vec<Tag>, String> {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for note in self.notes.borrow().iter().filter(|note| !note.is_trashed()) {
+            for tag in &note.tags {
+                *counts.entry(tag.clone()).or_default() += 1;
+            }
+        }
+
+        Ok(counts
+            .into_iter()
+            .map(|(name, note_count)| Tag::from_primitives(None, name, note_count))
+            .collect())
+    }
+
+    fn rename_tag(&self, from: &str, to: &str) -> Result<usize, String> {
+        self.merge_tags(&[from.to_string()], to)
+    }
+
+    fn merge_tags(&self, sources: &[String], target: &str) -> Result<usize, String> {
+        let mut affected = 0;
+
+        for note in self.notes.borrow_mut().iter_mut() {
+            let before = note.tags.len();
+            note.tags.retain(|tag| tag == target || !sources.contains(tag));
+
+            if note.tags.len() != before {
+                note.tags.insert(target.to_string());
+                affected += 1;
+            }
+        }
+
+        Ok(affected)
+    }
+
+    fn find_notes_by_tags(&self, tags: &[String], mode: TagMatch) -> Result<Vec<Note>, String> {
+        if tags.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .notes
+            .borrow()
+            .iter()
+            .filter(|note| !note.is_trashed())
+            .filter(|note| match mode {
+                TagMatch::All => tags.iter().all(|tag| note.tags.contains(tag)),
+                TagMatch::Any => tags.iter().any(|tag| note.tags.contains(tag)),
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+impl NotebookRepository for InMemoryNoteRepository {
+    fn save_notebook(&self, notebook: &Notebook) -> Result<i64, String> {
+        let id = self.next_notebook_id.get();
+        self.next_notebook_id.set(id + 1);
+
+        let mut stored = notebook.clone();
+        stored.id = Some(id);
+        self.notebooks.borrow_mut().push(stored);
+
+        Ok(id)
+    }
+
+    fn find_all_notebooks(&self) -> Result<Vec<Notebook>, String> {
+        let notes = self.notes.borrow();
+        let mut notebooks: Vec<Notebook> = self
+            .notebooks
+            .borrow()
+            .iter()
+            .map(|notebook| {
+                let mut notebook = notebook.clone();
+                notebook.note_count = notes
+                    .iter()
+                    .filter(|note| !note.is_trashed() && Some(note.notebook_id) == notebook.id)
+                    .count();
+                notebook
+            })
+            .collect();
+
+        notebooks.sort_by_key(|notebook| (!notebook.is_inbox(), notebook.name.to_lowercase()));
+
+        Ok(notebooks)
+    }
+
+    fn find_notes_in_notebook(&self, notebook_id: i64) -> Result<Vec<Note>, String> {
+        Ok(self
+            .notes
+            .borrow()
+            .iter()
+            .filter(|note| !note.is_trashed() && note.notebook_id == notebook_id)
+            .cloned()
+            .collect())
+    }
+
+    fn move_note(&self, note_id: i64, notebook_id: i64) -> Result<(), String> {
+        for note in self.notes.borrow_mut().iter_mut() {
+            if note.id == Some(note_id) {
+                note.notebook_id = notebook_id;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn delete_notebook(&self, id: i64, strategy: NotebookDeletion) -> Result<usize, String> {
+        let parent_id = self
+            .notebooks
+            .borrow()
+            .iter()
+            .find(|notebook| notebook.id == Some(id))
+            .and_then(|notebook| notebook.parent_id);
+
+        let mut notebooks = self.notebooks.borrow_mut();
+        let mut notes = self.notes.borrow_mut();
+
+        match strategy {
+            NotebookDeletion::Cascade => {
+                let mut ids = Notebook::descendants_of(&notebooks, id);
+                ids.push(id);
+
+                let mut affected = 0;
+                for note in notes
+                    .iter_mut()
+                    .filter(|note| !note.is_trashed() && ids.contains(&note.notebook_id))
+                {
+                    note.deleted_at = Some(Utc::now());
+                    affected += 1;
+                }
+                notebooks.retain(|notebook| !notebook.id.is_some_and(|notebook_id| ids.contains(&notebook_id)));
+
+                Ok(affected)
+            }
+            NotebookDeletion::Reparent => {
+                let mut affected = 0;
+                for note in notes.iter_mut().filter(|note| note.notebook_id == id) {
+                    note.notebook_id = parent_id.unwrap_or(Notebook::INBOX_ID);
+                    affected += 1;
+                }
+                for notebook in notebooks.iter_mut().filter(|notebook| notebook.parent_id == Some(id)) {
+                    notebook.parent_id = parent_id;
+                }
+                notebooks.retain(|notebook| notebook.id != Some(id));
+
+                Ok(affected)
+            }
+        }
+    }
+}
+
+impl TrashRepository for InMemoryNoteRepository {
+    fn find_trashed(&self) -> Result<Vec<Note>, String> {
+        let mut notes: Vec<Note> = self
+            .notes
+            .borrow()
+            .iter()
+            .filter(|note| note.is_trashed())
+            .cloned()
+            .collect();
+
+        notes.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+
+        Ok(notes)
+    }
+
+    fn restore(&self, id: i64) -> Result<(), String> {
+        let notebooks = self.notebooks.borrow();
+
+        for note in self.notes.borrow_mut().iter_mut() {
+            if note.id == Some(id) && note.is_trashed() {
+                note.deleted_at = None;
+                if !notebooks.iter().any(|notebook| notebook.id == Some(note.notebook_id)) {
+                    note.notebook_id = Notebook::INBOX_ID;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn purge(&self, id: i64) -> Result<(), String> {
+        let mut notes = self.notes.borrow_mut();
+        notes.retain(|note| !(note.id == Some(id) && note.is_trashed()));
+        self.revisions
+            .borrow_mut()
+            .retain(|revision| notes.iter().any(|note| note.id == Some(revision.note_id)));
+
+        Ok(())
+    }
+
+    fn purge_deleted_before(&self, before: DateTime<Utc>) -> Result<usize, String> {
+        let mut notes = self.notes.borrow_mut();
+        let count = notes.len();
+        notes.retain(|note| !note.deleted_at.is_some_and(|deleted_at| deleted_at < before));
+        self.revisions
+            .borrow_mut()
+            .retain(|revision| notes.iter().any(|note| note.id == Some(revision.note_id)));
+
+        Ok(count - notes.len())
+    }
+}
+
+impl RevisionRepository for InMemoryNoteRepository {
+    fn find_revisions(&self, note_id: i64) -> Result<Vec<Revision>, String> {
+        Ok(self
+            .revisions
+            .borrow()
+            .iter()
+            .rev()
+            .filter(|revision| revision.note_id == note_id)
+            .cloned()
+            .collect())
+    }
+
+    fn find_revision(&self, id: i64) -> Result<Option<Revision>, String> {
+        Ok(self
+            .revisions
+            .borrow()
+            .iter()
+            .find(|revision| revision.id == id)
+            .cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::notes::{
+        create::CreateNewNote, delete::DeletedNote, find_all::FindAll, find_by_id::FindById,
+        find_by_title::FindByTitle, update::UpdateNote,
+    };
+    use crate::domain::{entities::note::NoteLimits, errors::note_error::NoteError};
+
+    fn create(note_repository: &InMemoryNoteRepository, title: &str, content: &str) -> Note {
+        CreateNewNote::new(note_repository, NoteLimits::default())
+            .execute(&title.to_string(), &content.to_string(), &[], Notebook::INBOX_ID)
+            .expect("the note is valid")
+    }
+
+    #[test]
+    fn creates_notes_with_consecutive_ids() {
+        let note_repository = InMemoryNoteRepository::new();
+
+        let first = create(&note_repository, "Meeting", "Review the plan");
+        let second = create(&note_repository, "Groceries", "Milk");
+
+        assert_eq!(first.get_id(), Some(1));
+        assert_eq!(second.get_id(), Some(2));
+        assert_eq!(FindAll::new(&note_repository).execute().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_notes_without_saving_them() {
+        let note_repository = InMemoryNoteRepository::new();
+        let create_note = CreateNewNote::new(&note_repository, NoteLimits::default());
+
+        let result = create_note.execute(&String::new(), &String::from("Content"), &[], Notebook::INBOX_ID);
+
+        assert!(matches!(result, Err(NoteError::EmptyTitle)));
+        assert!(matches!(FindAll::new(&note_repository).execute(), Err(NoteError::NoNotes)));
+    }
+
+    #[test]
+    fn rejects_titles_longer_than_the_limit() {
+        let note_repository = InMemoryNoteRepository::new();
+        let limits = NoteLimits {
+            max_title_length: 5,
+            ..NoteLimits::default()
+        };
+
+        let result = CreateNewNote::new(&note_repository, limits).execute(
+            &String::from("Too long"),
+            &String::from("Content"),
+            &[],
+            Notebook::INBOX_ID,
+        );
+
+        assert!(matches!(result, Err(NoteError::TitleTooLong { max: 5, actual: 8 })));
+    }
+
+    #[test]
+    fn finds_notes_by_id_and_title() {
+        let note_repository = InMemoryNoteRepository::new();
+        let note = create(&note_repository, "Meeting", "Review the plan");
+        create(&note_repository, "Groceries", "Milk");
+
+        let found = FindById::new(&note_repository).execute(note.get_id().unwrap()).unwrap();
+        assert_eq!(found.get_title(), "Meeting");
+
+        let found = FindByTitle::new(&note_repository).execute(&String::from("meet")).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].get_title(), "Meeting");
+
+        assert!(matches!(FindById::new(&note_repository).execute(99), Err(NoteError::NotFound(_))));
+        assert!(matches!(FindById::new(&note_repository).execute(0), Err(NoteError::InvalidId(0))));
+    }
+
+    #[test]
+    fn updates_the_title_and_content() {
+        let note_repository = InMemoryNoteRepository::new();
+        let id = create(&note_repository, "Meeting", "Review the plan").get_id().unwrap();
+
+        UpdateNote::new(&note_repository, NoteLimits::default())
+            .execute(id, &String::from("Meeting notes"), &String::from("Plan reviewed"))
+            .unwrap();
+
+        let found = FindById::new(&note_repository).execute(id).unwrap();
+        assert_eq!(found.get_title(), "Meeting notes");
+        assert_eq!(found.get_content(), "Plan reviewed");
+    }
+
+    #[test]
+    fn does_not_update_missing_notes() {
+        let note_repository = InMemoryNoteRepository::new();
+
+        let result = UpdateNote::new(&note_repository, NoteLimits::default()).execute(
+            7,
+            &String::from("Title"),
+            &String::from("Content"),
+        );
+
+        assert!(matches!(result, Err(NoteError::NotFound(_))));
+    }
+
+    #[test]
+    fn deleted_notes_are_no_longer_found() {
+        let note_repository = InMemoryNoteRepository::new();
+        let id = create(&note_repository, "Meeting", "Review the plan").get_id().unwrap();
+
+        DeletedNote::new(&note_repository).execute(id).unwrap();
+
+        assert!(matches!(FindById::new(&note_repository).execute(id), Err(NoteError::NotFound(_))));
+        assert!(matches!(DeletedNote::new(&note_repository).execute(id), Err(NoteError::NotFound(_))));
+        assert!(matches!(FindAll::new(&note_repository).execute(), Err(NoteError::NoNotes)));
+    }
+}
@@ -0,0 +1,272 @@
+use chrono::prelude::*;
+use rusqlite::Connection;
+use std::path::Path;
+
+///
+/// A single, ordered change to the SQLite schema.
+/// Migrations are identified by their `version`, which is stored in `PRAGMA user_version`
+/// once the migration has been applied. Versions must be consecutive and start at 1.
+///
+pub struct Migration {
+    /// Schema version reached once the migration is applied
+    pub version: u32,
+
+    /// Short human readable description of the change
+    pub description: &'static str,
+
+    /// SQL statements executed to apply the migration
+    pub sql: &'static str,
+}
+
+///
+/// Every migration known by this version of the application, in the order they must be applied.
+/// New migrations are appended at the end, existing ones must never be modified.
+///
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Create notes table",
+        sql: "CREATE TABLE IF NOT EXISTS notes (
+                id INTEGER PRIMARY KEY,
+                title TEXT NOT NULL,
+                content TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );",
+    },
+    Migration {
+        version: 2,
+        description: "Create full-text search index over notes",
+        sql: "CREATE VIRTUAL TABLE notes_fts USING fts5(
+                title,
+                content,
+                content = 'notes',
+                content_rowid = 'id'
+            );
+
+            INSERT INTO notes_fts(notes_fts) VALUES ('rebuild');
+
+            CREATE TRIGGER notes_fts_after_insert AFTER INSERT ON notes BEGIN
+                INSERT INTO notes_fts(rowid, title, content) VALUES (new.id, new.title, new.content);
+            END;
+
+            CREATE TRIGGER notes_fts_after_delete AFTER DELETE ON notes BEGIN
+                INSERT INTO notes_fts(notes_fts, rowid, title, content) VALUES ('delete', old.id, old.title, old.content);
+            END;
+
+            CREATE TRIGGER notes_fts_after_update AFTER UPDATE ON notes BEGIN
+                INSERT INTO notes_fts(notes_fts, rowid, title, content) VALUES ('delete', old.id, old.title, old.content);
+                INSERT INTO notes_fts(rowid, title, content) VALUES (new.id, new.title, new.content);
+            END;",
+    },
+    Migration {
+        version: 3,
+        description: "Create tags and note_tags tables",
+        sql: "CREATE TABLE tags (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL UNIQUE
+            );
+
+            CREATE TABLE note_tags (
+                note_id INTEGER NOT NULL REFERENCES notes(id) ON DELETE CASCADE,
+                tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+                PRIMARY KEY (note_id, tag_id)
+            );
+
+            CREATE INDEX note_tags_tag_id ON note_tags(tag_id);",
+    },
+    Migration {
+        version: 4,
+        description: "Create notebooks table and place every note in the Inbox",
+        sql: "CREATE TABLE notebooks (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                parent_id INTEGER REFERENCES notebooks(id),
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );
+
+            INSERT INTO notebooks (id, name, parent_id) VALUES (1, 'Inbox', NULL);
+
+            ALTER TABLE notes ADD COLUMN notebook_id INTEGER NOT NULL DEFAULT 1;
+
+            CREATE INDEX notes_notebook_id ON notes(notebook_id);",
+    },
+    Migration {
+        version: 5,
+        description: "Add deleted_at column to notes for the trash",
+        sql: "ALTER TABLE notes ADD COLUMN deleted_at TIMESTAMP DEFAULT NULL;
+
+            CREATE INDEX notes_deleted_at ON notes(deleted_at);",
+    },
+    Migration {
+        version: 6,
+        description: "Create note_revisions table with the current version of every note",
+        sql: "CREATE TABLE note_revisions (
+                id INTEGER PRIMARY KEY,
+                note_id INTEGER NOT NULL REFERENCES notes(id) ON DELETE CASCADE,
+                title TEXT NOT NULL,
+                content TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );
+
+            INSERT INTO note_revisions (note_id, title, content, created_at)
+                SELECT id, title, content, COALESCE(updated_at, CURRENT_TIMESTAMP) FROM notes;
+
+            CREATE INDEX note_revisions_note_id ON note_revisions(note_id);",
+    },
+    Migration {
+        version: 7,
+        description: "Add locked column to notes and leave the locked notes out of the full-text search index",
+        sql: "ALTER TABLE notes ADD COLUMN locked INTEGER NOT NULL DEFAULT 0;
+
+            DROP TRIGGER notes_fts_after_insert;
+            DROP TRIGGER notes_fts_after_delete;
+            DROP TRIGGER notes_fts_after_update;
+
+            CREATE TRIGGER notes_fts_after_insert AFTER INSERT ON notes WHEN new.locked = 0 BEGIN
+                INSERT INTO notes_fts(rowid, title, content) VALUES (new.id, new.title, new.content);
+            END;
+
+            CREATE TRIGGER notes_fts_after_delete AFTER DELETE ON notes WHEN old.locked = 0 BEGIN
+                INSERT INTO notes_fts(notes_fts, rowid, title, content) VALUES ('delete', old.id, old.title, old.content);
+            END;
+
+            CREATE TRIGGER notes_fts_after_update AFTER UPDATE ON notes BEGIN
+                INSERT INTO notes_fts(notes_fts, rowid, title, content)
+                    SELECT 'delete', old.id, old.title, old.content WHERE old.locked = 0;
+                INSERT INTO notes_fts(rowid, title, content)
+                    SELECT new.id, new.title, new.content WHERE new.locked = 0;
+            END;",
+    },
+];
+
+///
+/// The `Migrator` struct brings a SQLite database up to the latest schema version.
+///
+pub struct Migrator;
+
+impl Migrator {
+    ///
+    /// Returns the schema version the application expects.
+    ///
+    pub fn latest_version() -> u32 {
+        MIGRATIONS.last().map(|migration| migration.version).unwrap_or(0)
+    }
+
+    ///
+    /// Reads the schema version stored in the database.
+    /// # Arguments
+    /// * `connection`: The connection to the database.
+    /// # Returns
+    /// * `Ok(u32)`: The value of `PRAGMA user_version`, `0` for a database that was never migrated.
+    /// * `Err(String)`: An error message if the pragma cannot be read.
+    ///
+    pub fn current_version(connection: &Connection) -> Result<u32, String> {
+        connection
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .map_err(|err| format!("Error al leer la versión del esquema: {}", err))
+    }
+
+    ///
+    /// Applies every pending migration.
+    ///
+    /// Each migration runs in its own transaction together with the update of `PRAGMA user_version`,
+    /// so a failing migration leaves the database at the previous version.
+    /// Before upgrading a database that already contains data, a backup copy is written next to it.
+    ///
+    /// # Arguments
+    /// * `connection`: The connection to the database to migrate.
+    /// * `path`: The path of the database file, used to place the backup. `None` for in-memory databases.
+    /// # Returns
+    /// * `Ok(u32)`: The schema version after applying the migrations.
+    /// * `Err(String)`: An error message if the database is newer than the application,
+    ///   the backup cannot be written or a migration fails.
+    ///
+    /// # Example
+    /// ```
+    /// let mut connection = Connection::open("notes-rust.db")?;
+    /// Migrator::run(&mut connection, Some(Path::new("notes-rust.db")))?;
+    /// ```
+    ///
+    pub fn run(connection: &mut Connection, path: Option<&Path>) -> Result<u32, String> {
+        let current = Migrator::current_version(connection)?;
+        let latest = Migrator::latest_version();
+
+        if current > latest {
+            return Err(format!(
+                "La base de datos usa la versión {} del esquema, pero esta versión de la aplicación solo soporta hasta la {}. Actualiza notes-rust para abrirla",
+                current, latest
+            ));
+        }
+
+        if current == latest {
+            return Ok(current);
+        }
+
+        if let Some(path) = path {
+            if Migrator::has_tables(connection)? {
+                Migrator::backup(connection, path, current)?;
+            }
+        }
+
+        for migration in MIGRATIONS.iter().filter(|migration| migration.version > current) {
+            let transaction = connection
+                .transaction()
+                .map_err(|err| format!("Error al iniciar la migración {}: {}", migration.version, err))?;
+
+            transaction.execute_batch(migration.sql).map_err(|err| {
+                format!(
+                    "Error al aplicar la migración {} ({}): {}",
+                    migration.version, migration.description, err
+                )
+            })?;
+
+            transaction
+                .pragma_update(None, "user_version", migration.version)
+                .map_err(|err| format!("Error al actualizar la versión del esquema: {}", err))?;
+
+            transaction
+                .commit()
+                .map_err(|err| format!("Error al confirmar la migración {}: {}", migration.version, err))?;
+        }
+
+        Ok(latest)
+    }
+
+    ///
+    /// Tells whether the database already contains tables, i.e. it is not a brand new file.
+    ///
+    fn has_tables(connection: &Connection) -> Result<bool, String> {
+        connection
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|count| count > 0)
+            .map_err(|err| format!("Error al inspeccionar la base de datos: {}", err))
+    }
+
+    ///
+    /// Writes a consistent copy of the database next to the original file using `VACUUM INTO`.
+    /// The copy is named `<file>.v<version>-<timestamp>.bak`.
+    ///
+    fn backup(connection: &Connection, path: &Path, version: u32) -> Result<(), String> {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| String::from("notes-rust.db"));
+        let backup_path = path.with_file_name(format!(
+            "{}.v{}-{}.bak",
+            file_name,
+            version,
+            Utc::now().format("%Y%m%d%H%M%S")
+        ));
+
+        connection
+            .execute("VACUUM INTO ?1", [backup_path.to_string_lossy().to_string()])
+            .map_err(|err| format!("Error al crear la copia de seguridad: {}", err))?;
+
+        Ok(())
+    }
+}
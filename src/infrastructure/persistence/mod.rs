@@ -0,0 +1,5 @@
+pub mod backup;
+pub mod database_encryption;
+pub mod in_memory_note_repository;
+pub mod migrations;
+pub mod sqlite_note_repository;
\ No newline at end of file
@@ -0,0 +1,1043 @@
+use crate::domain::{
+    entities::{
+        note::Note,
+        notebook::{Notebook, NotebookDeletion},
+        revision::Revision,
+        search_result::SearchResult,
+        tag::{Tag, TagMatch},
+    },
+    repositories::{
+        note_repository::NoteRepository, notebook_repository::NotebookRepository,
+        revision_repository::RevisionRepository, tag_repository::TagRepository,
+        trash_repository::TrashRepository,
+    },
+};
+use chrono::prelude::*;
+use crate::infrastructure::persistence::{database_encryption::DatabaseEncryption, migrations::Migrator};
+use rusqlite::{Connection, Result, Row, params, params_from_iter};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+///
+/// The `SqliteNoteRepository` struct implements the `NoteRepository` trait on top of a SQLite database.
+/// It allows for saving, updating, deleting, and finding notes by their ID.
+/// 
+pub struct SqliteNoteRepository {
+    connection: Connection,
+
+    /// Passphrase the database was opened with, when it is encrypted
+    key: Option<String>,
+}
+
+impl SqliteNoteRepository {
+    /// Path of the database file used by older versions of the application, relative to the working directory
+    pub const DEFAULT_DATABASE_PATH: &'static str = "notes-rust.db";
+
+    ///
+    /// Opens the SQLite database at the given path, encrypted with SQLCipher when a key is given,
+    /// and applies any pending schema migration.
+    /// A backup copy of the file is made before upgrading an existing database.
+    /// # Arguments
+    /// * `path`: The path of the database file, created (encrypted with the key, if any) if it does not exist.
+    /// * `key`: The passphrase of the database, `None` for a plain database.
+    /// # Returns
+    /// * `Ok(SqliteNoteRepository)`: The repository, ready to be used.
+    /// * `Err(String)`: An error message if the database cannot be opened, the key does not open it,
+    ///   it comes from a newer version of the application or a migration fails.
+    ///
+    /// # Example
+    /// ```
+    /// let note_repository = SqliteNoteRepository::open_with_key("notes-rust.db", Some("correct horse battery"))?;
+    /// ```
+    ///
+    pub fn open_with_key(path: &str, key: Option<&str>) -> Result<Self, String> {
+        let mut connection = Connection::open(path)
+            .map_err(|err| format!("Error al abrir la base de datos: {}", err))?;
+
+        // The key is given before anything is read, SQLCipher decrypts every page with it
+        if let Some(key) = key {
+            DatabaseEncryption::apply_key(&connection, key)?;
+        }
+
+        connection
+            .pragma_update(None, "foreign_keys", true)
+            .map_err(|err| format!("Error al abrir la base de datos: {}", err))?;
+
+        // Deleted data is overwritten, so the plain content of a note is not left behind once it is locked
+        connection
+            .pragma_update(None, "secure_delete", true)
+            .map_err(|err| format!("Error al abrir la base de datos: {}", err))?;
+
+        Migrator::run(&mut connection, Some(Path::new(path)))?;
+
+        Ok(SqliteNoteRepository {
+            connection,
+            key: key.map(String::from),
+        })
+    }
+
+    ///
+    /// Returns a reference to the SQLite connection used by the repository.
+    /// This function allows access to the underlying database connection for executing raw SQL queries if needed.
+    /// # Returns
+    /// A reference to the `Connection` object.
+    ///
+    /// # Example
+    /// ```
+    /// let connection = note_repository.connection();
+    /// ```
+    ///     
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    ///
+    /// Returns the passphrase the database was opened with, so other files can be opened
+    /// with the same key, e.g. its backups.
+    /// # Returns
+    /// `Some(&str)` with the passphrase, or `None` if the database is not encrypted.
+    ///
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    ///
+    /// Turns an error of a full-text search into a message, telling apart the queries FTS5
+    /// cannot parse (syntax errors, or words taken as column filters) from the failures of the database.
+    ///
+    fn search_error(err: rusqlite::Error) -> String {
+        let message = err.to_string();
+        if message.contains("fts5: syntax error") || message.contains("no such column") {
+            return format!("{}: {}", SearchResult::INVALID_QUERY_ERROR, message);
+        }
+
+        format!("Error al buscar las notas: {}", message)
+    }
+
+    ///
+    /// Maps a row selected as `id, title, content, created_at, updated_at, tags, notebook_id, locked` into a `Note`.
+    /// The tags column holds the tag names joined with the `\u{1f}` (unit separator) character.
+    ///
+    fn row_to_note(row: &Row) -> Result<Note> {
+        let id: i64 = row.get(0)?;
+        let title: String = row.get(1)?;
+        let content: String = row.get(2)?;
+        let created_at: String = row.get(3)?;
+        let updated_at: String = row.get(4)?;
+        let tags: Option<String> = row.get(5)?;
+        let notebook_id: i64 = row.get(6)?;
+        let locked: bool = row.get(7)?;
+
+        let tags: BTreeSet<String> = tags
+            .map(|tags| tags.split('\u{1f}').map(String::from).collect())
+            .unwrap_or_default();
+
+        Ok(Note::from_primitives(
+            id,
+            title,
+            content,
+            SqliteNoteRepository::parse_timestamp(&created_at),
+            SqliteNoteRepository::parse_timestamp(&updated_at),
+        )
+        .with_tags(tags)
+        .with_notebook(notebook_id)
+        .with_locked(locked))
+    }
+
+    ///
+    /// Maps a row selected as `id, note_id, title, content, created_at` into a `Revision`.
+    ///
+    fn row_to_revision(row: &Row) -> Result<Revision> {
+        let created_at: String = row.get(4)?;
+
+        Ok(Revision::from_primitives(
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            row.get(3)?,
+            SqliteNoteRepository::parse_timestamp(&created_at),
+        ))
+    }
+
+    ///
+    /// Replaces the tags attached to a note and removes the tags no longer used by any note.
+    /// Must be called inside the transaction that saves or updates the note.
+    ///
+    fn save_tags(connection: &Connection, note_id: i64, tags: &BTreeSet<String>) -> Result<(), String> {
+        connection
+            .execute("DELETE FROM note_tags WHERE note_id = ?1", params![note_id])
+            .map_err(|err| format!("Error al guardar las etiquetas: {}", err))?;
+
+        for tag in tags {
+            connection
+                .execute("INSERT OR IGNORE INTO tags (name) VALUES (?1)", params![tag])
+                .map_err(|err| format!("Error al guardar las etiquetas: {}", err))?;
+            connection
+                .execute(
+                    "INSERT INTO note_tags (note_id, tag_id) SELECT ?1, id FROM tags WHERE name = ?2",
+                    params![note_id, tag],
+                )
+                .map_err(|err| format!("Error al guardar las etiquetas: {}", err))?;
+        }
+
+        connection
+            .execute(
+                "DELETE FROM tags WHERE id NOT IN (SELECT tag_id FROM note_tags)",
+                [],
+            )
+            .map_err(|err| format!("Error al guardar las etiquetas: {}", err))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Records the current title and content of a note as a new revision, unless they are the same
+    /// as in its latest revision (e.g. when only the tags changed).
+    /// Must be called inside the transaction that saves or updates the note.
+    ///
+    fn save_revision(connection: &Connection, note_id: i64) -> Result<(), String> {
+        connection
+            .execute(
+                "INSERT INTO note_revisions (note_id, title, content)
+                    SELECT notes.id, notes.title, notes.content
+                    FROM notes
+                    WHERE notes.id = ?1 AND NOT EXISTS (
+                        SELECT 1
+                        FROM note_revisions
+                        WHERE note_revisions.id = (SELECT MAX(id) FROM note_revisions WHERE note_id = ?1)
+                            AND note_revisions.title = notes.title
+                            AND note_revisions.content = notes.content
+                    )",
+                params![note_id],
+            )
+            .map_err(|err| format!("Error al guardar la revisión: {}", err))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Deletes every revision of a note. Locked notes keep no history, as the revisions
+    /// would hold the content the lock protects.
+    /// Must be called inside the transaction that saves or updates the note.
+    ///
+    fn forget_revisions(connection: &Connection, note_id: i64) -> Result<(), String> {
+        connection
+            .execute("DELETE FROM note_revisions WHERE note_id = ?1", params![note_id])
+            .map_err(|err| format!("Error al borrar las revisiones: {}", err))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Inserts a note with its tags and first revision, locked notes get no revision.
+    /// The timestamps of the note are stored when `keep_timestamps` is set, the current time otherwise.
+    ///
+    fn insert(&self, note: &Note, keep_timestamps: bool) -> Result<i64, String> {
+        let (created_at, updated_at) = if keep_timestamps {
+            (
+                Some(SqliteNoteRepository::format_timestamp(note.get_created_at())),
+                Some(SqliteNoteRepository::format_timestamp(note.get_updated_at())),
+            )
+        } else {
+            (None, None)
+        };
+
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(|err| format!("Error al guardar la nota: {}", err))?;
+
+        transaction
+            .execute(
+                "INSERT INTO notes (title, content, notebook_id, created_at, updated_at, locked)
+                    VALUES (?1, ?2, ?3, COALESCE(?4, CURRENT_TIMESTAMP), COALESCE(?5, CURRENT_TIMESTAMP), ?6)",
+                params![
+                    note.get_title(),
+                    note.get_content(),
+                    note.get_notebook_id(),
+                    created_at,
+                    updated_at,
+                    note.is_locked()
+                ],
+            )
+            .map_err(|err| format!("Error al guardar la nota: {}", err))?;
+
+        let id = transaction.last_insert_rowid();
+
+        SqliteNoteRepository::save_tags(&transaction, id, note.get_tags())?;
+        if !note.is_locked() {
+            SqliteNoteRepository::save_revision(&transaction, id)?;
+        }
+
+        if let Some(updated_at) = updated_at {
+            transaction
+                .execute(
+                    "UPDATE note_revisions SET created_at = ?2 WHERE note_id = ?1",
+                    params![id, updated_at],
+                )
+                .map_err(|err| format!("Error al guardar la revisión: {}", err))?;
+        }
+
+        transaction
+            .commit()
+            .map_err(|err| format!("Error al guardar la nota: {}", err))?;
+
+        Ok(id)
+    }
+
+    ///
+    /// Formats a timestamp the way `CURRENT_TIMESTAMP` stores it, `YYYY-MM-DD HH:MM:SS` in UTC,
+    /// so the stored values keep sorting the same.
+    ///
+    fn format_timestamp(value: DateTime<Utc>) -> String {
+        value.format("%Y-%m-%d %H:%M:%S").to_string()
+    }
+
+    ///
+    /// Parses a timestamp stored by SQLite.
+    /// `CURRENT_TIMESTAMP` stores values as `YYYY-MM-DD HH:MM:SS` in UTC, while RFC 3339 values
+    /// are also accepted. Falls back to the current time if the value cannot be parsed.
+    ///
+    fn parse_timestamp(value: &str) -> DateTime<Utc> {
+        if let Ok(date) = value.parse::<DateTime<Utc>>() {
+            return date;
+        }
+
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+            .map(|date| date.and_utc())
+            .unwrap_or_else(|_| Utc::now())
+    }
+}
+
+impl NoteRepository for SqliteNoteRepository {
+
+    ///
+    /// Saves a new note to the SQLite database.
+    /// # Arguments
+    /// * `note`: A reference to the `Note` object to be saved.
+    /// # Returns
+    /// * `Ok(i64)`: The ID of the newly created note if the operation is successful.
+    /// * `Err(String)`: An error message if there is an issue saving the note, such as a database error.
+    /// 
+    fn save(&self, note: &Note) -> Result<i64, String> {
+        self.insert(note, false)
+    }
+
+    ///
+    /// Saves a new note to the SQLite database with its own timestamps.
+    /// The first revision of the note is dated at its last update.
+    ///
+    fn save_with_timestamps(&self, note: &Note) -> Result<i64, String> {
+        self.insert(note, true)
+    }
+
+    fn find_all(&self) -> Option<Vec<Note>> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT 
+                        id,
+                        title, 
+                        content,
+                        created_at,
+                        updated_at,
+                        (SELECT group_concat(tags.name, char(31))
+                            FROM note_tags
+                            JOIN tags ON tags.id = note_tags.tag_id
+                            WHERE note_tags.note_id = notes.id) AS tags,
+                        notes.notebook_id,
+                        notes.locked
+                    FROM notes
+                    WHERE deleted_at IS NULL;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))
+            .ok()?;
+
+        let notes = stmt
+            .query_map([], SqliteNoteRepository::row_to_note)
+            .map_err(|err| format!("Error al buscar las notas: {}", err))
+            .ok()?;
+
+        let notes_vec: Vec<Note> = notes.filter_map(Result::ok).collect();
+
+        if notes_vec.is_empty() {
+            None
+        } else {
+            Some(notes_vec)
+        }
+    }
+
+    /// 
+    /// Updates an existing note in the SQLite database.
+    /// # Arguments
+    /// * `note`: A `Note` object containing the updated information.
+    /// # Returns
+    /// * `Ok(Note)`: The updated `Note` object if the operation is successful.
+    /// * `Err(String)`: An error message if there is an issue updating the note, such as a database error.
+    ///
+    fn update(&self, note: Note) -> Result<Note, String> {
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(|err| format!("Error al actualizar la nota: {}", err))?;
+
+        // Whether the note is being locked now, so its plain content has to be wiped from the index
+        let locking = note.is_locked()
+            && transaction
+                .query_row(
+                    "SELECT locked FROM notes WHERE id = ?1",
+                    params![note.get_id()],
+                    |row| row.get::<_, bool>(0),
+                )
+                .map(|locked| !locked)
+                .unwrap_or(false);
+
+        let updated = transaction
+            .execute(
+                "UPDATE notes SET title = ?1, content = ?2, notebook_id = ?3, locked = ?5, updated_at = CURRENT_TIMESTAMP WHERE id = ?4 AND deleted_at IS NULL",
+                params![
+                    note.get_title(), 
+                    note.get_content(), 
+                    note.get_notebook_id(),
+                    note.get_id(),
+                    note.is_locked()
+                ],
+            )
+            .map_err(|err| format!("Error al actualizar la nota: {}", err))?;
+
+        // A missing or trashed note must not get tags or a revision either, the transaction is rolled back
+        if updated == 0 {
+            return Err(format!(
+                "Error al actualizar la nota: no existe ninguna nota con id {} fuera de la papelera",
+                note.get_id().unwrap_or_default()
+            ));
+        }
+
+        if let Some(id) = note.get_id() {
+            SqliteNoteRepository::save_tags(&transaction, id, note.get_tags())?;
+            if note.is_locked() {
+                SqliteNoteRepository::forget_revisions(&transaction, id)?;
+            } else {
+                SqliteNoteRepository::save_revision(&transaction, id)?;
+            }
+        }
+
+        if locking {
+            // Deleting from the index only marks the terms as gone, merging the index drops them
+            transaction
+                .execute("INSERT INTO notes_fts(notes_fts) VALUES ('optimize')", [])
+                .map_err(|err| format!("Error al actualizar el índice de búsqueda: {}", err))?;
+        }
+
+        transaction
+            .commit()
+            .map_err(|err| format!("Error al actualizar la nota: {}", err))?;
+
+        Ok(note.clone())
+    }
+
+    /// 
+    /// Moves a note to the trash by setting its `deleted_at` column; the row is kept until it is purged.
+    /// # Arguments
+    /// * `id`: The ID of the note to be deleted.
+    /// # Returns
+    /// * `Ok(())`: If the note is successfully moved to the trash.
+    /// * `Err(String)`: An error message if there is an issue deleting the note, such as a database error.
+    /// 
+    fn delete(&self, id: i64) -> Result<(), String> {
+        self.connection
+            .execute(
+                "UPDATE notes SET deleted_at = CURRENT_TIMESTAMP WHERE id = ?1 AND deleted_at IS NULL",
+                params![id],
+            )
+            .map_err(|err| format!("Error al eliminar la nota: {}", err))?;
+
+        Ok(())
+    }
+
+    /// 
+    /// Finds a note by its ID in the SQLite database.
+    /// # Arguments
+    /// * `id`: The ID of the note to be found.
+    /// # Returns
+    /// * `Option<Note>`: An `Option` containing the `Note` if found, or `None` if no note with the given ID exists.
+    /// 
+    fn find_by_id(&self, id: i64) -> Option<Note> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT 
+                        id,
+                        title, 
+                        content,
+                        created_at,
+                        updated_at,
+                        (SELECT group_concat(tags.name, char(31))
+                            FROM note_tags
+                            JOIN tags ON tags.id = note_tags.tag_id
+                            WHERE note_tags.note_id = notes.id) AS tags,
+                        notes.notebook_id,
+                        notes.locked
+                    FROM notes 
+                    WHERE id = ?1 AND deleted_at IS NULL
+                    LIMIT 1;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))
+            .ok()?;
+
+        let result = stmt
+            .query_row(params![id], SqliteNoteRepository::row_to_note)
+            .map_err(|err| format!("Error al buscar la nota: {}", err))
+            .ok();
+
+        match result {
+            Some(note) => {
+                Some(note)
+            }
+            _ => {
+                None
+            }
+        }
+    }
+
+    ///
+    /// Finds notes by their title in the SQLite database.
+    /// # Arguments
+    /// * `title`: The title or partial title of the notes to be found.
+    /// # Returns
+    /// * `Option<Vec<Note>>`: An `Option` containing a vector of `Note` objects if found, or `None` if no notes match the given title.
+    ///
+    /// This function performs a case-insensitive search for the title in the notes table.
+    /// It uses a SQL query with a `LIKE` clause to match the title, allowing for partial matches.
+    /// If notes with the specified title are found, it returns them wrapped in `Some(Vec<Note>)`.
+    /// If no notes are found, it returns `None`.
+    ///
+    fn find_by_title(&self, title: &str) -> Option<Vec<Note>> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT 
+                        id,
+                        title, 
+                        content,
+                        created_at,
+                        updated_at,
+                        (SELECT group_concat(tags.name, char(31))
+                            FROM note_tags
+                            JOIN tags ON tags.id = note_tags.tag_id
+                            WHERE note_tags.note_id = notes.id) AS tags,
+                        notes.notebook_id,
+                        notes.locked
+                    FROM notes 
+                    WHERE title LIKE '%' || ?1 || '%' AND deleted_at IS NULL;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))
+            .ok()?;
+
+        let notes = stmt
+            .query_map(params![title], SqliteNoteRepository::row_to_note)
+            .map_err(|err| format!("Error al buscar las notas por título: {}", err))
+            .ok()?;
+
+        let notes_vec: Vec<Note> = notes.filter_map(Result::ok).collect();
+
+        if notes_vec.is_empty() {
+            None
+        } else {
+            Some(notes_vec)
+        }
+    }
+
+    ///
+    /// Performs a full-text search using the `notes_fts` FTS5 index.
+    /// The results are ranked with bm25, giving the title ten times more weight than the content,
+    /// and each result carries a snippet of the best matching column with the matched terms highlighted.
+    ///
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>, String> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT
+                        notes.id,
+                        notes.title,
+                        notes.content,
+                        notes.created_at,
+                        notes.updated_at,
+                        (SELECT group_concat(tags.name, char(31))
+                            FROM note_tags
+                            JOIN tags ON tags.id = note_tags.tag_id
+                            WHERE note_tags.note_id = notes.id) AS tags,
+                        notes.notebook_id,
+                        notes.locked,
+                        snippet(notes_fts, -1, ?2, ?3, '…', 16),
+                        bm25(notes_fts, 10.0, 1.0) AS rank
+                    FROM notes_fts
+                    JOIN notes ON notes.id = notes_fts.rowid
+                    WHERE notes_fts MATCH ?1 AND notes.deleted_at IS NULL
+                    ORDER BY rank
+                    LIMIT ?4;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))?;
+
+        let results = stmt
+            .query_map(
+                params![
+                    query,
+                    SearchResult::HIGHLIGHT_START.to_string(),
+                    SearchResult::HIGHLIGHT_END.to_string(),
+                    limit as i64
+                ],
+                |row| {
+                    let note = SqliteNoteRepository::row_to_note(row)?;
+                    let snippet: String = row.get(8)?;
+                    let rank: f64 = row.get(9)?;
+
+                    Ok(SearchResult::new(note, snippet, rank))
+                },
+            )
+            .map_err(SqliteNoteRepository::search_error)?;
+
+        results
+            .collect::<Result<Vec<SearchResult>>>()
+            .map_err(SqliteNoteRepository::search_error)
+    }
+}
+
+impl TagRepository for SqliteNoteRepository {
+    fn find_all_tags(&self) -> Result<Vec<Tag>, String> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT
+                        tags.id,
+                        tags.name,
+                        COUNT(note_tags.note_id)
+                    FROM tags
+                    JOIN note_tags ON note_tags.tag_id = tags.id
+                    JOIN notes ON notes.id = note_tags.note_id
+                    WHERE notes.deleted_at IS NULL
+                    GROUP BY tags.id
+                    ORDER BY tags.name;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))?;
+
+        let tags = stmt
+            .query_map([], |row| {
+                let id: i64 = row.get(0)?;
+                let name: String = row.get(1)?;
+                let note_count: i64 = row.get(2)?;
+
+                Ok(Tag::from_primitives(Some(id), name, note_count as usize))
+            })
+            .map_err(|err| format!("Error al buscar las etiquetas: {}", err))?;
+
+        tags.collect::<Result<Vec<Tag>>>()
+            .map_err(|err| format!("Error al buscar las etiquetas: {}", err))
+    }
+
+    fn rename_tag(&self, from: &str, to: &str) -> Result<usize, String> {
+        self.merge_tags(&[from.to_string()], to)
+    }
+
+    fn merge_tags(&self, sources: &[String], target: &str) -> Result<usize, String> {
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(|err| format!("Error al fusionar las etiquetas: {}", err))?;
+
+        let sources: Vec<&String> = sources.iter().filter(|source| source.as_str() != target).collect();
+        if sources.is_empty() {
+            return Ok(0);
+        }
+
+        let placeholders = vec!["?"; sources.len()].join(", ");
+        let affected: i64 = transaction
+            .query_row(
+                &format!(
+                    "SELECT COUNT(DISTINCT note_tags.note_id)
+                        FROM note_tags
+                        JOIN tags ON tags.id = note_tags.tag_id
+                        WHERE tags.name IN ({});",
+                    placeholders
+                ),
+                params_from_iter(sources.iter()),
+                |row| row.get(0),
+            )
+            .map_err(|err| format!("Error al fusionar las etiquetas: {}", err))?;
+
+        transaction
+            .execute("INSERT OR IGNORE INTO tags (name) VALUES (?1)", params![target])
+            .map_err(|err| format!("Error al fusionar las etiquetas: {}", err))?;
+
+        for source in &sources {
+            transaction
+                .execute(
+                    "INSERT OR IGNORE INTO note_tags (note_id, tag_id)
+                        SELECT note_tags.note_id, (SELECT id FROM tags WHERE name = ?2)
+                        FROM note_tags
+                        JOIN tags ON tags.id = note_tags.tag_id
+                        WHERE tags.name = ?1",
+                    params![source, target],
+                )
+                .map_err(|err| format!("Error al fusionar las etiquetas: {}", err))?;
+            transaction
+                .execute("DELETE FROM tags WHERE name = ?1", params![source])
+                .map_err(|err| format!("Error al fusionar las etiquetas: {}", err))?;
+        }
+
+        transaction
+            .execute(
+                "DELETE FROM tags WHERE id NOT IN (SELECT tag_id FROM note_tags)",
+                [],
+            )
+            .map_err(|err| format!("Error al fusionar las etiquetas: {}", err))?;
+
+        transaction
+            .commit()
+            .map_err(|err| format!("Error al fusionar las etiquetas: {}", err))?;
+
+        Ok(affected as usize)
+    }
+
+    fn find_notes_by_tags(&self, tags: &[String], mode: TagMatch) -> Result<Vec<Note>, String> {
+        if tags.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let required = match mode {
+            TagMatch::All => tags.len(),
+            TagMatch::Any => 1,
+        };
+        let placeholders = vec!["?"; tags.len()].join(", ");
+
+        let mut stmt = self
+            .connection
+            .prepare(&format!(
+                "SELECT
+                        id,
+                        title,
+                        content,
+                        created_at,
+                        updated_at,
+                        (SELECT group_concat(tags.name, char(31))
+                            FROM note_tags
+                            JOIN tags ON tags.id = note_tags.tag_id
+                            WHERE note_tags.note_id = notes.id) AS tags,
+                        notes.notebook_id,
+                        notes.locked
+                    FROM notes
+                    WHERE id IN (
+                        SELECT note_tags.note_id
+                        FROM note_tags
+                        JOIN tags ON tags.id = note_tags.tag_id
+                        WHERE tags.name IN ({})
+                        GROUP BY note_tags.note_id
+                        HAVING COUNT(DISTINCT tags.name) >= {}
+                    )
+                    AND deleted_at IS NULL
+                    ORDER BY updated_at DESC;",
+                placeholders, required
+            ))
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))?;
+
+        let notes = stmt
+            .query_map(params_from_iter(tags.iter()), SqliteNoteRepository::row_to_note)
+            .map_err(|err| format!("Error al buscar las notas por etiqueta: {}", err))?;
+
+        notes
+            .collect::<Result<Vec<Note>>>()
+            .map_err(|err| format!("Error al buscar las notas por etiqueta: {}", err))
+    }
+}
+
+impl NotebookRepository for SqliteNoteRepository {
+    fn save_notebook(&self, notebook: &Notebook) -> Result<i64, String> {
+        self.connection
+            .execute(
+                "INSERT INTO notebooks (name, parent_id) VALUES (?1, ?2)",
+                params![notebook.get_name(), notebook.get_parent_id()],
+            )
+            .map_err(|err| format!("Error al guardar la libreta: {}", err))?;
+
+        Ok(self.connection.last_insert_rowid())
+    }
+
+    fn find_all_notebooks(&self) -> Result<Vec<Notebook>, String> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT
+                        notebooks.id,
+                        notebooks.name,
+                        notebooks.parent_id,
+                        (SELECT COUNT(*) FROM notes WHERE notes.notebook_id = notebooks.id AND notes.deleted_at IS NULL)
+                    FROM notebooks
+                    ORDER BY notebooks.id != 1, notebooks.name COLLATE NOCASE;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))?;
+
+        let notebooks = stmt
+            .query_map([], |row| {
+                let id: i64 = row.get(0)?;
+                let name: String = row.get(1)?;
+                let parent_id: Option<i64> = row.get(2)?;
+                let note_count: i64 = row.get(3)?;
+
+                Ok(Notebook::from_primitives(id, name, parent_id, note_count as usize))
+            })
+            .map_err(|err| format!("Error al buscar las libretas: {}", err))?;
+
+        notebooks
+            .collect::<Result<Vec<Notebook>>>()
+            .map_err(|err| format!("Error al buscar las libretas: {}", err))
+    }
+
+    fn find_notes_in_notebook(&self, notebook_id: i64) -> Result<Vec<Note>, String> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT
+                        id,
+                        title,
+                        content,
+                        created_at,
+                        updated_at,
+                        (SELECT group_concat(tags.name, char(31))
+                            FROM note_tags
+                            JOIN tags ON tags.id = note_tags.tag_id
+                            WHERE note_tags.note_id = notes.id) AS tags,
+                        notes.notebook_id,
+                        notes.locked
+                    FROM notes
+                    WHERE notebook_id = ?1 AND deleted_at IS NULL
+                    ORDER BY updated_at DESC;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))?;
+
+        let notes = stmt
+            .query_map(params![notebook_id], SqliteNoteRepository::row_to_note)
+            .map_err(|err| format!("Error al buscar las notas de la libreta: {}", err))?;
+
+        notes
+            .collect::<Result<Vec<Note>>>()
+            .map_err(|err| format!("Error al buscar las notas de la libreta: {}", err))
+    }
+
+    fn move_note(&self, note_id: i64, notebook_id: i64) -> Result<(), String> {
+        self.connection
+            .execute(
+                "UPDATE notes SET notebook_id = ?1 WHERE id = ?2",
+                params![notebook_id, note_id],
+            )
+            .map_err(|err| format!("Error al mover la nota: {}", err))?;
+
+        Ok(())
+    }
+
+    fn delete_notebook(&self, id: i64, strategy: NotebookDeletion) -> Result<usize, String> {
+        let notebooks = self.find_all_notebooks()?;
+        let parent_id = notebooks
+            .iter()
+            .find(|notebook| notebook.get_id() == Some(id))
+            .and_then(|notebook| notebook.get_parent_id());
+
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(|err| format!("Error al eliminar la libreta: {}", err))?;
+
+        let affected = match strategy {
+            NotebookDeletion::Cascade => {
+                let mut ids = Notebook::descendants_of(&notebooks, id);
+                ids.push(id);
+
+                let mut affected = 0;
+                // Delete the deepest notebooks first so no notebook is left pointing to a deleted parent
+                for notebook_id in ids.iter().rev() {
+                    affected += transaction
+                        .execute(
+                            "UPDATE notes SET deleted_at = CURRENT_TIMESTAMP WHERE notebook_id = ?1 AND deleted_at IS NULL",
+                            params![notebook_id],
+                        )
+                        .map_err(|err| format!("Error al mover las notas de la libreta a la papelera: {}", err))?;
+                    transaction
+                        .execute("DELETE FROM notebooks WHERE id = ?1", params![notebook_id])
+                        .map_err(|err| format!("Error al eliminar la libreta: {}", err))?;
+                }
+
+                affected
+            }
+            NotebookDeletion::Reparent => {
+                let affected = transaction
+                    .execute(
+                        "UPDATE notes SET notebook_id = ?1 WHERE notebook_id = ?2",
+                        params![parent_id.unwrap_or(Notebook::INBOX_ID), id],
+                    )
+                    .map_err(|err| format!("Error al mover las notas de la libreta: {}", err))?;
+                transaction
+                    .execute(
+                        "UPDATE notebooks SET parent_id = ?1 WHERE parent_id = ?2",
+                        params![parent_id, id],
+                    )
+                    .map_err(|err| format!("Error al mover las libretas: {}", err))?;
+                transaction
+                    .execute("DELETE FROM notebooks WHERE id = ?1", params![id])
+                    .map_err(|err| format!("Error al eliminar la libreta: {}", err))?;
+
+                affected
+            }
+        };
+
+        transaction
+            .commit()
+            .map_err(|err| format!("Error al eliminar la libreta: {}", err))?;
+
+        Ok(affected)
+    }
+}
+
+impl TrashRepository for SqliteNoteRepository {
+    fn find_trashed(&self) -> Result<Vec<Note>, String> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT
+                        id,
+                        title,
+                        content,
+                        created_at,
+                        updated_at,
+                        (SELECT group_concat(tags.name, char(31))
+                            FROM note_tags
+                            JOIN tags ON tags.id = note_tags.tag_id
+                            WHERE note_tags.note_id = notes.id) AS tags,
+                        notes.notebook_id,
+                        notes.locked,
+                        notes.deleted_at
+                    FROM notes
+                    WHERE deleted_at IS NOT NULL
+                    ORDER BY deleted_at DESC;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))?;
+
+        let notes = stmt
+            .query_map([], |row| {
+                let note = SqliteNoteRepository::row_to_note(row)?;
+                let deleted_at: String = row.get(8)?;
+
+                Ok(note.with_deleted_at(SqliteNoteRepository::parse_timestamp(&deleted_at)))
+            })
+            .map_err(|err| format!("Error al buscar las notas de la papelera: {}", err))?;
+
+        notes
+            .collect::<Result<Vec<Note>>>()
+            .map_err(|err| format!("Error al buscar las notas de la papelera: {}", err))
+    }
+
+    fn restore(&self, id: i64) -> Result<(), String> {
+        self.connection
+            .execute(
+                "UPDATE notes
+                    SET deleted_at = NULL,
+                        notebook_id = CASE
+                            WHEN notebook_id IN (SELECT id FROM notebooks) THEN notebook_id
+                            ELSE ?2
+                        END
+                    WHERE id = ?1 AND deleted_at IS NOT NULL",
+                params![id, Notebook::INBOX_ID],
+            )
+            .map_err(|err| format!("Error al restaurar la nota: {}", err))?;
+
+        Ok(())
+    }
+
+    fn purge(&self, id: i64) -> Result<(), String> {
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(|err| format!("Error al eliminar la nota: {}", err))?;
+
+        transaction
+            .execute("DELETE FROM notes WHERE id = ?1 AND deleted_at IS NOT NULL", params![id])
+            .map_err(|err| format!("Error al eliminar la nota: {}", err))?;
+        transaction
+            .execute(
+                "DELETE FROM tags WHERE id NOT IN (SELECT tag_id FROM note_tags)",
+                [],
+            )
+            .map_err(|err| format!("Error al eliminar la nota: {}", err))?;
+
+        transaction
+            .commit()
+            .map_err(|err| format!("Error al eliminar la nota: {}", err))
+    }
+
+    fn purge_deleted_before(&self, before: DateTime<Utc>) -> Result<usize, String> {
+        let transaction = self
+            .connection
+            .unchecked_transaction()
+            .map_err(|err| format!("Error al vaciar la papelera: {}", err))?;
+
+        // `deleted_at` is written by CURRENT_TIMESTAMP, so it compares as text in the same format
+        let purged = transaction
+            .execute(
+                "DELETE FROM notes WHERE deleted_at IS NOT NULL AND deleted_at < ?1",
+                params![before.format("%Y-%m-%d %H:%M:%S").to_string()],
+            )
+            .map_err(|err| format!("Error al vaciar la papelera: {}", err))?;
+        transaction
+            .execute(
+                "DELETE FROM tags WHERE id NOT IN (SELECT tag_id FROM note_tags)",
+                [],
+            )
+            .map_err(|err| format!("Error al vaciar la papelera: {}", err))?;
+
+        transaction
+            .commit()
+            .map_err(|err| format!("Error al vaciar la papelera: {}", err))?;
+
+        Ok(purged)
+    }
+}
+
+impl RevisionRepository for SqliteNoteRepository {
+    fn find_revisions(&self, note_id: i64) -> Result<Vec<Revision>, String> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT id, note_id, title, content, created_at
+                    FROM note_revisions
+                    WHERE note_id = ?1
+                    ORDER BY id DESC;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))?;
+
+        let revisions = stmt
+            .query_map(params![note_id], SqliteNoteRepository::row_to_revision)
+            .map_err(|err| format!("Error al buscar las revisiones: {}", err))?;
+
+        revisions
+            .collect::<Result<Vec<Revision>>>()
+            .map_err(|err| format!("Error al buscar las revisiones: {}", err))
+    }
+
+    fn find_revision(&self, id: i64) -> Result<Option<Revision>, String> {
+        let mut stmt = self
+            .connection
+            .prepare(
+                "SELECT id, note_id, title, content, created_at
+                    FROM note_revisions
+                    WHERE id = ?1;",
+            )
+            .map_err(|err| format!("Error al preparar la consulta: {}", err))?;
+
+        let mut revisions = stmt
+            .query_map(params![id], SqliteNoteRepository::row_to_revision)
+            .map_err(|err| format!("Error al buscar la revisión: {}", err))?;
+
+        revisions
+            .next()
+            .transpose()
+            .map_err(|err| format!("Error al buscar la revisión: {}", err))
+    }
+}
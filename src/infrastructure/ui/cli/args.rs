@@ -0,0 +1,195 @@
+use crate::infrastructure::ui::cli::output::OutputFormat;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// Command line arguments of the application.
+///
+/// Without a subcommand the interactive menu is shown; with one, the command runs
+/// without asking anything (unless stated otherwise) so it can be used from scripts and git hooks.
+#[derive(Debug, Parser)]
+#[command(name = "notes", version, about = "Take notes from the terminal", long_about = None)]
+pub struct Cli {
+    /// The command to run, the interactive menu is shown when missing
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Output format of `list`, `show` and `search`
+    #[arg(short, long, value_enum, global = true, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
+    /// Database file to use instead of the one given by NOTES_DB, the configuration file or the default location
+    #[arg(long, value_name = "PATH", global = true)]
+    pub db: Option<PathBuf>,
+}
+
+/// The non-interactive commands.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Create a note, reading the content from stdin when `--content` is not given
+    Add {
+        /// Title of the note
+        #[arg(short, long)]
+        title: String,
+
+        /// Content of the note, read from stdin when missing
+        #[arg(short, long)]
+        content: Option<String>,
+
+        /// Tag to attach to the note, can be repeated
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+    },
+
+    /// List every note
+    List,
+
+    /// Print a note, asking for its passphrase when it is locked
+    Show {
+        /// ID of the note
+        id: i64,
+    },
+
+    /// Full-text search over the title and content of the notes
+    Search {
+        /// Search query: "exact phrases", prefix* and AND / OR / NOT
+        query: String,
+    },
+
+    /// Change the title or content of a note, opening it in $VISUAL / $EDITOR when neither is given
+    Edit {
+        /// ID of the note
+        id: i64,
+
+        /// New title of the note
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// New content of the note, `-` to read it from stdin
+        #[arg(short, long)]
+        content: Option<String>,
+    },
+
+    /// Move a note to the trash
+    Rm {
+        /// ID of the note
+        id: i64,
+
+        /// Do not ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Encrypt the content of a note with a passphrase, asked for twice; the title stays readable
+    Lock {
+        /// ID of the note
+        id: i64,
+    },
+
+    /// Decrypt a locked note for good, so it can be edited and searched again
+    Unlock {
+        /// ID of the note
+        id: i64,
+    },
+
+    /// Write notes to a directory as Markdown files with a YAML front matter, plus a manifest.json
+    Export {
+        /// Directory the files are written to, created if missing
+        directory: PathBuf,
+
+        /// Export only the notes carrying this tag, can be repeated
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Export only this note, can be repeated
+        #[arg(long = "id", value_name = "ID")]
+        ids: Vec<i64>,
+    },
+
+    /// Create notes from the .md and .txt files of a directory and its subdirectories, or from an export of another application
+    Import {
+        /// Directory holding the files, or the file exported by the application
+        path: PathBuf,
+
+        /// Application the files were written by
+        #[arg(long, value_enum, default_value_t = ImportSource::Markdown)]
+        from: ImportSource,
+
+        /// Check the files and report what would be imported, without saving anything (Markdown only)
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Leave out the files whose content is already held by a note (Markdown only)
+        #[arg(long)]
+        skip_duplicates: bool,
+    },
+
+    /// Write a consistent copy of the database, even while another process is using it
+    Backup {
+        /// Directory the copy is written to, `backups` next to the database by default
+        #[arg(long, value_name = "DIR")]
+        dir: Option<PathBuf>,
+
+        /// Compress the copy with gzip
+        #[arg(long)]
+        gzip: bool,
+
+        /// Keep only the newest N backups in the directory, deleting the older ones
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+        keep: Option<u64>,
+    },
+
+    /// Replace the database with a backup, once it is checked to be a healthy notes database
+    Restore {
+        /// Backup file, compressed with gzip or not
+        file: PathBuf,
+
+        /// Do not ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Encrypt the whole database in place with SQLCipher
+    Encrypt {
+        /// File holding the new passphrase, otherwise the key given by NOTES_DB_KEY or NOTES_DB_KEYFILE,
+        /// or one asked for twice
+        #[arg(long, value_name = "FILE")]
+        keyfile: Option<PathBuf>,
+    },
+
+    /// Change the passphrase of the encrypted database
+    Rekey {
+        /// File holding the new passphrase, otherwise it is asked for twice
+        #[arg(long, value_name = "FILE")]
+        keyfile: Option<PathBuf>,
+    },
+
+    /// Decrypt the database back to a plain SQLite file
+    Decrypt {
+        /// Do not ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Open the full-screen interface with the note list and a preview of the selected note
+    Tui,
+}
+
+/// Where the files given to `import` come from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ImportSource {
+    /// Markdown and plain-text files, e.g. written by `export`
+    #[default]
+    Markdown,
+
+    /// An Obsidian vault: folders become notebooks and [[wikilinks]] links between notes
+    Obsidian,
+
+    /// An .enex file exported by Evernote, or a directory of them: every file becomes a notebook
+    Enex,
+
+    /// The Keep directory of a Google Takeout export, a .json file per note
+    Keep,
+
+    /// The notes.json file of a Simplenote export, or the directory holding it
+    Simplenote,
+}
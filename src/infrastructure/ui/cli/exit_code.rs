@@ -0,0 +1,36 @@
+use crate::domain::errors::note_error::NoteError;
+
+/// Process exit codes returned by the non-interactive commands.
+pub struct ExitCode;
+
+impl ExitCode {
+    /// The command ran successfully
+    pub const SUCCESS: i32 = 0;
+
+    /// The database could not be read or written
+    pub const FAILURE: i32 = 1;
+
+    /// The arguments or the input are not valid (the same code used by the argument parser)
+    pub const INVALID_INPUT: i32 = 2;
+
+    /// The requested note does not exist
+    pub const NOT_FOUND: i32 = 3;
+
+    /// The command was canceled, e.g. a deletion was not confirmed
+    pub const CANCELED: i32 = 4;
+
+    /// Maps a `NoteError` to the exit code describing it.
+    ///
+    /// # Arguments
+    /// - `error`: The error returned by a use case.
+    ///
+    /// # Returns
+    /// The exit code for the error.
+    pub fn from_error(error: &NoteError) -> i32 {
+        match error {
+            NoteError::NotFound(_) | NoteError::NoNotes => ExitCode::NOT_FOUND,
+            NoteError::Storage(_) => ExitCode::FAILURE,
+            _ => ExitCode::INVALID_INPUT,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod args;
+pub mod exit_code;
+pub mod output;
+pub mod runner;
\ No newline at end of file
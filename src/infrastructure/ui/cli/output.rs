@@ -0,0 +1,127 @@
+use crate::domain::entities::{note::Note, search_result::SearchResult};
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// Version of the JSON documents written by the commands.
+///
+/// It is only increased when a field is removed, renamed or changes its meaning;
+/// adding new fields keeps the version, so consumers must ignore the fields they do not know.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// How the read commands (`list`, `show` and `search`) print their results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable text
+    #[default]
+    Text,
+
+    /// A single JSON document: an array for `list` and `search`, an object for `show`
+    Json,
+
+    /// Newline-delimited JSON: one object per line
+    Ndjson,
+}
+
+/// JSON representation of a note.
+#[derive(Debug, Serialize)]
+pub struct NoteRecord {
+    /// Version of this schema, see `SCHEMA_VERSION`
+    pub schema_version: u32,
+
+    pub id: i64,
+    pub title: String,
+    pub content: String,
+
+    /// Creation time in RFC 3339, UTC
+    pub created_at: String,
+
+    /// Last update time in RFC 3339, UTC
+    pub updated_at: String,
+
+    pub notebook_id: i64,
+
+    /// Tag names, sorted alphabetically
+    pub tags: Vec<String>,
+
+    /// Whether the content is encrypted: `list` gives its sealed content, `show` asks for the passphrase
+    pub locked: bool,
+}
+
+impl From<&Note> for NoteRecord {
+    fn from(note: &Note) -> Self {
+        NoteRecord {
+            schema_version: SCHEMA_VERSION,
+            id: note.get_id().unwrap_or_default(),
+            title: note.get_title().clone(),
+            content: note.get_content().clone(),
+            created_at: note.get_created_at().to_rfc3339(),
+            updated_at: note.get_updated_at().to_rfc3339(),
+            notebook_id: note.get_notebook_id(),
+            tags: note.get_tags().iter().cloned().collect(),
+            locked: note.is_locked(),
+        }
+    }
+}
+
+/// JSON representation of a search result: the note plus the matching fragment.
+#[derive(Debug, Serialize)]
+pub struct SearchRecord {
+    #[serde(flatten)]
+    pub note: NoteRecord,
+
+    /// Fragment of the note around the matched terms, without highlight markers
+    pub snippet: String,
+
+    /// Relevance of the match, lower is better
+    pub rank: f64,
+}
+
+impl From<&SearchResult> for SearchRecord {
+    fn from(result: &SearchResult) -> Self {
+        SearchRecord {
+            note: NoteRecord::from(&result.note),
+            snippet: result
+                .snippet
+                .chars()
+                .filter(|c| *c != SearchResult::HIGHLIGHT_START && *c != SearchResult::HIGHLIGHT_END)
+                .collect(),
+            rank: result.rank,
+        }
+    }
+}
+
+/// Prints records as JSON or newline-delimited JSON.
+pub struct JsonWriter;
+
+impl JsonWriter {
+    /// Prints a list of records: a JSON array, or one object per line for NDJSON.
+    ///
+    /// # Arguments
+    /// - `format`: `OutputFormat::Json` or `OutputFormat::Ndjson`.
+    /// - `records`: The records to print, possibly none.
+    pub fn write_all<T: Serialize>(format: OutputFormat, records: &[T]) -> Result<(), String> {
+        match format {
+            OutputFormat::Ndjson => {
+                for record in records {
+                    println!("{}", JsonWriter::to_json(record)?);
+                }
+            }
+            _ => println!("{}", JsonWriter::to_json(&records)?),
+        }
+
+        Ok(())
+    }
+
+    /// Prints a single record as one JSON object, in a single line.
+    ///
+    /// # Arguments
+    /// - `record`: The record to print.
+    pub fn write_one<T: Serialize>(record: &T) -> Result<(), String> {
+        println!("{}", JsonWriter::to_json(record)?);
+        Ok(())
+    }
+
+    fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String, String> {
+        serde_json::to_string(value).map_err(|err| format!("Error al generar el JSON: {}", err))
+    }
+}
@@ -0,0 +1,843 @@
+use crate::{
+    application::palette::Palette,
+    application::use_cases::{
+        importers::{
+            ImportProblem, ImportReport,
+            enex::ImportEnex,
+            keep::ImportKeepNotes,
+            obsidian::{ImportObsidianVault, VaultReport},
+            simplenote::ImportSimplenoteNotes,
+        },
+        notebooks::find_all::FindAllNotebooks,
+        notes::{
+            create::CreateNewNote,
+            delete::DeletedNote,
+            export::{ExportFilter, ExportNotes},
+            find_all::FindAll,
+            find_by_id::FindById,
+            import::{ImportNotes, ImportOptions, ImportOutcome},
+            lock::LockNote,
+            reveal::RevealNote,
+            search::SearchNotes,
+            unlock::UnlockNote,
+            update::UpdateNote,
+        },
+    },
+    domain::{
+        entities::{note::Note, notebook::Notebook, search_result::SearchResult},
+        errors::note_error::NoteError,
+    },
+    infrastructure::config::{
+        database_key::{DatabaseKey, KeySource},
+        settings::Settings,
+    },
+    infrastructure::persistence::{
+        backup::{BackupOptions, DatabaseBackup, RestoreError},
+        database_encryption::DatabaseEncryption,
+        sqlite_note_repository::SqliteNoteRepository,
+    },
+    infrastructure::ui::{
+        cli::{
+            args::{Command, ImportSource},
+            exit_code::ExitCode,
+            output::{JsonWriter, NoteRecord, OutputFormat, SearchRecord},
+        },
+        icli::{
+            actions::notes::edit::{EditNoteAction, EditOutcome},
+            error_renderer::ErrorRenderer,
+        },
+        passphrase::PassphrasePrompt,
+        tui::app::TuiApp,
+        viewer::NoteViewer,
+    },
+};
+use chrono::prelude::*;
+use inquire::Confirm;
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+/// Runs the non-interactive commands on top of the existing use cases.
+pub struct CommandRunner<'a> {
+    note_repository: &'a SqliteNoteRepository,
+    settings: &'a Settings,
+    output: OutputFormat,
+}
+
+impl<'a> CommandRunner<'a> {
+    /// Creates a new `CommandRunner`.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by every command.
+    /// - `settings`: The settings of the application.
+    /// - `output`: How the read commands print their results.
+    pub fn new(note_repository: &'a SqliteNoteRepository, settings: &'a Settings, output: OutputFormat) -> Self {
+        CommandRunner {
+            note_repository,
+            settings,
+            output,
+        }
+    }
+
+    /// Runs a command, printing its output to stdout and any error to stderr.
+    ///
+    /// # Arguments
+    /// - `command`: The parsed command.
+    ///
+    /// # Returns
+    /// The process exit code, see `ExitCode`.
+    pub fn run(&self, command: Command) -> i32 {
+        let result = match command {
+            Command::Add { title, content, tags } => self.add(title, content, &tags),
+            Command::List => self.list(),
+            Command::Show { id } => self.show(id),
+            Command::Search { query } => self.search(&query),
+            Command::Edit { id, title, content } => self.edit(id, title, content),
+            Command::Rm { id, yes } => self.remove(id, yes),
+            Command::Lock { id } => self.lock(id),
+            Command::Unlock { id } => self.unlock(id),
+            Command::Export { directory, tags, ids } => self.export(&directory, tags, ids),
+            Command::Import {
+                path,
+                from,
+                dry_run,
+                skip_duplicates,
+            } => match from {
+                ImportSource::Markdown => self.import(&path, dry_run, skip_duplicates),
+                _ if dry_run || skip_duplicates => Ok(CommandRunner::unsupported_import_flags()),
+                ImportSource::Obsidian => self.import_obsidian(&path),
+                ImportSource::Enex => self.import_enex(&path),
+                ImportSource::Keep => ImportKeepNotes::new(self.note_repository, self.settings.limits)
+                    .execute(&path, Notebook::INBOX_ID)
+                    .map(|report| CommandRunner::render_import_report(&report)),
+                ImportSource::Simplenote => ImportSimplenoteNotes::new(self.note_repository, self.settings.limits)
+                    .execute(&path, Notebook::INBOX_ID)
+                    .map(|report| CommandRunner::render_import_report(&report)),
+            },
+            Command::Backup { dir, gzip, keep } => self.backup(dir, gzip, keep),
+            Command::Restore { file, yes } => self.restore(&file, yes),
+            Command::Encrypt { keyfile } => self.encrypt(keyfile.as_deref()),
+            Command::Rekey { keyfile } => self.rekey(keyfile.as_deref()),
+            Command::Decrypt { yes } => self.decrypt(yes),
+            Command::Tui => self.tui(),
+        };
+
+        match result {
+            Ok(code) => code,
+            Err(err) => {
+                let indicator = if err.is_validation() {
+                    Palette::current().warning(">")
+                } else {
+                    Palette::current().error(">")
+                };
+                eprintln!("{} {}", indicator, ErrorRenderer::message(&err));
+
+                ExitCode::from_error(&err)
+            }
+        }
+    }
+
+    /// `notes add`: creates a note, reading the content from stdin when it is not given.
+    fn add(&self, title: String, content: Option<String>, tags: &[String]) -> Result<i32, NoteError> {
+        let content = match content {
+            Some(content) => content,
+            None => match CommandRunner::read_stdin() {
+                Some(content) => content,
+                None => return Ok(ExitCode::FAILURE),
+            },
+        };
+
+        CreateNewNote::new(self.note_repository, self.settings.limits).execute(&title, &content, tags, Notebook::INBOX_ID)?;
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes list`: prints one line per note with its ID, last update and title, separated by tabs,
+    /// or every note as JSON.
+    fn list(&self) -> Result<i32, NoteError> {
+        let notes = match FindAll::new(self.note_repository).execute() {
+            Ok(notes) => notes,
+            // An empty list is not an error when listing
+            Err(NoteError::NoNotes) => Vec::new(),
+            Err(err) => return Err(err),
+        };
+
+        if self.output != OutputFormat::Text {
+            let records: Vec<NoteRecord> = notes.iter().map(NoteRecord::from).collect();
+            JsonWriter::write_all(self.output, &records).map_err(NoteError::Storage)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        for note in notes {
+            println!(
+                "{}\t{}\t{}",
+                note.get_id().unwrap_or_default(),
+                note.get_updated_at().with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+                note.get_title()
+            );
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes show`: prints the title, metadata and content of a note,
+    /// asking for the passphrase of a locked note to print its content.
+    fn show(&self, id: i64) -> Result<i32, NoteError> {
+        let stored = FindById::new(self.note_repository).execute(id)?;
+
+        let note = if stored.is_locked() {
+            let Some(passphrase) = CommandRunner::ask_passphrase(&stored) else {
+                return Ok(ExitCode::CANCELED);
+            };
+            RevealNote::new(self.note_repository).execute(id, &passphrase)?
+        } else {
+            stored.clone()
+        };
+
+        if self.output != OutputFormat::Text {
+            let record = NoteRecord {
+                locked: stored.is_locked(),
+                ..NoteRecord::from(&note)
+            };
+            JsonWriter::write_one(&record).map_err(NoteError::Storage)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        let notebooks = FindAllNotebooks::new(self.note_repository).execute()?;
+
+        NoteViewer::show(&note, &notebooks);
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes search`: prints the matching notes, best first, each one followed by its snippet.
+    /// As JSON, no match is an empty result rather than an error.
+    fn search(&self, query: &str) -> Result<i32, NoteError> {
+        if self.output != OutputFormat::Text {
+            let results = match SearchNotes::new(self.note_repository).execute(query) {
+                Ok(results) => results,
+                Err(NoteError::NotFound(_)) => Vec::new(),
+                Err(err) => return Err(err),
+            };
+
+            let records: Vec<SearchRecord> = results.iter().map(SearchRecord::from).collect();
+            JsonWriter::write_all(self.output, &records).map_err(NoteError::Storage)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        let results = SearchNotes::new(self.note_repository).execute(query)?;
+
+        for result in &results {
+            println!("{}\t{}", result.note.get_id().unwrap_or_default(), result.note.get_title());
+            println!("    {}", CommandRunner::render_snippet(&result.snippet));
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes edit`: updates the given fields, or opens the note in the user's editor when none is given.
+    fn edit(&self, id: i64, title: Option<String>, content: Option<String>) -> Result<i32, NoteError> {
+        let note = FindById::new(self.note_repository).execute(id)?;
+
+        let content = match content.as_deref() {
+            Some("-") => match CommandRunner::read_stdin() {
+                Some(content) => Some(content),
+                None => return Ok(ExitCode::FAILURE),
+            },
+            _ => content,
+        };
+
+        let (title, content) = match (title, content) {
+            // The editor would write the plain content of a locked note to a file
+            (None, None) if note.is_locked() => return Err(NoteError::Locked(id)),
+            (None, None) => {
+                return Ok(match EditNoteAction::execute(self.note_repository, self.settings, &note) {
+                    EditOutcome::Saved | EditOutcome::Unchanged => ExitCode::SUCCESS,
+                    EditOutcome::Canceled => ExitCode::CANCELED,
+                });
+            }
+            (title, content) => (
+                title.unwrap_or_else(|| note.get_title().clone()),
+                content.unwrap_or_else(|| note.get_content().clone()),
+            ),
+        };
+
+        if &title == note.get_title() && &content == note.get_content() {
+            println!("{} No changes to save", Palette::current().warning(">"));
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        UpdateNote::new(self.note_repository, self.settings.limits).execute(id, &title, &content)?;
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes rm`: moves a note to the trash, asking first unless `--yes` is given.
+    fn remove(&self, id: i64, yes: bool) -> Result<i32, NoteError> {
+        let note: Note = FindById::new(self.note_repository).execute(id)?;
+
+        if !yes {
+            // Without a terminal there is nobody to ask, so scripts must pass --yes
+            if !std::io::stdin().is_terminal() {
+                eprintln!(
+                    "{} Refusing to delete the note without confirmation, pass --yes",
+                    Palette::current().error(">")
+                );
+                return Ok(ExitCode::CANCELED);
+            }
+
+            let message = format!("Move the note `{}` to the trash?", note.get_title());
+            if !Confirm::new(&message).with_default(self.settings.confirm.delete).prompt().unwrap_or(false) {
+                return Ok(ExitCode::CANCELED);
+            }
+        }
+
+        DeletedNote::new(self.note_repository).execute(id)?;
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes lock`: seals the content of a note with a passphrase, asked for twice.
+    fn lock(&self, id: i64) -> Result<i32, NoteError> {
+        let note = FindById::new(self.note_repository).execute(id)?;
+        if note.is_locked() {
+            return Err(NoteError::Locked(id));
+        }
+
+        let Some(passphrase) = CommandRunner::ask_new_passphrase(&note) else {
+            return Ok(ExitCode::CANCELED);
+        };
+        LockNote::new(self.note_repository).execute(id, &passphrase)?;
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes unlock`: opens a locked note with its passphrase and saves its plain content.
+    fn unlock(&self, id: i64) -> Result<i32, NoteError> {
+        let note = FindById::new(self.note_repository).execute(id)?;
+        if !note.is_locked() {
+            return Err(NoteError::NotLocked(id));
+        }
+
+        let Some(passphrase) = CommandRunner::ask_passphrase(&note) else {
+            return Ok(ExitCode::CANCELED);
+        };
+        UnlockNote::new(self.note_repository).execute(id, &passphrase)?;
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// Asks for the passphrase of a locked note, `None` when canceled or there is nobody to ask.
+    fn ask_passphrase(note: &Note) -> Option<String> {
+        if !CommandRunner::can_ask_passphrase(note) {
+            return None;
+        }
+
+        PassphrasePrompt::ask(note)
+    }
+
+    /// Asks twice for the passphrase to lock a note with, `None` when canceled or there is nobody to ask.
+    fn ask_new_passphrase(note: &Note) -> Option<String> {
+        if !CommandRunner::can_ask_passphrase(note) {
+            return None;
+        }
+
+        PassphrasePrompt::ask_new(note)
+    }
+
+    /// Passphrases are only typed in a terminal, never read from stdin or the arguments,
+    /// so they do not end up in the shell history or in the scripts.
+    fn can_ask_passphrase(note: &Note) -> bool {
+        let terminal = std::io::stdin().is_terminal();
+        if !terminal {
+            eprintln!(
+                "{} The passphrase of note {} can only be typed in a terminal",
+                Palette::current().error(">"),
+                note.get_id().unwrap_or_default()
+            );
+        }
+
+        terminal
+    }
+
+    /// `notes export`: writes the notes to a directory, printing the ID and file of each one.
+    fn export(&self, directory: &Path, tags: Vec<String>, ids: Vec<i64>) -> Result<i32, NoteError> {
+        let filter = ExportFilter { ids, tags };
+        let exported = ExportNotes::new(self.note_repository, self.note_repository).execute(directory, &filter)?;
+
+        for note in &exported {
+            println!("{}\t{}", note.id, note.path.display());
+        }
+        println!(
+            "{} Exported {} notes to {}",
+            Palette::current().success(">"),
+            Palette::current().accent(exported.len().to_string()),
+            directory.display()
+        );
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes import`: imports the files of a directory, printing what happened to each one
+    /// and a summary. Files that cannot be imported make the command exit with `INVALID_INPUT`.
+    fn import(&self, directory: &Path, dry_run: bool, skip_duplicates: bool) -> Result<i32, NoteError> {
+        let options = ImportOptions {
+            dry_run,
+            skip_duplicates,
+            notebook_id: Notebook::INBOX_ID,
+        };
+        let files = ImportNotes::new(self.note_repository, self.note_repository, self.settings.limits)
+            .execute(directory, &options)?;
+
+        let palette = Palette::current();
+        let (mut imported, mut skipped, mut failed) = (0, 0, 0);
+        for file in &files {
+            let (label, detail) = match &file.outcome {
+                ImportOutcome::Imported(id) => {
+                    imported += 1;
+                    (palette.success("imported"), format!("note {}: {}", id, file.title))
+                }
+                ImportOutcome::WouldImport => {
+                    imported += 1;
+                    (palette.success("would import"), file.title.clone())
+                }
+                ImportOutcome::Duplicate(id) => {
+                    skipped += 1;
+                    let detail = match id {
+                        Some(id) => format!("same content as note {}", id),
+                        None => String::from("same content as a previous file"),
+                    };
+                    (palette.warning("duplicate"), detail)
+                }
+                ImportOutcome::AlreadyImported(id) => {
+                    skipped += 1;
+                    (palette.warning("skipped"), format!("exported from note {}, which has not changed", id))
+                }
+                ImportOutcome::Invalid(err) => {
+                    failed += 1;
+                    (palette.error("invalid"), ErrorRenderer::message(err))
+                }
+                ImportOutcome::Unreadable(reason) => {
+                    failed += 1;
+                    (palette.error("unreadable"), reason.clone())
+                }
+            };
+            println!("{}\t{}\t{}", label, file.path.display(), detail);
+        }
+
+        println!(
+            "{} {} {} notes, skipped {}, {} files could not be imported",
+            palette.success(">"),
+            if dry_run { "Would import" } else { "Imported" },
+            palette.accent(imported.to_string()),
+            skipped,
+            failed
+        );
+
+        Ok(if failed > 0 { ExitCode::INVALID_INPUT } else { ExitCode::SUCCESS })
+    }
+
+    /// `notes import --from obsidian`: imports a vault, then prints the note created for each file,
+    /// the files that failed, the links that could not be resolved and a summary.
+    fn import_obsidian(&self, vault: &Path) -> Result<i32, NoteError> {
+        let report = ImportObsidianVault::new(self.note_repository, self.note_repository, self.settings.limits)
+            .execute(vault, Notebook::INBOX_ID)?;
+
+        CommandRunner::render_vault_report(&report);
+
+        Ok(if report.failures.is_empty() { ExitCode::SUCCESS } else { ExitCode::INVALID_INPUT })
+    }
+
+    fn render_vault_report(report: &VaultReport) {
+        let palette = Palette::current();
+
+        for note in &report.notes {
+            println!("{}	{}	note {}: {}", palette.success("imported"), note.path.display(), note.id, note.title);
+        }
+        for (path, err) in &report.failures {
+            println!("{}\t{}\t{}", palette.error("failed"), path.display(), ErrorRenderer::message(err));
+        }
+        for link in &report.unresolved {
+            println!("{}\t{}\t{}: {}", palette.warning("unresolved"), link.path.display(), link.link, link.reason);
+        }
+
+        println!(
+            "{} Imported {} notes, created {} notebooks, {} links could not be resolved, {} problems",
+            palette.success(">"),
+            palette.accent(report.notes.len().to_string()),
+            report.notebooks_created,
+            report.unresolved.len(),
+            report.failures.len()
+        );
+    }
+
+    /// `notes import --from enex`: imports Evernote exports, then prints the notes saved, the notes
+    /// that failed, the attachments left out and a summary.
+    fn import_enex(&self, path: &Path) -> Result<i32, NoteError> {
+        let report = ImportEnex::new(self.note_repository, self.note_repository, self.settings.limits).execute(path)?;
+        let palette = Palette::current();
+
+        for note in &report.notes {
+            println!("{}	{}	note {}: {}", palette.success("imported"), note.path.display(), note.id, note.title);
+        }
+        for failure in &report.failures {
+            let (label, detail) = match &failure.problem {
+                ImportProblem::Invalid(err) => (palette.error("invalid"), ErrorRenderer::message(err)),
+                ImportProblem::Unreadable(reason) => (palette.error("unreadable"), reason.clone()),
+            };
+            let location = match &failure.title {
+                Some(title) => format!("{}: {}", failure.path.display(), title),
+                None => failure.path.display().to_string(),
+            };
+            println!("{}\t{}\t{}", label, location, detail);
+        }
+        for resource in &report.skipped {
+            println!(
+                "{}\t{}: {}\tattachment {} not imported",
+                palette.warning("skipped"),
+                resource.path.display(),
+                resource.note,
+                resource.name
+            );
+        }
+
+        println!(
+            "{} Imported {} notes, created {} notebooks, skipped {} attachments, {} problems",
+            palette.success(">"),
+            palette.accent(report.notes.len().to_string()),
+            report.notebooks_created,
+            report.skipped.len(),
+            report.failures.len()
+        );
+
+        Ok(if report.failures.is_empty() { ExitCode::SUCCESS } else { ExitCode::INVALID_INPUT })
+    }
+
+    /// `notes import --from keep` and `--from simplenote`: prints the notes saved, the notes that failed
+    /// and a summary, returning `INVALID_INPUT` when some note failed.
+    fn render_import_report(report: &ImportReport) -> i32 {
+        let palette = Palette::current();
+
+        for note in &report.notes {
+            let label = if note.trashed { palette.warning("trashed") } else { palette.success("imported") };
+            println!("{}	{}	note {}: {}", label, note.source, note.id, note.title);
+        }
+        for failure in &report.failures {
+            let (label, detail) = match &failure.problem {
+                ImportProblem::Invalid(err) => (palette.error("invalid"), ErrorRenderer::message(err)),
+                ImportProblem::Unreadable(reason) => (palette.error("unreadable"), reason.clone()),
+            };
+            println!("{}\t{}\t{}", label, failure.source, detail);
+        }
+
+        println!(
+            "{} Imported {} notes, {} of them to the trash, {} problems",
+            palette.success(">"),
+            palette.accent(report.notes.len().to_string()),
+            report.trashed,
+            report.failures.len()
+        );
+
+        if report.failures.is_empty() { ExitCode::SUCCESS } else { ExitCode::INVALID_INPUT }
+    }
+
+    /// `--dry-run` and `--skip-duplicates` are only implemented for plain Markdown files.
+    fn unsupported_import_flags() -> i32 {
+        eprintln!(
+            "{} --dry-run and --skip-duplicates can only be used with --from markdown",
+            Palette::current().warning(">")
+        );
+        ExitCode::INVALID_INPUT
+    }
+
+    /// `notes backup`: copies the database to the backup directory, printing the new backup
+    /// and the old ones deleted by `--keep`.
+    fn backup(&self, dir: Option<PathBuf>, compress: bool, keep: Option<u64>) -> Result<i32, NoteError> {
+        let database = self.database_path()?;
+        let directory = dir.unwrap_or_else(|| DatabaseBackup::default_dir(&database));
+        let options = BackupOptions {
+            compress,
+            keep: keep.map(|keep| keep as usize),
+            key: self.note_repository.key().map(String::from),
+        };
+
+        let outcome =
+            DatabaseBackup::create(self.note_repository.connection(), &directory, &options).map_err(NoteError::Storage)?;
+
+        let palette = Palette::current();
+        println!(
+            "{} Backed up the database to {}",
+            palette.success(">"),
+            palette.accent(outcome.path.display().to_string())
+        );
+        for removed in &outcome.removed {
+            println!("{} Deleted the old backup {}", palette.secondary(">"), removed.display());
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes restore`: replaces the database with a backup, asking first unless `--yes` is given.
+    /// A file that is not a valid backup makes the command exit with `INVALID_INPUT`.
+    fn restore(&self, file: &Path, yes: bool) -> Result<i32, NoteError> {
+        let database = self.database_path()?;
+
+        if !yes {
+            // Without a terminal there is nobody to ask, so scripts must pass --yes
+            if !std::io::stdin().is_terminal() {
+                eprintln!(
+                    "{} Refusing to replace the database without confirmation, pass --yes",
+                    Palette::current().error(">")
+                );
+                return Ok(ExitCode::CANCELED);
+            }
+
+            let message = format!(
+                "Replace every note in {} with the backup {}?",
+                database.display(),
+                file.display()
+            );
+            let confirmed = Confirm::new(&message)
+                .with_help_message("The changes made after the backup are lost")
+                .with_default(self.settings.confirm.delete)
+                .prompt()
+                .unwrap_or(false);
+            if !confirmed {
+                return Ok(ExitCode::CANCELED);
+            }
+        }
+
+        match DatabaseBackup::restore(&database, file, self.note_repository.key()) {
+            Ok(outcome) => {
+                let palette = Palette::current();
+                println!(
+                    "{} Restored the backup {}",
+                    palette.success(">"),
+                    palette.accent(file.display().to_string())
+                );
+                if outcome.migrated_to > outcome.version {
+                    println!(
+                        "{} Upgraded it from schema version {} to {}",
+                        palette.secondary(">"),
+                        outcome.version,
+                        outcome.migrated_to
+                    );
+                }
+                Ok(ExitCode::SUCCESS)
+            }
+            Err(RestoreError::Invalid(reason)) => {
+                eprintln!("{} {}", Palette::current().warning(">"), reason);
+                Ok(ExitCode::INVALID_INPUT)
+            }
+            Err(RestoreError::Failed(reason)) => Err(NoteError::Storage(reason)),
+        }
+    }
+
+    /// `notes encrypt`: encrypts the whole database in place with SQLCipher. The passphrase is read
+    /// from `--keyfile`, else from NOTES_DB_KEY or the keyfile given by NOTES_DB_KEYFILE or the
+    /// configuration, so the database can be opened with it next time, else asked for twice.
+    fn encrypt(&self, keyfile: Option<&Path>) -> Result<i32, NoteError> {
+        let database = self.database_path()?;
+        if DatabaseEncryption::is_encrypted(&database) {
+            eprintln!(
+                "{} The database is already encrypted, use `rekey` to change its passphrase",
+                Palette::current().warning(">")
+            );
+            return Ok(ExitCode::INVALID_INPUT);
+        }
+
+        let key = match keyfile {
+            Some(keyfile) => Some(DatabaseKey::from_keyfile(keyfile).map_err(NoteError::Storage)?),
+            None => DatabaseKey::resolve(self.settings.database_keyfile.as_deref()).map_err(NoteError::Storage)?,
+        };
+        let Some(key) = key.or_else(|| CommandRunner::ask_new_database_key(&database)) else {
+            return Ok(ExitCode::CANCELED);
+        };
+
+        DatabaseEncryption::encrypt(self.note_repository.connection(), key.get_passphrase()).map_err(NoteError::Storage)?;
+
+        let palette = Palette::current();
+        println!(
+            "{} Encrypted the database {}",
+            palette.success(">"),
+            palette.accent(database.display().to_string())
+        );
+        if *key.get_source() == KeySource::Prompt {
+            println!(
+                "{} It is asked for from now on, or read from {} or {}",
+                palette.secondary(">"),
+                DatabaseKey::ENV_VAR,
+                DatabaseKey::KEYFILE_ENV_VAR
+            );
+        }
+
+        // Backups made before are left as they were, readable by anyone who can read the files
+        let plain_copies = DatabaseBackup::plain_copies(&database);
+        if !plain_copies.is_empty() {
+            println!(
+                "{} These copies of the database are not encrypted, delete them if you no longer need them:",
+                palette.warning(">")
+            );
+            for copy in &plain_copies {
+                println!("  {}", copy.display());
+            }
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes rekey`: changes the passphrase of the encrypted database, reading the new one
+    /// from `--keyfile` or asking for it twice.
+    fn rekey(&self, keyfile: Option<&Path>) -> Result<i32, NoteError> {
+        let database = self.database_path()?;
+        if !DatabaseEncryption::is_encrypted(&database) {
+            eprintln!(
+                "{} The database is not encrypted, use `encrypt` to encrypt it",
+                Palette::current().warning(">")
+            );
+            return Ok(ExitCode::INVALID_INPUT);
+        }
+
+        let key = match keyfile {
+            Some(keyfile) => Some(DatabaseKey::from_keyfile(keyfile).map_err(NoteError::Storage)?),
+            None => CommandRunner::ask_new_database_key(&database),
+        };
+        let Some(key) = key else {
+            return Ok(ExitCode::CANCELED);
+        };
+
+        DatabaseEncryption::rekey(self.note_repository.connection(), key.get_passphrase()).map_err(NoteError::Storage)?;
+
+        let palette = Palette::current();
+        println!(
+            "{} Changed the passphrase of the database {}",
+            palette.success(">"),
+            palette.accent(database.display().to_string())
+        );
+        // The old backups are still encrypted with the previous passphrase
+        println!(
+            "{} Its backups keep the previous passphrase, update {} or {} if you use them",
+            palette.secondary(">"),
+            DatabaseKey::ENV_VAR,
+            DatabaseKey::KEYFILE_ENV_VAR
+        );
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// `notes decrypt`: decrypts the database back to a plain SQLite file, asking first unless `--yes` is given.
+    fn decrypt(&self, yes: bool) -> Result<i32, NoteError> {
+        let database = self.database_path()?;
+        if !DatabaseEncryption::is_encrypted(&database) {
+            eprintln!("{} The database is not encrypted", Palette::current().warning(">"));
+            return Ok(ExitCode::INVALID_INPUT);
+        }
+
+        if !yes {
+            // Without a terminal there is nobody to ask, so scripts must pass --yes
+            if !std::io::stdin().is_terminal() {
+                eprintln!(
+                    "{} Refusing to decrypt the database without confirmation, pass --yes",
+                    Palette::current().error(">")
+                );
+                return Ok(ExitCode::CANCELED);
+            }
+
+            let message = format!("Decrypt {}, leaving every note readable on disk?", database.display());
+            let confirmed = Confirm::new(&message)
+                .with_default(self.settings.confirm.save)
+                .prompt()
+                .unwrap_or(false);
+            if !confirmed {
+                return Ok(ExitCode::CANCELED);
+            }
+        }
+
+        DatabaseEncryption::decrypt(self.note_repository.connection()).map_err(NoteError::Storage)?;
+
+        println!(
+            "{} Decrypted the database {}",
+            Palette::current().success(">"),
+            Palette::current().accent(database.display().to_string())
+        );
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// Asks twice for a new passphrase of the database, `None` when canceled or there is nobody to ask.
+    fn ask_new_database_key(database: &Path) -> Option<DatabaseKey> {
+        if !std::io::stdin().is_terminal() {
+            eprintln!(
+                "{} The new passphrase of the database can only be typed in a terminal, or given with --keyfile",
+                Palette::current().error(">")
+            );
+            return None;
+        }
+
+        PassphrasePrompt::ask_new_database(database).map(DatabaseKey::typed)
+    }
+
+    /// The file of the database the repository works on.
+    fn database_path(&self) -> Result<PathBuf, NoteError> {
+        self.note_repository
+            .connection()
+            .path()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .ok_or_else(|| NoteError::Storage(String::from("la base de datos no está en un fichero")))
+    }
+
+    /// `notes tui`: opens the full-screen interface until the user quits.
+    fn tui(&self) -> Result<i32, NoteError> {
+        if let Err(err) = TuiApp::new(self.note_repository, self.settings).run() {
+            eprintln!("{} Could not run the full-screen interface: {}", Palette::current().error(">"), err);
+            return Ok(ExitCode::FAILURE);
+        }
+
+        Ok(ExitCode::SUCCESS)
+    }
+
+    /// Reads the whole standard input, dropping the trailing line break.
+    /// Prints the problem and returns `None` if it cannot be read.
+    fn read_stdin() -> Option<String> {
+        let mut content = String::new();
+
+        if let Err(err) = std::io::stdin().read_to_string(&mut content) {
+            eprintln!("{} Could not read the content from stdin: {}", Palette::current().error(">"), err);
+            return None;
+        }
+
+        let trimmed = content.trim_end_matches(['\n', '\r']).len();
+        content.truncate(trimmed);
+        Some(content)
+    }
+
+    /// Turns a search snippet into a single line, highlighting the matched terms
+    /// only when the output is a terminal.
+    fn render_snippet(snippet: &str) -> String {
+        let colored = std::io::stdout().is_terminal();
+        let mut rendered = String::new();
+        let mut highlighted = String::new();
+        let mut in_highlight = false;
+
+        for c in snippet.chars() {
+            match c {
+                SearchResult::HIGHLIGHT_START => in_highlight = true,
+                SearchResult::HIGHLIGHT_END => {
+                    if colored {
+                        rendered.push_str(&Palette::current().highlight(highlighted.as_str()).to_string());
+                    } else {
+                        rendered.push_str(&highlighted);
+                    }
+                    highlighted.clear();
+                    in_highlight = false;
+                }
+                '\n' | '\r' | '\t' => rendered.push(' '),
+                c if in_highlight => highlighted.push(c),
+                c => rendered.push(c),
+            }
+        }
+
+        rendered.push_str(&highlighted);
+        rendered
+    }
+}
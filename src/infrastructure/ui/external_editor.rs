@@ -0,0 +1,325 @@
+use crate::domain::entities::{note::Note, tag::Tag};
+use crate::infrastructure::config::xdg::Xdg;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeSet,
+    fs::{self, DirBuilder, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+};
+#[cfg(unix)]
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+
+/// The editable parts of a note, as written in the temporary file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteDraft {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub content: String,
+}
+
+/// What the user did in the editor.
+#[derive(Debug)]
+pub enum EditorOutcome {
+    /// The file was saved without changing the note
+    Unchanged,
+
+    /// The note was changed, the draft holds the new values
+    Edited(NoteDraft),
+}
+
+/// Edits a note in the user's editor (`$VISUAL`, then `$EDITOR`) through a temporary Markdown file.
+///
+/// The file starts with a front-matter header holding the ID, title and tags of the note,
+/// followed by its content:
+///
+/// ```text
+/// ---
+/// id: 3
+/// title: Meeting
+/// tags: work, planning
+/// ---
+///
+/// Review the plan
+/// ```
+///
+/// The file is only removed with `discard`, so the edits survive an editor crash,
+/// a header that cannot be read or a failed save, and can be resumed later.
+///
+/// It holds the note in plain text, even when the database is encrypted, so it is kept in
+/// `$XDG_STATE_HOME/notes-rust/drafts`, readable only by the user, and never in the shared
+/// temporary directory. Its name tells the database apart, so two databases do not share the edits
+/// of their notes with the same ID.
+pub struct ExternalEditor {
+    id: i64,
+    original: NoteDraft,
+    path: PathBuf,
+}
+
+impl ExternalEditor {
+    /// Delimiter of the front-matter header
+    const DELIMITER: &'static str = "---";
+
+    /// Editor launched when neither `$VISUAL` nor `$EDITOR` are set
+    #[cfg(windows)]
+    const FALLBACK_EDITOR: &'static str = "notepad";
+    #[cfg(not(windows))]
+    const FALLBACK_EDITOR: &'static str = "vi";
+
+    /// Name of the directory of the temporary files inside the state directory
+    const DRAFTS_DIR: &'static str = "drafts";
+
+    /// Prepares the edition of a note. Nothing is written until `write` is called.
+    ///
+    /// # Arguments
+    /// - `note`: The note to edit, as currently stored.
+    /// - `database`: The path of the database holding the note.
+    ///
+    /// # Errors
+    /// Returns an error message if the home directory is unknown, so there is no private place for the file.
+    pub fn for_note(note: &Note, database: &str) -> Result<Self, String> {
+        let id = note.get_id().unwrap_or_default();
+
+        let drafts = Xdg::state_dir()
+            .map(|dir| dir.join(ExternalEditor::DRAFTS_DIR))
+            .ok_or_else(|| String::from("Could not find a private directory for the temporary file, set $HOME"))?;
+        let database = format!("{:x}", Sha256::digest(database.as_bytes()));
+
+        Ok(ExternalEditor {
+            id,
+            original: NoteDraft {
+                title: note.get_title().clone(),
+                tags: note.get_tags().iter().cloned().collect(),
+                content: note.get_content().clone(),
+            },
+            path: drafts.join(format!("note-{}-{}.md", &database[..16], id)),
+        })
+    }
+
+    /// Returns the path of the temporary file.
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Tells whether a previous edition of the same note left its file behind,
+    /// because it could not be read or saved.
+    pub fn has_leftover(&self) -> bool {
+        self.path.is_file()
+    }
+
+    /// Writes the note to the temporary file, replacing any previous edition.
+    ///
+    /// The directory is created readable only by the user, and the file is always created anew
+    /// with the same permissions, so a file or link left in its place is never written through.
+    ///
+    /// # Errors
+    /// Returns an error message if the file cannot be written.
+    pub fn write(&self) -> Result<(), String> {
+        self.write_private(&ExternalEditor::render(self.id, &self.original)).map_err(|err| {
+            format!("Could not write the temporary file {}: {}", self.path.display(), err)
+        })
+    }
+
+    fn write_private(&self, text: &str) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            let mut builder = DirBuilder::new();
+            builder.recursive(true);
+            #[cfg(unix)]
+            builder.mode(0o700);
+            builder.create(dir)?;
+
+            // The directory may come from an older version, created with the default permissions
+            #[cfg(unix)]
+            fs::set_permissions(dir, fs::Permissions::from_mode(0o700))?;
+        }
+
+        match fs::remove_file(&self.path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+
+        options.open(&self.path)?.write_all(text.as_bytes())
+    }
+
+    /// Launches the editor on the temporary file, waits for it and reads the result back.
+    /// The file must have been written first, see `write` and `has_leftover`.
+    ///
+    /// # Returns
+    /// - `Ok(EditorOutcome)` telling whether the note changed.
+    /// - `Err(String)` if the editor could not run or the file cannot be read;
+    ///   the file is kept so it can be fixed by opening the editor again.
+    pub fn open(&self) -> Result<EditorOutcome, String> {
+        let command = std::env::var("VISUAL")
+            .ok()
+            .filter(|editor| !editor.trim().is_empty())
+            .or_else(|| std::env::var("EDITOR").ok().filter(|editor| !editor.trim().is_empty()))
+            .unwrap_or_else(|| String::from(ExternalEditor::FALLBACK_EDITOR));
+
+        // The variables may carry arguments, e.g. `code --wait`
+        let mut parts = command.split_whitespace();
+        let program = parts.next().unwrap_or(ExternalEditor::FALLBACK_EDITOR);
+
+        let status = Command::new(program)
+            .args(parts)
+            .arg(&self.path)
+            .status()
+            .map_err(|err| format!("Could not launch the editor `{}`: {}", command, err))?;
+
+        if !status.success() {
+            return Err(format!("The editor `{}` exited with {}", command, status));
+        }
+
+        let text = fs::read_to_string(&self.path).map_err(|err| {
+            format!("Could not read the temporary file {}: {}", self.path.display(), err)
+        })?;
+        let draft = ExternalEditor::parse(&text)?;
+
+        if self.is_unchanged(&draft) {
+            return Ok(EditorOutcome::Unchanged);
+        }
+
+        Ok(EditorOutcome::Edited(draft))
+    }
+
+    /// Removes the temporary file once the edits are saved or no longer wanted.
+    pub fn discard(&self) {
+        let _ = fs::remove_file(&self.path);
+    }
+
+    /// Tells whether the draft holds the same title, tags and content as the note,
+    /// comparing the tags once normalized.
+    fn is_unchanged(&self, draft: &NoteDraft) -> bool {
+        let tags: Result<BTreeSet<String>, _> = draft.tags.iter().map(|tag| Tag::normalize(tag)).collect();
+        let original_tags: BTreeSet<String> = self.original.tags.iter().cloned().collect();
+
+        draft.title == self.original.title
+            && draft.content == self.original.content
+            && tags.map(|tags| tags == original_tags).unwrap_or(false)
+    }
+
+    /// Writes a note as a front-matter header followed by its content.
+    fn render(id: i64, draft: &NoteDraft) -> String {
+        format!(
+            "{delimiter}\nid: {}\ntitle: {}\ntags: {}\n{delimiter}\n\n{}\n",
+            id,
+            draft.title,
+            draft.tags.join(", "),
+            draft.content,
+            delimiter = ExternalEditor::DELIMITER
+        )
+    }
+
+    /// Reads a note back from its front-matter header and content.
+    ///
+    /// The `id` line is informative and ignored. Tags can be separated by commas or spaces,
+    /// optionally wrapped in `[` `]`. The blank line after the header and the line break
+    /// `render` writes after the content are dropped, so a content ending in a line break keeps it.
+    ///
+    /// # Errors
+    /// Returns an error message if the header is missing, not closed or has unknown fields.
+    fn parse(text: &str) -> Result<NoteDraft, String> {
+        let text = text.replace("\r\n", "\n");
+        let mut lines = text.split('\n');
+
+        if lines.next().map(str::trim) != Some(ExternalEditor::DELIMITER) {
+            return Err(format!(
+                "The file must start with a `{}` line followed by the title and tags",
+                ExternalEditor::DELIMITER
+            ));
+        }
+
+        let mut title: Option<String> = None;
+        let mut tags: Vec<String> = Vec::new();
+        let mut closed = false;
+
+        for line in lines.by_ref() {
+            if line.trim() == ExternalEditor::DELIMITER {
+                closed = true;
+                break;
+            }
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("The header line `{}` is not a `key: value` pair", line))?;
+
+            match key.trim() {
+                "id" => {}
+                "title" => title = Some(value.trim().to_string()),
+                "tags" => {
+                    tags = value
+                        .trim()
+                        .trim_start_matches('[')
+                        .trim_end_matches(']')
+                        .split(|c: char| c == ',' || c.is_whitespace())
+                        .map(str::trim)
+                        .filter(|tag| !tag.is_empty())
+                        .map(String::from)
+                        .collect();
+                }
+                other => return Err(format!("Unknown header field `{}`, use title and tags", other)),
+            }
+        }
+
+        if !closed {
+            return Err(format!(
+                "The header is not closed, add a `{}` line after the tags",
+                ExternalEditor::DELIMITER
+            ));
+        }
+
+        let content: Vec<&str> = lines.collect();
+        let content = content.join("\n");
+        let content = content.strip_prefix('\n').unwrap_or(&content);
+        let content = content.strip_suffix('\n').unwrap_or(content).to_string();
+
+        Ok(NoteDraft {
+            title: title.ok_or_else(|| String::from("The header has no `title` field"))?,
+            tags,
+            content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft(content: &str) -> NoteDraft {
+        NoteDraft {
+            title: String::from("Meeting"),
+            tags: vec![String::from("work"), String::from("planning")],
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn reads_back_what_it_renders() {
+        let original = draft("Review the plan");
+
+        assert_eq!(ExternalEditor::parse(&ExternalEditor::render(3, &original)), Ok(original));
+    }
+
+    #[test]
+    fn keeps_the_trailing_line_break_of_the_content() {
+        let original = draft("Review the plan\n");
+
+        assert_eq!(ExternalEditor::parse(&ExternalEditor::render(3, &original)), Ok(original));
+    }
+
+    #[test]
+    fn keeps_an_empty_content() {
+        let original = draft("");
+
+        assert_eq!(ExternalEditor::parse(&ExternalEditor::render(3, &original)), Ok(original));
+    }
+}
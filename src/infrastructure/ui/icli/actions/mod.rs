@@ -1 +1,3 @@
-pub mod notes;
\ No newline at end of file
+pub mod notebooks;
+pub mod notes;
+pub mod tags;
\ No newline at end of file
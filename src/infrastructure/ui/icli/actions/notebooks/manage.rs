@@ -0,0 +1,146 @@
+use crate::{
+    application::use_cases::{
+        notebooks::{
+            create::CreateNotebook, delete::DeleteNotebook, find_notes::FindNotesInNotebook,
+            move_note::MoveNote,
+        },
+    },
+    domain::entities::notebook::NotebookDeletion,
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::{error_renderer::ErrorRenderer, notebook_navigator::NotebookNavigator},
+};
+use inquire::{Confirm, Select, Text};
+
+/// Represents the action of organizing notebooks (create, move notes, delete) through the CLI.
+pub struct ManageNotebooksAction;
+
+impl ManageNotebooksAction {
+    /// Executes the process of managing notebooks.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    /// - `settings`: The settings of the application.
+    ///
+    /// # Returns
+    /// - `true` if the notebooks were changed.
+    /// - `false` if the process was canceled or an error occurred.
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings) -> bool {
+
+        let options = vec!["Create a notebook", "Move a note", "Delete a notebook"];
+        match Select::new("What do you want to do?", options).prompt() {
+            Ok("Create a notebook") => ManageNotebooksAction::create(note_repository),
+            Ok("Move a note") => ManageNotebooksAction::move_note(note_repository),
+            Ok(_) => ManageNotebooksAction::delete(note_repository, settings),
+            Err(_) => false,
+        }
+    }
+
+    /// Asks for the location and name of a new notebook, then creates it.
+    fn create(note_repository: &SqliteNoteRepository) -> bool {
+        let locations = vec!["At the top level", "Inside another notebook"];
+        let parent_id = match Select::new("Where?", locations).prompt() {
+            Ok("At the top level") => None,
+            Ok(_) => match NotebookNavigator::pick(note_repository, "Parent notebook:") {
+                Some(parent) => parent.get_id(),
+                None => return false,
+            },
+            Err(_) => return false,
+        };
+
+        loop {
+            let name = match Text::new("Name:").prompt() {
+                Ok(name) => name,
+                Err(_) => return false,
+            };
+
+            match CreateNotebook::new(note_repository).execute(&name, parent_id) {
+                Ok(_) => return true,
+                Err(err) if err.is_validation() => ErrorRenderer::render(&err),
+                Err(err) => {
+                    ErrorRenderer::render(&err);
+                    return false;
+                }
+            }
+        }
+    }
+
+    /// Asks for a note and its destination notebook, then moves it.
+    fn move_note(note_repository: &SqliteNoteRepository) -> bool {
+        let source = match NotebookNavigator::pick(note_repository, "Move from:") {
+            Some(source) => source,
+            None => return false,
+        };
+
+        let notes = match FindNotesInNotebook::new(note_repository).execute(source.get_id().unwrap_or_default()) {
+            Ok(notes) => notes,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        // Map notes into a vector of formatted strings for the Select component
+        let options: Vec<String> = notes
+            .iter()
+            .map(|note| format!("{} - {}", note.id.unwrap_or_default(), note.title))
+            .collect();
+
+        let selected_note = match Select::new("Select a note to move:", options).prompt() {
+            Ok(selection) => selection,
+            Err(_) => return false,
+        };
+
+        // Extract the id from the selected note
+        let id: i64 = match selected_note.split(" - ").next().unwrap_or_default().parse() {
+            Ok(parsed_id) => parsed_id,
+            Err(_) => return false,
+        };
+
+        let destination = match NotebookNavigator::pick(note_repository, "Move to:") {
+            Some(destination) => destination,
+            None => return false,
+        };
+
+        match MoveNote::new(note_repository, note_repository)
+            .execute(id, destination.get_id().unwrap_or_default())
+        {
+            Ok(()) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
+        }
+    }
+
+    /// Asks for a notebook and what to do with its content, then deletes it.
+    fn delete(note_repository: &SqliteNoteRepository, settings: &Settings) -> bool {
+        let notebook = match NotebookNavigator::pick(note_repository, "Notebook to delete:") {
+            Some(notebook) => notebook,
+            None => return false,
+        };
+
+        let strategies = vec![
+            "Move its notes and sub-notebooks to the parent notebook",
+            "Delete its sub-notebooks too and move the notes to the trash",
+        ];
+        let strategy = match Select::new("What about its content?", strategies).prompt() {
+            Ok("Delete its sub-notebooks too and move the notes to the trash") => NotebookDeletion::Cascade,
+            Ok(_) => NotebookDeletion::Reparent,
+            Err(_) => return false,
+        };
+
+        let message = format!("Are you sure you want to delete the notebook `{}`?", notebook.get_name());
+        if !Confirm::new(&message).with_default(settings.confirm.delete).prompt().unwrap_or(false) {
+            return false;
+        }
+
+        match DeleteNotebook::new(note_repository).execute(notebook.get_id().unwrap_or_default(), strategy) {
+            Ok(_) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
+        }
+    }
+}
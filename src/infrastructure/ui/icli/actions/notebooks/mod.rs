@@ -0,0 +1 @@
+pub mod manage;
\ No newline at end of file
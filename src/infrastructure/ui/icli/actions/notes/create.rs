@@ -1,10 +1,17 @@
 use crate::{
-    application::use_cases::notes::create::CreateNewNote,
-    domain::repositories::note_repository::NoteRepository,
+    application::palette::Palette,
+    application::use_cases::{notes::create::CreateNewNote, tags::find_all::FindAllTags},
+    domain::{
+        entities::{note::Note, notebook::Notebook, tag::Tag},
+        errors::note_error::NoteError,
+        repositories::{note_repository::NoteRepository, tag_repository::TagRepository},
+    },
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::{error_renderer::ErrorRenderer, notebook_navigator::NotebookNavigator},
 };
-use ansi_term::Colour;
 use inquire::{
-    Confirm, Editor, Text,
+    Confirm, Editor, MultiSelect, Text,
     ui::{Color, RenderConfig, Styled},
 };
 
@@ -14,62 +21,155 @@ pub struct CreateNoteAction;
 impl CreateNoteAction {
     /// Executes the process of creating a new note.
     ///
-    /// This method prompts the user for the note's title and content, confirms the action,
+    /// This method prompts the user for the note's title, content, tags and notebook, confirms the action,
     /// and then attempts to save the note using the application logic.
+    /// If the title or content are not valid the problem is explained and the user is asked again,
+    /// keeping what was already written.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    /// - `settings`: The settings of the application.
     ///
     /// # Returns
     /// - `true` if the note was successfully created.
     /// - `false` if the process was canceled or an error occurred.
-    pub fn execute() -> bool {
-        let title = Text::new("Title:").prompt();
-        let title = match title {
-            Ok(title) => title,
-            Err(_) => {
-                println!(
-                    "{} An error occurred when asking for the title, try again later",
-                    Colour::Red.paint(">")
-                );
-
-                return false;
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings) -> bool {
+        let mut title = String::new();
+        let mut content = String::new();
+
+        loop {
+            title = match Text::new("Title:").with_initial_value(&title).prompt() {
+                Ok(title) => title,
+                Err(_) => {
+                    println!(
+                        "{} An error occurred when asking for the title, try again later",
+                        Palette::current().error(">")
+                    );
+
+                    return false;
+                }
+            };
+
+            if let Err(err) = Note::validate_title(&title, &settings.limits) {
+                ErrorRenderer::render(&err);
+                continue;
             }
-        };
 
-        let content = Editor::new("Content:")
-            .with_formatter(&|submission| {
-                let char_count = submission.chars().count();
-                if char_count == 0 {
-                    String::from("<skipped>")
-                } else if char_count <= 20 {
-                    submission.into()
-                } else {
-                    let mut substr: String = submission.chars().take(17).collect();
-                    substr.push_str("...");
-                    substr
+            break;
+        }
+
+        let preview_length = settings.display.preview_length;
+
+        loop {
+            let prompt = Editor::new("Content:")
+                .with_predefined_text(&content)
+                .with_formatter(&|submission| {
+                    let char_count = submission.chars().count();
+                    if char_count == 0 {
+                        String::from("<skipped>")
+                    } else if char_count <= preview_length {
+                        submission.into()
+                    } else {
+                        let mut substr: String = submission.chars().take(preview_length - 3).collect();
+                        substr.push_str("...");
+                        substr
+                    }
+                })
+                .with_render_config(CreateNoteAction::description_render_config())
+                .prompt();
+
+            content = match prompt {
+                Ok(content) => content,
+                Err(_) => {
+                    println!(
+                        "{} An error occurred when asking for the content, try again later",
+                        Palette::current().error(">")
+                    );
+                    return false;
                 }
-            })
-            .with_render_config(CreateNoteAction::description_render_config())
-            .prompt();
-
-        let content = match content {
-            Ok(content) => content,
-            Err(_) => {
-                println!(
-                    "{} An error occurred when asking for the content, try again later",
-                    Colour::Red.paint(">")
-                );
-                return false;
+            };
+
+            if let Err(err) = Note::validate_content(&content, &settings.limits) {
+                ErrorRenderer::render(&err);
+                continue;
             }
+
+            break;
+        }
+
+        let tags = match CreateNoteAction::pick_tags(note_repository) {
+            Some(tags) => tags,
+            None => return false,
+        };
+
+        let notebook = match NotebookNavigator::pick(note_repository, "Notebook:") {
+            Some(notebook) => notebook,
+            None => return false,
         };
 
-        let confirm = Confirm::new("Save").with_default(false).prompt().unwrap();
+        let confirm = Confirm::new("Save").with_default(settings.confirm.save).prompt().unwrap_or(false);
 
         if !confirm {
             return false;
         }
 
-        CreateNoteAction::create_note(&title, &content);
+        let notebook_id = notebook.get_id().unwrap_or(Notebook::INBOX_ID);
+        CreateNoteAction::create_note(note_repository, settings, &title, &content, &tags, notebook_id)
+    }
+
+    /// Lets the user pick the tags of the new note.
+    ///
+    /// Existing tags are offered in a multi-select list and new ones can be typed
+    /// separated by commas. Invalid tag names are explained and asked again.
+    ///
+    /// # Arguments
+    /// - `tag_repository`: The repository used to list the existing tags.
+    ///
+    /// # Returns
+    /// - `Some(Vec<String>)` with the chosen tag names, possibly empty.
+    /// - `None` if the prompt was canceled.
+    fn pick_tags(tag_repository: &dyn TagRepository) -> Option<Vec<String>> {
+        let existing: Vec<String> = FindAllTags::new(tag_repository)
+            .execute()
+            .map(|tags| tags.iter().map(|tag| tag.get_name().clone()).collect())
+            .unwrap_or_default();
+
+        let mut tags: Vec<String> = if existing.is_empty() {
+            Vec::new()
+        } else {
+            MultiSelect::new("Tags:", existing)
+                .with_help_message("Space to select, enter to continue")
+                .prompt_skippable()
+                .ok()?
+                .unwrap_or_default()
+        };
 
-        return true;
+        loop {
+            let new_tags = Text::new("New tags:")
+                .with_help_message("Comma separated, leave empty to skip")
+                .prompt_skippable()
+                .ok()?
+                .unwrap_or_default();
+
+            let parsed: Result<Vec<String>, NoteError> = new_tags
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(Tag::normalize)
+                .collect();
+
+            match parsed {
+                Ok(parsed) => {
+                    for tag in parsed {
+                        if !tags.contains(&tag) {
+                            tags.push(tag);
+                        }
+                    }
+                    return Some(tags);
+                }
+                Err(err) => ErrorRenderer::render(&err),
+            }
+        }
     }
 
     /// Creates a new note using the provided title and content.
@@ -78,14 +178,31 @@ impl CreateNoteAction {
     /// to persist the note.
     ///
     /// # Arguments
+    /// - `note_repository`: The repository where the note is stored.
+    /// - `settings`: The settings of the application.
     /// - `title`: The title of the note.
     /// - `content`: The content of the note.
-    fn create_note(title: &String, content: &String) -> () {
-        let note_repository: NoteRepository = NoteRepository::new();
-        let create_use_case: CreateNewNote = CreateNewNote::new(&note_repository);
+    /// - `tags`: The names of the tags attached to the note.
+    /// - `notebook_id`: The ID of the notebook where the note is stored.
+    ///
+    /// # Returns
+    /// - `true` if the note was saved, `false` otherwise.
+    fn create_note(
+        note_repository: &dyn NoteRepository,
+        settings: &Settings,
+        title: &String,
+        content: &String,
+        tags: &[String],
+        notebook_id: i64,
+    ) -> bool {
+        let create_use_case: CreateNewNote = CreateNewNote::new(note_repository, settings.limits);
 
-        if let Err(err) = create_use_case.execute(&title, &content) {
-            println!("{} Failed to create note: {}", Colour::Red.paint(">"), err);
+        match create_use_case.execute(&title, &content, tags, notebook_id) {
+            Ok(_) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
         }
     }
 
@@ -1,10 +1,13 @@
 use crate::{
+    application::use_cases::notebooks::find_notes::FindNotesInNotebook,
     application::use_cases::notes::{
         create::CreateNewNote,
         delete::{self, DeletedNote},
         find_all::{self, FindAll},
     },
-    domain::repositories::note_repository::NoteRepository,
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::{error_renderer::ErrorRenderer, notebook_navigator::NotebookNavigator},
 };
 use ansi_term::Colour;
 use inquire::{
@@ -15,12 +18,23 @@ use inquire::{
 pub struct DeletedNoteAction;
 
 impl DeletedNoteAction {
-    pub fn execute() -> bool {
-        let note_repository: NoteRepository = NoteRepository::new();
-        let find_all = FindAll::new(&note_repository);
-        let notes = match find_all.execute() {
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings) -> bool {
+
+        let modes: Vec<&str> = vec!["All notes", "Browse notebooks"];
+        let result = match Select::new("Where is the note?", modes).prompt() {
+            Ok("All notes") => FindAll::new(note_repository).execute(),
+            Ok(_) => match NotebookNavigator::pick(note_repository, "Notebook:") {
+                Some(notebook) => FindNotesInNotebook::new(note_repository)
+                    .execute(notebook.get_id().unwrap_or_default()),
+                None => return false,
+            },
+            Err(_) => return false,
+        };
+
+        let notes = match result {
             Ok(notes) => notes,
-            Err(e) => {
+            Err(err) => {
+                ErrorRenderer::render(&err);
                 return false;
             }
         };
@@ -51,16 +65,17 @@ impl DeletedNoteAction {
             }
         };
 
-        let message: String = format!("Are you sure you want to delete the note with ID: {}", id);
-        let confirm = Confirm::new(&message).with_default(false).prompt().unwrap();
+        let message: String = format!("Move the note with ID {} to the trash?", id);
+        let confirm = Confirm::new(&message).with_default(settings.confirm.delete).prompt().unwrap_or(false);
 
         if !confirm {
             return false;
         }
 
-        let delete_note = DeletedNote::new(&note_repository);
+        let delete_note = DeletedNote::new(note_repository);
         if let Err(err) = delete_note.execute(id) {
-            println!("{} Failed to delete note: {}", Colour::Red.paint(">"), err);
+            ErrorRenderer::render(&err);
+            return false;
         }
 
         return true;
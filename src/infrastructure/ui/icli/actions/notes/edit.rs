@@ -0,0 +1,181 @@
+use crate::{
+    application::palette::Palette,
+    application::use_cases::notes::update::UpdateNote,
+    domain::{entities::note::Note, errors::note_error::NoteError},
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::{
+        external_editor::{EditorOutcome, ExternalEditor, NoteDraft},
+        icli::error_renderer::ErrorRenderer,
+    },
+};
+use inquire::Confirm;
+use similar::{ChangeTag, TextDiff};
+
+/// How the edition of a note ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOutcome {
+    /// The changes were saved
+    Saved,
+
+    /// The note was left as it was
+    Unchanged,
+
+    /// The user gave up; unsaved edits are kept in the temporary file
+    Canceled,
+}
+
+/// Represents the action of editing the title, tags and content of a note in the user's editor.
+pub struct EditNoteAction;
+
+impl EditNoteAction {
+    /// Executes the process of editing a note.
+    ///
+    /// The note is opened in `$VISUAL` / `$EDITOR` through a temporary file, see `ExternalEditor`.
+    /// Once the editor is closed the changes are shown and, once confirmed, saved.
+    /// If the file cannot be read or the note cannot be saved, the problem is explained and the
+    /// editor can be opened again on the same file; otherwise the file is kept and its path shown,
+    /// and the next edition of the note offers to resume it. Locked notes cannot be edited.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    /// - `settings`: The settings of the application.
+    /// - `note`: The note to edit, as currently stored.
+    ///
+    /// # Returns
+    /// How the edition ended, see `EditOutcome`.
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings, note: &Note) -> EditOutcome {
+        // The temporary file would hold the plain content of a locked note
+        if note.is_locked() {
+            ErrorRenderer::render(&NoteError::Locked(note.get_id().unwrap_or_default()));
+            return EditOutcome::Canceled;
+        }
+
+        let database = note_repository.connection().path().unwrap_or_default();
+        let editor = match ExternalEditor::for_note(note, database) {
+            Ok(editor) => editor,
+            Err(err) => {
+                println!("{} {}", Palette::current().error(">"), err);
+                return EditOutcome::Canceled;
+            }
+        };
+
+        let resume = editor.has_leftover()
+            && Confirm::new("This note has unsaved edits from a previous session, resume them?")
+                .with_help_message(&editor.get_path().display().to_string())
+                .with_default(true)
+                .prompt()
+                .unwrap_or(false);
+
+        if !resume {
+            if let Err(err) = editor.write() {
+                println!("{} {}", Palette::current().error(">"), err);
+                return EditOutcome::Canceled;
+            }
+        }
+
+        loop {
+            let draft = match editor.open() {
+                Ok(EditorOutcome::Unchanged) => {
+                    editor.discard();
+                    println!("{} No changes to save", Palette::current().warning(">"));
+                    return EditOutcome::Unchanged;
+                }
+                Ok(EditorOutcome::Edited(draft)) => draft,
+                Err(err) => {
+                    println!("{} {}", Palette::current().error(">"), err);
+                    if EditNoteAction::edit_again(&editor) {
+                        continue;
+                    }
+                    return EditOutcome::Canceled;
+                }
+            };
+
+            EditNoteAction::render_diff(note, &draft);
+
+            let confirm = Confirm::new("Save")
+                .with_default(settings.confirm.save)
+                .prompt()
+                .unwrap_or(false);
+
+            if !confirm {
+                editor.discard();
+                return EditOutcome::Canceled;
+            }
+
+            let update_note = UpdateNote::new(note_repository, settings.limits);
+            let id = note.get_id().unwrap_or_default();
+
+            match update_note.execute_with_tags(id, &draft.title, &draft.content, &draft.tags) {
+                Ok(_) => {
+                    editor.discard();
+                    return EditOutcome::Saved;
+                }
+                Err(err) => {
+                    ErrorRenderer::render(&err);
+                    if EditNoteAction::edit_again(&editor) {
+                        continue;
+                    }
+                    return EditOutcome::Canceled;
+                }
+            }
+        }
+    }
+
+    /// Asks whether to open the editor again after a problem.
+    /// When the answer is no, tells the user where the edits were kept.
+    fn edit_again(editor: &ExternalEditor) -> bool {
+        let again = Confirm::new("Open the editor again?")
+            .with_default(true)
+            .prompt()
+            .unwrap_or(false);
+
+        if !again {
+            println!(
+                "{} Your edits are kept in {}",
+                Palette::current().warning(">"),
+                Palette::current().accent(editor.get_path().display().to_string())
+            );
+        }
+
+        again
+    }
+
+    /// Prints the differences between the stored note and the edited values.
+    ///
+    /// Removed lines are shown in red prefixed with `-` and added lines in green prefixed with `+`.
+    ///
+    /// # Arguments
+    /// - `note`: The note as currently stored.
+    /// - `draft`: The edited values.
+    fn render_diff(note: &Note, draft: &NoteDraft) {
+        println!("{} Changes:", Palette::current().success(">"));
+
+        if &draft.title != note.get_title() {
+            println!("  Title:");
+            println!("{}", Palette::current().error(format!("  - {}", note.get_title())));
+            println!("{}", Palette::current().success(format!("  + {}", draft.title)));
+        }
+
+        let tags: Vec<String> = note.get_tags().iter().cloned().collect();
+        if draft.tags != tags {
+            println!("  Tags:");
+            println!("{}", Palette::current().error(format!("  - {}", tags.join(", "))));
+            println!("{}", Palette::current().success(format!("  + {}", draft.tags.join(", "))));
+        }
+
+        if &draft.content != note.get_content() {
+            println!("  Content:");
+
+            let diff = TextDiff::from_lines(note.get_content().as_str(), draft.content.as_str());
+            for change in diff.iter_all_changes() {
+                let line = change.value().trim_end_matches('\n');
+                match change.tag() {
+                    ChangeTag::Delete => println!("{}", Palette::current().error(format!("  - {}", line))),
+                    ChangeTag::Insert => println!("{}", Palette::current().success(format!("  + {}", line))),
+                    ChangeTag::Equal => println!("    {}", line),
+                }
+            }
+        }
+    }
+}
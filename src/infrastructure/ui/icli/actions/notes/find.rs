@@ -1,47 +1,38 @@
 use crate::{
-    FindByTitle,
-    application::use_cases::notes::find_by_id::{self, FindById},
-    domain::repositories::note_repository::NoteRepository,
-};
-use ansi_term::Colour;
-use inquire::{
-    Confirm, Editor, Select, Text,
-    ui::{Color, RenderConfig, Styled},
+    application::palette::Palette,
+    application::use_cases::{
+        notebooks::{find_all::FindAllNotebooks, find_notes::FindNotesInNotebook},
+        notes::{find_by_id::FindById, search::SearchNotes},
+    },
+    domain::{entities::search_result::SearchResult, repositories::note_repository::NoteRepository},
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::{
+        actions::notes::{history::HistoryAction, lock::LockNoteAction},
+        error_renderer::ErrorRenderer,
+        notebook_navigator::NotebookNavigator,
+    },
+    infrastructure::ui::viewer::NoteViewer,
 };
+use inquire::{Confirm, Select, Text};
 
 pub struct FindNoteAction;
 
 impl FindNoteAction {
-    pub fn execute() -> bool {
-        let note_repository: NoteRepository = NoteRepository::new();
-        let find_by_title = FindByTitle::new(&note_repository);
-        let find_by_id = FindById::new(&note_repository);
-
-        let title = Text::new("Title:").prompt();
-        let title = match title {
-            Ok(title) => title,
-            Err(_) => {
-                return false;
-            }
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings) -> bool {
+        let find_by_id = FindById::new(note_repository);
+
+        let modes: Vec<&str> = vec!["Search all notes", "Browse notebooks"];
+        let options = match Select::new("How do you want to find the note?", modes).prompt() {
+            Ok("Search all notes") => FindNoteAction::search(note_repository),
+            Ok(_) => FindNoteAction::browse(note_repository),
+            Err(_) => None,
         };
 
-        let result = find_by_title.execute(&title);
-
-        if let Err(err) = result {
-            return false;
-        }
-
-        let notes = result.unwrap();
-
-        if notes.is_empty() {
-            return false;
-        }
-
-        // Map notes into a vector of formatted strings for the Select component
-        let options: Vec<String> = notes
-            .iter()
-            .map(|note| format!("{} - {}", note.id.unwrap_or_default(), note.title))
-            .collect();
+        let options = match options {
+            Some(options) => options,
+            None => return false,
+        };
 
         // Prompt the user to select a note
         let selected_note = Select::new("Select a note:", options).prompt();
@@ -66,29 +57,126 @@ impl FindNoteAction {
         // Execute the find by ID use case
         let current_note = find_by_id.execute(id);
         if let Err(err) = current_note {
+            ErrorRenderer::render(&err);
             return false;
         }
 
-        let note = current_note.unwrap();
+        // A locked note is shown only once its passphrase is given
+        let Some(note) = LockNoteAction::reveal(note_repository, current_note.unwrap()) else {
+            return false;
+        };
+
+        // Display the note rendered as Markdown
+        let notebooks = FindAllNotebooks::new(note_repository).execute().unwrap_or_default();
+        NoteViewer::show(&note, &notebooks);
 
-        // Display note content on inquire Editor
-        let content = Editor::new("Content:")
-            .with_render_config(FindNoteAction::description_render_config())
-            .with_predefined_text(&note.content)
-            .prompt();
+        let next_steps: Vec<&str> = vec!["Done", "History"];
+        if let Ok("History") = Select::new("What next?", next_steps).prompt() {
+            HistoryAction::execute(note_repository, settings, &note);
+        }
 
         return true;
     }
 
-    /// Provides a custom render configuration for the description editor.
+    /// Runs a full-text search and prints every match with its highlighted snippet.
     ///
-    /// This configuration customizes the appearance of the editor's prompt
-    /// when the user cancels the input.
+    /// # Arguments
+    /// - `note_repository`: The repository used to search the notes.
     ///
     /// # Returns
-    /// A `RenderConfig` instance with the desired customization.
-    fn description_render_config() -> RenderConfig<'static> {
-        RenderConfig::default()
-            .with_canceled_prompt_indicator(Styled::new("<skipped>").with_fg(Color::DarkYellow))
+    /// - `Some(Vec<String>)` with the matching notes formatted as `{id} - {title}`.
+    /// - `None` if the prompt was canceled or the search failed.
+    fn search(note_repository: &dyn NoteRepository) -> Option<Vec<String>> {
+        let search_notes = SearchNotes::new(note_repository);
+
+        let results = loop {
+            let query = Text::new("Search:")
+                .with_help_message("Use \"exact phrases\", prefix* and AND / OR / NOT")
+                .prompt()
+                .ok()?;
+
+            match search_notes.execute(&query) {
+                Ok(results) => break results,
+                Err(err) if err.is_validation() => ErrorRenderer::render(&err),
+                Err(err) => {
+                    ErrorRenderer::render(&err);
+                    return None;
+                }
+            }
+        };
+
+        // Show every match together with the highlighted fragment of its content
+        for result in &results {
+            println!(
+                "{} {} - {}",
+                Palette::current().success(">"),
+                Palette::current().accent(result.note.id.unwrap_or_default().to_string()),
+                result.note.title
+            );
+            println!("    {}", FindNoteAction::render_snippet(&result.snippet));
+        }
+
+        // Map notes into a vector of formatted strings for the Select component
+        Some(
+            results
+                .iter()
+                .map(|result| format!("{} - {}", result.note.id.unwrap_or_default(), result.note.title))
+                .collect(),
+        )
+    }
+
+    /// Lets the user navigate the notebook tree and lists the notes of the chosen notebook.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository used to read the notebooks and notes.
+    ///
+    /// # Returns
+    /// - `Some(Vec<String>)` with the notes of the notebook formatted as `{id} - {title}`.
+    /// - `None` if the prompt was canceled or the notebook has no notes.
+    fn browse(note_repository: &SqliteNoteRepository) -> Option<Vec<String>> {
+        let notebook = NotebookNavigator::pick(note_repository, "Notebook:")?;
+
+        match FindNotesInNotebook::new(note_repository).execute(notebook.get_id()?) {
+            Ok(notes) => Some(
+                notes
+                    .iter()
+                    .map(|note| format!("{} - {}", note.id.unwrap_or_default(), note.title))
+                    .collect(),
+            ),
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                None
+            }
+        }
+    }
+
+    /// Turns a search snippet into a single line with the matched terms highlighted.
+    ///
+    /// # Arguments
+    /// - `snippet`: The snippet returned by the search, including the highlight markers.
+    ///
+    /// # Returns
+    /// The snippet ready to be printed in the terminal.
+    fn render_snippet(snippet: &str) -> String {
+        let mut rendered = String::new();
+        let mut highlighted = String::new();
+        let mut in_highlight = false;
+
+        for c in snippet.chars() {
+            match c {
+                SearchResult::HIGHLIGHT_START => in_highlight = true,
+                SearchResult::HIGHLIGHT_END => {
+                    rendered.push_str(&Palette::current().highlight(highlighted.as_str()).to_string());
+                    highlighted.clear();
+                    in_highlight = false;
+                }
+                '\n' | '\r' | '\t' => rendered.push(' '),
+                c if in_highlight => highlighted.push(c),
+                c => rendered.push(c),
+            }
+        }
+
+        rendered.push_str(&highlighted);
+        rendered
     }
 }
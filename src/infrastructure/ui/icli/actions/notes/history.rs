@@ -0,0 +1,151 @@
+use crate::{
+    application::palette::Palette,
+    application::use_cases::revisions::{
+        diff::DiffRevisions, find_all::FindRevisions, restore::RestoreRevision,
+    },
+    domain::entities::{note::Note, revision::Revision},
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::error_renderer::ErrorRenderer,
+};
+use chrono::prelude::*;
+use inquire::{Confirm, Select};
+
+/// Represents the action of reviewing the revision history of a note through the CLI.
+pub struct HistoryAction;
+
+impl HistoryAction {
+    /// Executes the process of reviewing the history of a note.
+    ///
+    /// This method lists the revisions of the note, most recent first, and lets the user
+    /// see what changed in a revision, compare it with any other revision, or restore it.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository holding the note and its revisions.
+    /// - `settings`: The settings of the application.
+    /// - `note`: The note whose history is shown.
+    ///
+    /// # Returns
+    /// - `true` if the note was restored to an earlier revision.
+    /// - `false` otherwise.
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings, note: &Note) -> bool {
+        let note_id = note.get_id().unwrap_or_default();
+
+        let revisions = match FindRevisions::new(note_repository).execute(note_id) {
+            Ok(revisions) => revisions,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        let options: Vec<String> = revisions
+            .iter()
+            .enumerate()
+            .map(|(index, revision)| HistoryAction::describe(revision, index == 0))
+            .collect();
+
+        let selected = match Select::new("Revisions:", options).raw_prompt() {
+            Ok(selected) => selected,
+            Err(_) => return false,
+        };
+        let revision = &revisions[selected.index];
+
+        let actions = vec![
+            "Show what changed in this revision",
+            "Compare with another revision",
+            "Restore this revision",
+        ];
+        match Select::new("What do you want to do?", actions).prompt() {
+            Ok("Show what changed in this revision") => {
+                // Revisions are sorted from the most recent, so the previous one comes next
+                match revisions.get(selected.index + 1) {
+                    Some(previous) => HistoryAction::show_diff(note_repository, previous.get_id(), revision.get_id()),
+                    None => println!("{} This is the first revision of the note", Palette::current().warning(">")),
+                }
+                false
+            }
+            Ok("Compare with another revision") => {
+                let others: Vec<(usize, String)> = revisions
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| *index != selected.index)
+                    .map(|(index, other)| (index, HistoryAction::describe(other, index == 0)))
+                    .collect();
+
+                if others.is_empty() {
+                    println!("{} The note has no other revision", Palette::current().warning(">"));
+                    return false;
+                }
+
+                let labels: Vec<String> = others.iter().map(|(_, label)| label.clone()).collect();
+                if let Ok(other) = Select::new("Compare with:", labels).raw_prompt() {
+                    let other = &revisions[others[other.index].0];
+                    HistoryAction::show_diff(note_repository, revision.get_id(), other.get_id());
+                }
+                false
+            }
+            Ok(_) => {
+                if selected.index == 0 {
+                    println!("{} This revision is already the current version", Palette::current().warning(">"));
+                    return false;
+                }
+
+                let message = format!("Restore the note to revision {}?", revision.get_id());
+                if !Confirm::new(&message).with_default(settings.confirm.save).prompt().unwrap_or(false) {
+                    return false;
+                }
+
+                match RestoreRevision::new(note_repository, note_repository, settings.limits).execute(note_id, revision.get_id()) {
+                    Ok(_) => true,
+                    Err(err) => {
+                        ErrorRenderer::render(&err);
+                        false
+                    }
+                }
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Formats a revision for the selection lists, e.g. `#4 - 2025-03-01 10:20 - Title (current)`.
+    fn describe(revision: &Revision, current: bool) -> String {
+        format!(
+            "#{} - {} - {}{}",
+            revision.get_id(),
+            revision.get_created_at().with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+            revision.get_title(),
+            if current { " (current)" } else { "" }
+        )
+    }
+
+    /// Prints the unified diff between two revisions, coloring added and removed lines.
+    fn show_diff(note_repository: &SqliteNoteRepository, from_id: i64, to_id: i64) {
+        let diff = match DiffRevisions::new(note_repository).execute(from_id, to_id) {
+            Ok(diff) => diff,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return;
+            }
+        };
+
+        if diff.is_empty() {
+            println!("{} Both revisions are identical", Palette::current().warning(">"));
+            return;
+        }
+
+        for line in diff.lines() {
+            if line.starts_with("---") || line.starts_with("+++") {
+                println!("{}", Palette::current().bold(line));
+            } else if line.starts_with("@@") {
+                println!("{}", Palette::current().secondary(line));
+            } else if line.starts_with('+') {
+                println!("{}", Palette::current().success(line));
+            } else if line.starts_with('-') {
+                println!("{}", Palette::current().error(line));
+            } else {
+                println!("{}", line);
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    application::use_cases::notes::{
+        find_all::FindAll, find_by_id::FindById, lock::LockNote, reveal::RevealNote, unlock::UnlockNote,
+    },
+    domain::entities::note::Note,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::{icli::error_renderer::ErrorRenderer, passphrase::PassphrasePrompt},
+};
+use inquire::Select;
+
+/// Represents the action of locking a note with a passphrase, or unlocking a locked one, through the CLI.
+pub struct LockNoteAction;
+
+impl LockNoteAction {
+    /// Executes the process of locking or unlocking a note.
+    ///
+    /// The user picks a note from the full list, where locked notes are marked; a plain note
+    /// is locked with a passphrase asked for twice, and a locked note is unlocked with its passphrase.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    ///
+    /// # Returns
+    /// - `true` if the note was locked or unlocked.
+    /// - `false` if the process was canceled or an error occurred.
+    pub fn execute(note_repository: &SqliteNoteRepository) -> bool {
+        let notes = match FindAll::new(note_repository).execute() {
+            Ok(notes) if !notes.is_empty() => notes,
+            Ok(_) => return false,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        // Map notes into a vector of formatted strings for the Select component
+        let options: Vec<String> = notes
+            .iter()
+            .map(|note| {
+                let mark = if note.is_locked() { " (locked)" } else { "" };
+                format!("{} - {}{}", note.id.unwrap_or_default(), note.title, mark)
+            })
+            .collect();
+
+        let selected_note = match Select::new("Select a note to lock or unlock:", options).prompt() {
+            Ok(selection) => selection,
+            Err(_) => return false,
+        };
+
+        // Extract the id from the selected note
+        let id_str = selected_note.split(" - ").next().unwrap_or_default();
+
+        let id: i64 = match id_str.parse() {
+            Ok(parsed_id) => parsed_id,
+            Err(_) => {
+                return false;
+            }
+        };
+
+        let note = match FindById::new(note_repository).execute(id) {
+            Ok(note) => note,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        let result = if note.is_locked() {
+            let Some(passphrase) = PassphrasePrompt::ask(&note) else {
+                return false;
+            };
+            UnlockNote::new(note_repository).execute(id, &passphrase)
+        } else {
+            let Some(passphrase) = PassphrasePrompt::ask_new(&note) else {
+                return false;
+            };
+            LockNote::new(note_repository).execute(id, &passphrase)
+        };
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
+        }
+    }
+
+    /// Asks for the passphrase of a locked note and returns it decrypted, without saving it.
+    /// A note that is not locked is returned as it is.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository holding the note.
+    /// - `note`: The note about to be shown.
+    ///
+    /// # Returns
+    /// - `Some(Note)` with the content ready to be shown.
+    /// - `None` if the prompt was canceled or the passphrase is wrong, the problem is rendered.
+    pub fn reveal(note_repository: &SqliteNoteRepository, note: Note) -> Option<Note> {
+        if !note.is_locked() {
+            return Some(note);
+        }
+
+        let passphrase = PassphrasePrompt::ask(&note)?;
+        match RevealNote::new(note_repository).execute(note.get_id()?, &passphrase) {
+            Ok(note) => Some(note),
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                None
+            }
+        }
+    }
+}
@@ -1,3 +1,8 @@
 pub mod create;
 pub mod delete;
-pub mod find;
\ No newline at end of file
+pub mod edit;
+pub mod find;
+pub mod history;
+pub mod lock;
+pub mod trash;
+pub mod update;
\ No newline at end of file
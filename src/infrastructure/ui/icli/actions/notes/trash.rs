@@ -0,0 +1,102 @@
+use crate::{
+    application::palette::Palette,
+    application::use_cases::trash::{find_all::FindTrashedNotes, purge::PurgeNote, restore::RestoreNote},
+    domain::errors::note_error::NoteError,
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::error_renderer::ErrorRenderer,
+};
+use chrono::prelude::*;
+use inquire::{Confirm, Select};
+
+/// Represents the action of reviewing the trash through the CLI.
+pub struct TrashAction;
+
+impl TrashAction {
+    /// Executes the process of reviewing the trash.
+    ///
+    /// This method lists the notes in the trash with the time they were deleted and lets the user
+    /// restore one of them, delete it permanently, or empty the whole trash.
+    /// Permanent deletions are confirmed first, as they cannot be undone.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    /// - `settings`: The settings of the application.
+    ///
+    /// # Returns
+    /// - `true` if a note was restored or deleted.
+    /// - `false` if the process was canceled or an error occurred.
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings) -> bool {
+
+        let notes = match FindTrashedNotes::new(note_repository).execute() {
+            Ok(notes) => notes,
+            Err(NoteError::NotFound(_)) => {
+                println!("{} The trash is empty", Palette::current().warning(">"));
+                return false;
+            }
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        // Map notes into a vector of formatted strings for the Select component,
+        // the first option empties the whole trash
+        let mut options: Vec<String> = vec![String::from("[Empty the trash]")];
+        options.extend(notes.iter().map(|note| {
+            let deleted_at = note
+                .get_deleted_at()
+                .map(|date| date.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_default();
+            format!("{} - {} (deleted {})", note.id.unwrap_or_default(), note.title, deleted_at)
+        }));
+
+        let selected = match Select::new("Trash:", options).raw_prompt() {
+            Ok(selected) => selected,
+            Err(_) => return false,
+        };
+
+        if selected.index == 0 {
+            let message = format!("Permanently delete the {} notes in the trash?", notes.len());
+            if !Confirm::new(&message).with_default(settings.confirm.delete).prompt().unwrap_or(false) {
+                return false;
+            }
+
+            return match PurgeNote::new(note_repository).execute_all() {
+                Ok(_) => true,
+                Err(err) => {
+                    ErrorRenderer::render(&err);
+                    false
+                }
+            };
+        }
+
+        // Extract the id from the selected note
+        let id: i64 = match selected.value.split(" - ").next().unwrap_or_default().parse() {
+            Ok(parsed_id) => parsed_id,
+            Err(_) => return false,
+        };
+
+        let actions = vec!["Restore", "Delete permanently"];
+        let result = match Select::new("What do you want to do with it?", actions).prompt() {
+            Ok("Restore") => RestoreNote::new(note_repository).execute(id),
+            Ok(_) => {
+                let message = format!("Permanently delete the note with ID {}? This cannot be undone", id);
+                if !Confirm::new(&message).with_default(settings.confirm.delete).prompt().unwrap_or(false) {
+                    return false;
+                }
+
+                PurgeNote::new(note_repository).execute(id)
+            }
+            Err(_) => return false,
+        };
+
+        match result {
+            Ok(()) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
+        }
+    }
+}
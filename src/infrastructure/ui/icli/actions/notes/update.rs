@@ -0,0 +1,110 @@
+use crate::{
+    application::palette::Palette,
+    application::use_cases::notes::{find_all::FindAll, find_by_id::FindById, find_by_title::FindByTitle},
+    domain::{entities::note::Note, repositories::note_repository::NoteRepository},
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::{
+        actions::notes::edit::{EditNoteAction, EditOutcome},
+        error_renderer::ErrorRenderer,
+    },
+};
+use inquire::{Select, Text};
+
+/// Represents the action of updating an existing note through the CLI.
+pub struct UpdateNoteAction;
+
+impl UpdateNoteAction {
+    /// Executes the process of updating a note.
+    ///
+    /// This method lets the user pick a note (searching by title or browsing the full list)
+    /// and opens it in the user's editor, see `EditNoteAction`.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    /// - `settings`: The settings of the application.
+    ///
+    /// # Returns
+    /// - `true` if the note was successfully updated.
+    /// - `false` if the process was canceled, nothing changed or an error occurred.
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings) -> bool {
+
+        let notes = match UpdateNoteAction::pick_candidates(note_repository) {
+            Some(notes) => notes,
+            None => return false,
+        };
+
+        // Map notes into a vector of formatted strings for the Select component
+        let options: Vec<String> = notes
+            .iter()
+            .map(|note| format!("{} - {}", note.id.unwrap_or_default(), note.title))
+            .collect();
+
+        // Prompt the user to select a note
+        let selected_note = Select::new("Select a note to update:", options).prompt();
+
+        let selected_note = match selected_note {
+            Ok(selection) => selection,
+            Err(_) => {
+                return false;
+            }
+        };
+
+        // Extract the id from the selected note
+        let id_str = selected_note.split(" - ").next().unwrap_or_default();
+
+        let id: i64 = match id_str.parse() {
+            Ok(parsed_id) => parsed_id,
+            Err(_) => {
+                return false;
+            }
+        };
+
+        // Load the latest version of the note
+        let find_by_id = FindById::new(note_repository);
+        let note = match find_by_id.execute(id) {
+            Ok(note) => note,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        EditNoteAction::execute(note_repository, settings, &note) == EditOutcome::Saved
+    }
+
+    /// Asks the user how to locate the note to update and returns the candidate notes.
+    ///
+    /// The user can either search by title, using the `FindByTitle` use case,
+    /// or browse every stored note, using the `FindAll` use case.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository used to look up the notes.
+    ///
+    /// # Returns
+    /// - `Some(Vec<Note>)` with the notes to choose from.
+    /// - `None` if the prompt was canceled or no notes were found, telling the user so.
+    fn pick_candidates(note_repository: &dyn NoteRepository) -> Option<Vec<Note>> {
+        let modes: Vec<&str> = vec!["Search by title", "Browse all notes"];
+        let mode = Select::new("How do you want to find the note?", modes).prompt().ok()?;
+
+        let result = if mode == "Search by title" {
+            let title = Text::new("Title:").prompt().ok()?;
+            FindByTitle::new(note_repository).execute(&title)
+        } else {
+            FindAll::new(note_repository).execute()
+        };
+
+        match result {
+            Ok(notes) if !notes.is_empty() => Some(notes),
+            Ok(_) => {
+                println!("{} No note matched, nothing to update", Palette::current().warning(">"));
+                None
+            }
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    application::palette::Palette,
+    application::use_cases::{
+        notes::find_by_id::FindById,
+        tags::{find_all::FindAllTags, find_notes::FindNotesByTags},
+    },
+    domain::entities::tag::TagMatch,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::{actions::notes::lock::LockNoteAction, error_renderer::ErrorRenderer},
+};
+use inquire::{MultiSelect, Select};
+
+/// Represents the action of browsing notes by their tags through the CLI.
+pub struct BrowseTagsAction;
+
+impl BrowseTagsAction {
+    /// Executes the process of browsing notes by tag.
+    ///
+    /// This method lists the existing tags with their note counts, lets the user pick one or more,
+    /// asks how to combine them (all / any) when several are picked, and shows the selected note.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    ///
+    /// # Returns
+    /// - `true` if a note was shown.
+    /// - `false` if the process was canceled or an error occurred.
+    pub fn execute(note_repository: &SqliteNoteRepository) -> bool {
+
+        let tags = match FindAllTags::new(note_repository).execute() {
+            Ok(tags) => tags,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        // Map tags into a vector of formatted strings for the MultiSelect component
+        let options: Vec<String> = tags
+            .iter()
+            .map(|tag| format!("{} ({})", tag.get_name(), tag.get_note_count()))
+            .collect();
+
+        let selected = match MultiSelect::new("Select tags:", options)
+            .with_help_message("Space to select, enter to continue")
+            .prompt()
+        {
+            Ok(selected) => selected,
+            Err(_) => return false,
+        };
+
+        // Extract the tag names from the selected options
+        let names: Vec<String> = selected
+            .iter()
+            .map(|option| option.rsplit_once(" (").map(|(name, _)| name).unwrap_or(option).to_string())
+            .collect();
+
+        let mode = if names.len() > 1 {
+            let modes = vec!["Notes with all the tags (AND)", "Notes with any of the tags (OR)"];
+            match Select::new("Combine tags:", modes).prompt() {
+                Ok("Notes with all the tags (AND)") => TagMatch::All,
+                Ok(_) => TagMatch::Any,
+                Err(_) => return false,
+            }
+        } else {
+            TagMatch::Any
+        };
+
+        let notes = match FindNotesByTags::new(note_repository).execute(&names, mode) {
+            Ok(notes) => notes,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        // Map notes into a vector of formatted strings for the Select component
+        let options: Vec<String> = notes
+            .iter()
+            .map(|note| {
+                let tags: Vec<String> = note.tags.iter().map(|tag| format!("#{}", tag)).collect();
+                format!("{} - {} {}", note.id.unwrap_or_default(), note.title, tags.join(" "))
+            })
+            .collect();
+
+        let selected_note = match Select::new("Select a note:", options).prompt() {
+            Ok(selection) => selection,
+            Err(_) => return false,
+        };
+
+        // Extract the id from the selected note
+        let id_str = selected_note.split(" - ").next().unwrap_or_default();
+
+        let id: i64 = match id_str.parse() {
+            Ok(parsed_id) => parsed_id,
+            Err(_) => {
+                return false;
+            }
+        };
+
+        let note = match FindById::new(note_repository).execute(id) {
+            Ok(note) => note,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        // A locked note is shown only once its passphrase is given
+        let Some(note) = LockNoteAction::reveal(note_repository, note) else {
+            return false;
+        };
+
+        println!();
+        println!("{}", Palette::current().bold(note.get_title()));
+        let tags: Vec<String> = note.get_tags().iter().map(|tag| format!("#{}", tag)).collect();
+        println!("{}", Palette::current().secondary(tags.join(" ")));
+        println!();
+        println!("{}", note.get_content());
+        println!();
+
+        return true;
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    application::palette::Palette,
+    application::use_cases::{
+        notes::find_all::FindAll,
+        tags::{add::AddTag, find_all::FindAllTags, merge::MergeTags, remove::RemoveTag, rename::RenameTag},
+    },
+    domain::{
+        entities::{note::Note, tag::Tag},
+        errors::note_error::NoteError,
+        repositories::{note_repository::NoteRepository, tag_repository::TagRepository},
+    },
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::icli::error_renderer::ErrorRenderer,
+};
+use inquire::{Confirm, MultiSelect, Select, Text};
+
+/// Represents the action of managing tags (tagging and untagging notes, renaming and merging) through the CLI.
+pub struct ManageTagsAction;
+
+impl ManageTagsAction {
+    /// Executes the process of managing tags.
+    ///
+    /// The user chooses between tagging a note, removing a tag from a note, renaming a tag
+    /// or merging several tags into one; renaming and merging are confirmed before the change
+    /// is applied to every affected note, and only offered once some note is tagged.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    /// - `settings`: The settings of the application.
+    ///
+    /// # Returns
+    /// - `true` if the tags were changed.
+    /// - `false` if the process was canceled or an error occurred.
+    pub fn execute(note_repository: &SqliteNoteRepository, settings: &Settings) -> bool {
+
+        let tags: Vec<String> = match FindAllTags::new(note_repository).execute() {
+            Ok(tags) => tags.iter().map(|tag| tag.get_name().clone()).collect(),
+            Err(NoteError::NotFound(_)) => Vec::new(),
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return false;
+            }
+        };
+
+        let mut options = vec!["Tag a note", "Remove a tag from a note"];
+        if !tags.is_empty() {
+            options.extend(["Rename a tag", "Merge tags"]);
+        }
+
+        match Select::new("What do you want to do?", options).prompt() {
+            Ok("Tag a note") => ManageTagsAction::tag(note_repository, tags),
+            Ok("Remove a tag from a note") => ManageTagsAction::untag(note_repository),
+            Ok("Rename a tag") => ManageTagsAction::rename(note_repository, settings, tags),
+            Ok(_) => ManageTagsAction::merge(note_repository, settings, tags),
+            Err(_) => false,
+        }
+    }
+
+    /// Asks for a note and a tag, then tags the note with it.
+    fn tag(note_repository: &dyn NoteRepository, tags: Vec<String>) -> bool {
+        let Some(note) = ManageTagsAction::select_note(note_repository, "Note to tag:", |_| true) else {
+            return false;
+        };
+
+        let help = match tags.is_empty() {
+            true => String::from("For example #work"),
+            false => format!("In use: {}", tags.join(", ")),
+        };
+        let name = loop {
+            let Ok(name) = Text::new("Tag:").with_help_message(&help).prompt() else {
+                return false;
+            };
+
+            match Tag::normalize(&name) {
+                Ok(name) => break name,
+                Err(err) => ErrorRenderer::render(&err),
+            }
+        };
+
+        if note.has_tag(&name) {
+            println!("{} The note is already tagged with `{}`", Palette::current().warning(">"), name);
+            return false;
+        }
+
+        match AddTag::new(note_repository).execute(note.get_id().unwrap_or_default(), &name) {
+            Ok(_) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
+        }
+    }
+
+    /// Asks for a tagged note and one of its tags, then removes the tag from the note.
+    fn untag(note_repository: &dyn NoteRepository) -> bool {
+        let Some(note) =
+            ManageTagsAction::select_note(note_repository, "Note to untag:", |note| !note.get_tags().is_empty())
+        else {
+            return false;
+        };
+
+        let tags: Vec<String> = note.get_tags().iter().cloned().collect();
+        let tag = match Select::new("Tag to remove:", tags).prompt() {
+            Ok(tag) => tag,
+            Err(_) => return false,
+        };
+
+        match RemoveTag::new(note_repository).execute(note.get_id().unwrap_or_default(), &tag) {
+            Ok(_) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
+        }
+    }
+
+    /// Asks for one of the notes kept by `filter`, listed with their tags.
+    ///
+    /// # Returns
+    /// - `Some(Note)` with the chosen note.
+    /// - `None` if no note is kept, the prompt was canceled or an error occurred.
+    fn select_note(note_repository: &dyn NoteRepository, message: &str, filter: fn(&Note) -> bool) -> Option<Note> {
+        let notes: Vec<Note> = match FindAll::new(note_repository).execute() {
+            Ok(notes) => notes.into_iter().filter(filter).collect(),
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return None;
+            }
+        };
+
+        if notes.is_empty() {
+            ErrorRenderer::render(&NoteError::NotFound(String::from("tags")));
+            return None;
+        }
+
+        let options: Vec<String> = notes
+            .iter()
+            .map(|note| {
+                let tags: Vec<&str> = note.get_tags().iter().map(String::as_str).collect();
+                format!("{} - {} [{}]", note.id.unwrap_or_default(), note.title, tags.join(", "))
+            })
+            .collect();
+
+        let selected = Select::new(message, options.clone()).prompt().ok()?;
+        let index = options.iter().position(|option| *option == selected)?;
+
+        notes.into_iter().nth(index)
+    }
+
+    /// Asks for a tag and its new name, then renames it.
+    fn rename(tag_repository: &dyn TagRepository, settings: &Settings, tags: Vec<String>) -> bool {
+        let from = match Select::new("Tag to rename:", tags).prompt() {
+            Ok(from) => from,
+            Err(_) => return false,
+        };
+
+        let to = match ManageTagsAction::ask_tag_name("New name:", &from) {
+            Some(to) => to,
+            None => return false,
+        };
+
+        let message = format!("Rename `{}` to `{}` on every note?", from, to);
+        if !Confirm::new(&message).with_default(settings.confirm.save).prompt().unwrap_or(false) {
+            return false;
+        }
+
+        match RenameTag::new(tag_repository).execute(&from, &to) {
+            Ok(_) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
+        }
+    }
+
+    /// Asks for the tags to merge and the resulting name, then merges them.
+    fn merge(tag_repository: &dyn TagRepository, settings: &Settings, tags: Vec<String>) -> bool {
+        let sources = match MultiSelect::new("Tags to merge:", tags)
+            .with_help_message("Space to select, enter to continue")
+            .prompt()
+        {
+            Ok(sources) if !sources.is_empty() => sources,
+            _ => return false,
+        };
+
+        let target = match ManageTagsAction::ask_tag_name("Merge into:", &sources[0]) {
+            Some(target) => target,
+            None => return false,
+        };
+
+        let message = format!("Merge {} into `{}`?", sources.join(", "), target);
+        if !Confirm::new(&message).with_default(settings.confirm.save).prompt().unwrap_or(false) {
+            return false;
+        }
+
+        match MergeTags::new(tag_repository).execute(&sources, &target) {
+            Ok(_) => true,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                false
+            }
+        }
+    }
+
+    /// Asks for a tag name until a valid one is given.
+    ///
+    /// # Returns
+    /// - `Some(String)` with the normalized name.
+    /// - `None` if the prompt was canceled.
+    fn ask_tag_name(message: &str, initial: &str) -> Option<String> {
+        loop {
+            let name = Text::new(message).with_initial_value(initial).prompt().ok()?;
+
+            match Tag::normalize(&name) {
+                Ok(name) => return Some(name),
+                Err(err) => ErrorRenderer::render(&err),
+            }
+        }
+    }
+}
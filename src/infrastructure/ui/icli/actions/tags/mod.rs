@@ -0,0 +1,2 @@
+pub mod browse;
+pub mod manage;
\ No newline at end of file
@@ -0,0 +1,66 @@
+use crate::application::palette::Palette;
+use crate::domain::errors::note_error::NoteError;
+
+/// Renders domain errors as friendly, colored messages in the terminal.
+pub struct ErrorRenderer;
+
+impl ErrorRenderer {
+    /// Prints a human friendly message for the given `NoteError`.
+    ///
+    /// Validation errors are shown in yellow because the user can fix them by trying again,
+    /// while lookup and storage errors are shown in red.
+    ///
+    /// # Arguments
+    /// - `error`: The error to render.
+    pub fn render(error: &NoteError) {
+        let indicator = if error.is_validation() {
+            Palette::current().warning(">")
+        } else {
+            Palette::current().error(">")
+        };
+
+        println!("{} {}", indicator, ErrorRenderer::message(error));
+    }
+
+    /// Builds the human friendly message for the given `NoteError`, without any color.
+    ///
+    /// # Arguments
+    /// - `error`: The error to describe.
+    ///
+    /// # Returns
+    /// The message to show to the user.
+    pub fn message(error: &NoteError) -> String {
+        match error {
+            NoteError::EmptyTitle => String::from("The note needs a title, please write one."),
+            NoteError::TitleTooLong { max, actual } => format!(
+                "The title is too long ({} characters), please keep it under {}.",
+                actual, max
+            ),
+            NoteError::EmptyContent => String::from("The note needs some content, please write it."),
+            NoteError::ContentTooLong { max, actual } => format!(
+                "The content is too long ({} characters), please keep it under {}.",
+                actual, max
+            ),
+            NoteError::InvalidTag(reason) => format!("That tag cannot be used: {}.", reason),
+            NoteError::InvalidNotebook(reason) => format!("That notebook change is not possible: {}.", reason),
+            NoteError::InvalidId(id) => format!("`{}` is not a valid note ID.", id),
+            NoteError::InvalidQuery(reason) => format!(
+                "The search could not be understood ({}), please rephrase it.",
+                reason
+            ),
+            NoteError::NotFound(lookup) => format!("We could not find any note with {}.", lookup),
+            NoteError::NoNotes => String::from("There are no notes yet, create one first."),
+            NoteError::Locked(id) => format!(
+                "Note {} is locked, unlock it with its passphrase before changing its content.",
+                id
+            ),
+            NoteError::NotLocked(id) => format!("Note {} is not locked.", id),
+            NoteError::InvalidPassphrase(reason) => format!("That passphrase cannot be used: {}.", reason),
+            NoteError::WrongPassphrase => String::from("That passphrase does not open the note, please try again."),
+            NoteError::Storage(message) => format!(
+                "Something went wrong while accessing the notes database: {}",
+                message
+            ),
+        }
+    }
+}
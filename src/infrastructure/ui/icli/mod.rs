@@ -1,2 +1,4 @@
-pub mod presenter;
-pub mod actions;
\ No newline at end of file
+pub mod actions;
+pub mod error_renderer;
+pub mod notebook_navigator;
+pub mod presenter;
\ No newline at end of file
@@ -0,0 +1,108 @@
+use crate::{
+    application::use_cases::notebooks::find_all::FindAllNotebooks,
+    domain::{entities::notebook::Notebook, repositories::notebook_repository::NotebookRepository},
+    infrastructure::ui::icli::error_renderer::ErrorRenderer,
+};
+use inquire::Select;
+
+/// One entry of the navigation list.
+enum Choice {
+    /// Pick the notebook currently being browsed
+    UseCurrent,
+
+    /// Open (or pick, if it has no sub-notebooks) a child notebook
+    Open(Notebook),
+
+    /// Go back to the parent level
+    GoUp,
+}
+
+/// Lets the user walk the notebook tree to pick a notebook.
+pub struct NotebookNavigator;
+
+impl NotebookNavigator {
+    /// Prompts the user to navigate the notebook tree and pick one notebook.
+    ///
+    /// Each level lists the notebooks it contains with their note counts. Choosing a notebook
+    /// without sub-notebooks picks it right away; choosing one with sub-notebooks opens it,
+    /// where it can be picked with `[Use this notebook]` or left with `[Go up]`.
+    ///
+    /// # Arguments
+    /// - `notebook_repository`: The repository used to list the notebooks.
+    /// - `message`: The prompt shown to the user.
+    ///
+    /// # Returns
+    /// - `Some(Notebook)` with the picked notebook.
+    /// - `None` if the prompt was canceled or the notebooks could not be read.
+    pub fn pick(notebook_repository: &dyn NotebookRepository, message: &str) -> Option<Notebook> {
+        let notebooks = match FindAllNotebooks::new(notebook_repository).execute() {
+            Ok(notebooks) => notebooks,
+            Err(err) => {
+                ErrorRenderer::render(&err);
+                return None;
+            }
+        };
+
+        let mut current: Option<Notebook> = None;
+
+        loop {
+            let current_id = current.as_ref().and_then(|notebook| notebook.get_id());
+            let mut choices: Vec<Choice> = Vec::new();
+            let mut options: Vec<String> = Vec::new();
+
+            if let Some(notebook) = &current {
+                choices.push(Choice::UseCurrent);
+                options.push(format!(
+                    "[Use this notebook] {} ({} notes)",
+                    notebook.get_name(),
+                    notebook.get_note_count()
+                ));
+            }
+
+            for child in notebooks.iter().filter(|notebook| notebook.get_parent_id() == current_id) {
+                let has_children = notebooks
+                    .iter()
+                    .any(|notebook| notebook.get_parent_id() == child.get_id());
+                options.push(format!(
+                    "{}{} ({} notes)",
+                    child.get_name(),
+                    if has_children { " /" } else { "" },
+                    child.get_note_count()
+                ));
+                choices.push(Choice::Open(child.clone()));
+            }
+
+            if current.is_some() {
+                choices.push(Choice::GoUp);
+                options.push(String::from("[Go up]"));
+            }
+
+            let prompt = match current_id {
+                Some(id) => format!("{} {}", message, Notebook::path_of(&notebooks, id)),
+                None => message.to_string(),
+            };
+
+            let selected = Select::new(&prompt, options).raw_prompt().ok()?;
+
+            match choices.swap_remove(selected.index) {
+                Choice::UseCurrent => return current,
+                Choice::Open(notebook) => {
+                    let has_children = notebooks
+                        .iter()
+                        .any(|child| child.get_parent_id() == notebook.get_id());
+                    if !has_children {
+                        return Some(notebook);
+                    }
+                    current = Some(notebook);
+                }
+                Choice::GoUp => {
+                    let parent_id = current.as_ref().and_then(|notebook| notebook.get_parent_id());
+                    current = notebooks
+                        .iter()
+                        .find(|notebook| parent_id.is_some() && notebook.get_id() == parent_id)
+                        .cloned();
+                }
+            }
+        }
+    }
+}
@@ -2,46 +2,101 @@ use inquire::{
     error::InquireResult, ui::{Color, RenderConfig, Styled}, Confirm, Editor, InquireError, Select, Text
 };
 
-use crate::infrastructure::ui::icli::actions::notes::{create::CreateNoteAction, delete::DeletedNoteAction, find::FindNoteAction};
+use crate::application::palette::Palette;
+use crate::infrastructure::config::settings::Settings;
+use crate::infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository;
+use crate::infrastructure::ui::icli::actions::{
+    notebooks::manage::ManageNotebooksAction,
+    notes::{
+        create::CreateNoteAction, delete::DeletedNoteAction, find::FindNoteAction, lock::LockNoteAction,
+        trash::TrashAction, update::UpdateNoteAction,
+    },
+    tags::{browse::BrowseTagsAction, manage::ManageTagsAction},
+};
 
 enum ActionOptions {
     Create,
     Find,
     Update,
     Delete,
+    Lock,
+    BrowseTags,
+    ManageTags,
+    ManageNotebooks,
+    Trash,
+    Quit,
 }
 
-pub struct Presenter;
+pub struct Presenter<'a> {
+    note_repository: &'a SqliteNoteRepository,
+    settings: &'a Settings,
+}
 
-impl Presenter {
-    pub fn new() -> Self {
-        Presenter
+impl<'a> Presenter<'a> {
+    pub fn new(note_repository: &'a SqliteNoteRepository, settings: &'a Settings) -> Self {
+        Presenter {
+            note_repository,
+            settings,
+        }
     }
 
+    /// Runs the interactive session: the main menu is shown again after every action,
+    /// together with a status line telling how the previous action ended,
+    /// until the user picks "Quit" or presses Esc / Ctrl-C.
     pub fn execute(&self) {
         self.render_banner();
 
         let options: Vec<&str> = vec![
             "Create a new note",
-            "Search by title",
+            "Search notes",
             "Update a note",
             "Delete a note",
+            "Lock or unlock a note",
+            "Browse by tag",
+            "Manage tags",
+            "Manage notebooks",
+            "Trash",
+            "Quit",
         ];
 
-        let ans: Result<&str, InquireError> = Select::new("What do you want to do?", options.clone()).prompt();
-
-        match ans {
-            Ok(choice) => {
-                match self.map_choice_to_action(choice, &options) {
-                    Some(ActionOptions::Create) => CreateNoteAction::execute(),
-                    Some(ActionOptions::Find) => FindNoteAction::execute(),
-                    Some(ActionOptions::Update) => false,
-                    Some(ActionOptions::Delete) => DeletedNoteAction::execute(),
-                    None => false,
-                };
+        let mut last_outcome: Option<(&str, bool)> = None;
+
+        loop {
+            if let Some((action, succeeded)) = last_outcome {
+                self.render_status(action, succeeded);
             }
-            Err(_) => println!("There was an error, please try again."),
+
+            let ans: Result<&str, InquireError> = Select::new("What do you want to do?", options.clone())
+                .with_help_message("Esc or Ctrl-C to quit")
+                .prompt();
+
+            let choice = match ans {
+                Ok(choice) => choice,
+                Err(InquireError::OperationCanceled) | Err(InquireError::OperationInterrupted) => break,
+                Err(_) => {
+                    println!("There was an error, please try again.");
+                    break;
+                }
+            };
+
+            let succeeded = match self.map_choice_to_action(choice, &options) {
+                Some(ActionOptions::Create) => CreateNoteAction::execute(self.note_repository, self.settings),
+                Some(ActionOptions::Find) => FindNoteAction::execute(self.note_repository, self.settings),
+                Some(ActionOptions::Update) => UpdateNoteAction::execute(self.note_repository, self.settings),
+                Some(ActionOptions::Delete) => DeletedNoteAction::execute(self.note_repository, self.settings),
+                Some(ActionOptions::Lock) => LockNoteAction::execute(self.note_repository),
+                Some(ActionOptions::BrowseTags) => BrowseTagsAction::execute(self.note_repository),
+                Some(ActionOptions::ManageTags) => ManageTagsAction::execute(self.note_repository, self.settings),
+                Some(ActionOptions::ManageNotebooks) => ManageNotebooksAction::execute(self.note_repository, self.settings),
+                Some(ActionOptions::Trash) => TrashAction::execute(self.note_repository, self.settings),
+                Some(ActionOptions::Quit) => break,
+                None => false,
+            };
+
+            last_outcome = Some((choice, succeeded));
         }
+
+        println!("{} Bye!", Palette::current().success(">"));
     }
 
     fn map_choice_to_action(&self, choice: &str, options: &[&str]) -> Option<ActionOptions> {
@@ -50,13 +105,47 @@ impl Presenter {
             Some(1) => Some(ActionOptions::Find),
             Some(2) => Some(ActionOptions::Update),
             Some(3) => Some(ActionOptions::Delete),
+            Some(4) => Some(ActionOptions::Lock),
+            Some(5) => Some(ActionOptions::BrowseTags),
+            Some(6) => Some(ActionOptions::ManageTags),
+            Some(7) => Some(ActionOptions::ManageNotebooks),
+            Some(8) => Some(ActionOptions::Trash),
+            Some(9) => Some(ActionOptions::Quit),
             _ => None,
         }
     }
 
+    /// Prints the outcome of the previous action above the menu.
+    fn render_status(&self, action: &str, succeeded: bool) {
+        let (indicator, outcome) = if succeeded {
+            (Palette::current().success(">"), "done")
+        } else {
+            (Palette::current().warning(">"), "canceled or not completed")
+        };
+
+        println!();
+        println!(
+            "{} Last action: {} ({})",
+            indicator,
+            Palette::current().bold(action),
+            outcome
+        );
+    }
+
+    /// Clears the terminal and prints the banner, or the text configured in `display.banner_text`.
+    /// Nothing is done when `display.banner` is disabled.
     fn render_banner(&self) {
+        if !self.settings.display.banner {
+            return;
+        }
+
         Presenter::clear_terminal();
 
+        if let Some(text) = &self.settings.display.banner_text {
+            println!("{}", text);
+            return;
+        }
+
         // Print the banner
         println!(r"
 
@@ -1 +1,6 @@
-pub mod icli;
\ No newline at end of file
+pub mod cli;
+pub mod external_editor;
+pub mod icli;
+pub mod passphrase;
+pub mod tui;
+pub mod viewer;
\ No newline at end of file
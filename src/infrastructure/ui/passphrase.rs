@@ -0,0 +1,85 @@
+use crate::{application::note_cipher::NoteCipher, domain::entities::note::Note};
+use inquire::{Password, PasswordDisplayMode, validator::Validation};
+use std::path::Path;
+
+/// Asks for the passphrase of locked notes and of the encrypted database without echoing it,
+/// shared by the interactive session and the commands.
+pub struct PassphrasePrompt;
+
+impl PassphrasePrompt {
+    /// Asks for the passphrase that opens a locked note.
+    ///
+    /// # Arguments
+    /// - `note`: The locked note, its title is shown in the prompt.
+    ///
+    /// # Returns
+    /// The passphrase, or `None` if the prompt was canceled or there is no terminal to ask.
+    pub fn ask(note: &Note) -> Option<String> {
+        PassphrasePrompt::ask_once(&format!("Passphrase of `{}`:", note.get_title()))
+    }
+
+    /// Asks twice for the passphrase a note is going to be locked with.
+    ///
+    /// # Arguments
+    /// - `note`: The note to lock, its title is shown in the prompt.
+    ///
+    /// # Returns
+    /// The passphrase, or `None` if the prompt was canceled or there is no terminal to ask.
+    pub fn ask_new(note: &Note) -> Option<String> {
+        PassphrasePrompt::ask_twice(
+            &format!("New passphrase for `{}`:", note.get_title()),
+            "It cannot be recovered: the note cannot be read without it",
+        )
+    }
+
+    /// Asks for the passphrase that opens the encrypted database.
+    ///
+    /// # Arguments
+    /// - `database`: The path of the database, shown in the prompt.
+    ///
+    /// # Returns
+    /// The passphrase, or `None` if the prompt was canceled or there is no terminal to ask.
+    pub fn ask_database(database: &Path) -> Option<String> {
+        PassphrasePrompt::ask_once(&format!("Passphrase of the database {}:", database.display()))
+    }
+
+    /// Asks twice for the passphrase the database is going to be encrypted with.
+    ///
+    /// # Arguments
+    /// - `database`: The path of the database, shown in the prompt.
+    ///
+    /// # Returns
+    /// The passphrase, or `None` if the prompt was canceled or there is no terminal to ask.
+    pub fn ask_new_database(database: &Path) -> Option<String> {
+        PassphrasePrompt::ask_twice(
+            &format!("New passphrase for the database {}:", database.display()),
+            "It cannot be recovered: no note can be read without it",
+        )
+    }
+
+    fn ask_once(message: &str) -> Option<String> {
+        Password::new(message)
+            .with_display_mode(PasswordDisplayMode::Hidden)
+            .without_confirmation()
+            .prompt()
+            .ok()
+    }
+
+    fn ask_twice(message: &str, help: &str) -> Option<String> {
+        Password::new(message)
+            .with_display_mode(PasswordDisplayMode::Hidden)
+            .with_custom_confirmation_message("Type it again:")
+            .with_custom_confirmation_error_message("The passphrases do not match, please try again.")
+            .with_help_message(help)
+            .with_validator(|passphrase: &str| {
+                Ok(match NoteCipher::validate_passphrase(passphrase) {
+                    Ok(()) => Validation::Valid,
+                    Err(_) => Validation::Invalid(
+                        format!("Use at least {} characters", NoteCipher::MIN_PASSPHRASE_LENGTH).into(),
+                    ),
+                })
+            })
+            .prompt()
+            .ok()
+    }
+}
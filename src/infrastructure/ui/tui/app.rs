@@ -0,0 +1,291 @@
+use crate::{
+    application::use_cases::{
+        notebooks::find_all::FindAllNotebooks,
+        notes::{delete::DeletedNote, find_all::FindAll, search::SearchNotes},
+    },
+    domain::errors::note_error::NoteError,
+    infrastructure::config::settings::Settings,
+    infrastructure::persistence::sqlite_note_repository::SqliteNoteRepository,
+    infrastructure::ui::{
+        icli::{
+            actions::notes::{
+                create::CreateNoteAction,
+                edit::{EditNoteAction, EditOutcome},
+            },
+            error_renderer::ErrorRenderer,
+        },
+        tui::{
+            state::{Mode, TuiState},
+            view,
+        },
+    },
+};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::DefaultTerminal;
+use std::io::stdout;
+
+/// Notes moved at once by PageUp / PageDown.
+const PAGE_SIZE: isize = 10;
+
+/// Full-screen front end: a note list, a preview of the selected note and single-key bindings,
+/// driven by the same use cases as the interactive menu.
+pub struct TuiApp<'a> {
+    note_repository: &'a SqliteNoteRepository,
+    settings: &'a Settings,
+    state: TuiState,
+    quit: bool,
+}
+
+impl<'a> TuiApp<'a> {
+    /// Creates a new `TuiApp`.
+    ///
+    /// # Arguments
+    /// - `note_repository`: The repository shared by the whole session.
+    /// - `settings`: The settings of the application.
+    pub fn new(note_repository: &'a SqliteNoteRepository, settings: &'a Settings) -> Self {
+        TuiApp {
+            note_repository,
+            settings,
+            state: TuiState::new(),
+            quit: false,
+        }
+    }
+
+    /// Takes over the terminal until the user quits, restoring it afterwards.
+    ///
+    /// # Returns
+    /// - `Ok(())` when the user quits.
+    /// - `Err(String)` if the terminal could not be set up or read.
+    pub fn run(&mut self) -> Result<(), String> {
+        self.reload();
+
+        let mut terminal = ratatui::try_init().map_err(|err| err.to_string())?;
+        let result = self.event_loop(&mut terminal);
+        ratatui::restore();
+
+        result
+    }
+
+    /// Draws the screen and handles key presses until `quit` is set.
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> Result<(), String> {
+        while !self.quit {
+            terminal
+                .draw(|frame| view::render(&mut self.state, frame))
+                .map_err(|err| err.to_string())?;
+
+            if let Event::Key(key) = event::read().map_err(|err| err.to_string())? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key, terminal)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches a key press according to the current mode.
+    fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) -> Result<(), String> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return Ok(());
+        }
+
+        match self.state.mode {
+            Mode::Browse => self.handle_browse_key(key, terminal)?,
+            Mode::Filter => self.handle_filter_key(key),
+            Mode::Search => self.handle_search_key(key),
+            Mode::ConfirmDelete => {
+                self.handle_confirm_key(key);
+                // The use case prints its outcome, so the whole screen is drawn again
+                terminal.clear().map_err(|err| err.to_string())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keys of the note list.
+    fn handle_browse_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) -> Result<(), String> {
+        match key.code {
+            KeyCode::Char('q') => self.quit = true,
+            KeyCode::Esc => {
+                // Esc first drops the filter and the search, then quits
+                if !self.state.filter.is_empty() {
+                    self.state.filter.clear();
+                    self.state.apply_filter();
+                } else if self.state.search.is_some() {
+                    self.state.search = None;
+                    self.reload();
+                } else {
+                    self.quit = true;
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.state.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => self.state.move_selection(-1),
+            KeyCode::PageDown => self.state.move_selection(PAGE_SIZE),
+            KeyCode::PageUp => self.state.move_selection(-PAGE_SIZE),
+            KeyCode::Home | KeyCode::Char('g') => self.state.select_edge(false),
+            KeyCode::End | KeyCode::Char('G') => self.state.select_edge(true),
+            KeyCode::Char('J') => self.state.preview_scroll = self.state.preview_scroll.saturating_add(1),
+            KeyCode::Char('K') => self.state.preview_scroll = self.state.preview_scroll.saturating_sub(1),
+            KeyCode::Char('/') => {
+                self.state.mode = Mode::Filter;
+                self.state.status = None;
+            }
+            KeyCode::Char('s') => {
+                self.state.input = self.state.search.clone().unwrap_or_default();
+                self.state.mode = Mode::Search;
+                self.state.status = None;
+            }
+            KeyCode::Char('d') => {
+                if let Some(note) = self.state.selected_note() {
+                    let message = format!("Move the note `{}` to the trash? (y/n)", note.get_title());
+                    self.state.set_status(message, false);
+                    self.state.mode = Mode::ConfirmDelete;
+                }
+            }
+            KeyCode::Char('n') => {
+                let created = TuiApp::suspended(terminal, || CreateNoteAction::execute(self.note_repository, self.settings))?;
+                self.reload();
+                if created {
+                    self.state.set_status("Note created", false);
+                } else {
+                    self.state.set_status("Note not created", true);
+                }
+            }
+            KeyCode::Char('e') => {
+                if let Some(note) = self.state.selected_note().cloned() {
+                    let outcome = TuiApp::suspended(terminal, || {
+                        EditNoteAction::execute(self.note_repository, self.settings, &note)
+                    })?;
+                    self.reload();
+                    match outcome {
+                        EditOutcome::Saved => self.state.set_status("Note updated", false),
+                        EditOutcome::Unchanged => self.state.set_status("No changes to save", false),
+                        EditOutcome::Canceled => self.state.set_status("Note not updated", true),
+                    }
+                }
+            }
+            KeyCode::Char('r') => {
+                self.reload();
+                self.state.set_status("Notes reloaded", false);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Keys of the filter box: the list is filtered as the user types.
+    fn handle_filter_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.state.mode = Mode::Browse,
+            KeyCode::Esc => {
+                self.state.filter.clear();
+                self.state.apply_filter();
+                self.state.mode = Mode::Browse;
+            }
+            KeyCode::Backspace => {
+                self.state.filter.pop();
+                self.state.apply_filter();
+            }
+            KeyCode::Down => self.state.move_selection(1),
+            KeyCode::Up => self.state.move_selection(-1),
+            KeyCode::Char(c) => {
+                self.state.filter.push(c);
+                self.state.apply_filter();
+            }
+            _ => {}
+        }
+    }
+
+    /// Keys of the search box: the full-text search runs on Enter.
+    fn handle_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => {
+                let query = self.state.input.trim().to_string();
+                self.state.search = if query.is_empty() { None } else { Some(query) };
+                self.state.mode = Mode::Browse;
+                self.reload();
+            }
+            KeyCode::Esc => self.state.mode = Mode::Browse,
+            KeyCode::Backspace => {
+                self.state.input.pop();
+            }
+            KeyCode::Char(c) => self.state.input.push(c),
+            _ => {}
+        }
+    }
+
+    /// Keys of the delete confirmation: only `y` deletes.
+    fn handle_confirm_key(&mut self, key: KeyEvent) {
+        self.state.mode = Mode::Browse;
+
+        let id = match self.state.selected_note().and_then(|note| note.get_id()) {
+            Some(id) if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) => id,
+            _ => {
+                self.state.set_status("Deletion canceled", false);
+                return;
+            }
+        };
+
+        match DeletedNote::new(self.note_repository).execute(id) {
+            Ok(()) => {
+                self.reload();
+                self.state.set_status(format!("Moved note with id {} to the trash", id), false);
+            }
+            Err(err) => self.state.set_status(ErrorRenderer::message(&err), true),
+        }
+    }
+
+    /// Loads the notes again, running the active search if there is one, and the notebooks.
+    /// Problems are reported in the footer and leave the list empty.
+    fn reload(&mut self) {
+        let notes = match &self.state.search {
+            Some(query) => SearchNotes::new(self.note_repository)
+                .execute(query)
+                .map(|results| results.into_iter().map(|result| result.note).collect()),
+            None => FindAll::new(self.note_repository).execute(),
+        };
+
+        let notes = match notes {
+            Ok(notes) => notes,
+            // An empty list is not an error when browsing
+            Err(NoteError::NotFound(_) | NoteError::NoNotes) => Vec::new(),
+            Err(err) => {
+                self.state.set_status(ErrorRenderer::message(&err), true);
+                Vec::new()
+            }
+        };
+
+        self.state.notebooks = FindAllNotebooks::new(self.note_repository)
+            .execute()
+            .unwrap_or_default();
+        self.state.set_notes(notes);
+    }
+
+    /// Gives the terminal back to the prompts while `action` runs, then takes it over again.
+    ///
+    /// # Arguments
+    /// - `terminal`: The terminal of the TUI.
+    /// - `action`: What to run with a regular terminal.
+    ///
+    /// # Returns
+    /// What `action` returned, or the error if the terminal could not be switched.
+    fn suspended<T>(terminal: &mut DefaultTerminal, action: impl FnOnce() -> T) -> Result<T, String> {
+        disable_raw_mode().map_err(|err| err.to_string())?;
+        execute!(stdout(), LeaveAlternateScreen).map_err(|err| err.to_string())?;
+
+        let result = action();
+
+        execute!(stdout(), EnterAlternateScreen).map_err(|err| err.to_string())?;
+        enable_raw_mode().map_err(|err| err.to_string())?;
+        terminal.clear().map_err(|err| err.to_string())?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::domain::entities::{note::Note, notebook::Notebook};
+use ratatui::widgets::ListState;
+
+/// What the keyboard is currently driving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Moving around the note list
+    Browse,
+
+    /// Typing in the incremental filter box
+    Filter,
+
+    /// Typing a full-text search query
+    Search,
+
+    /// Waiting for the user to confirm the deletion of the selected note
+    ConfirmDelete,
+}
+
+/// Everything shown on screen: the loaded notes, what is selected and what is being typed.
+pub struct TuiState {
+    /// Notes currently loaded: every note, or the results of the active search
+    pub(crate) notes: Vec<Note>,
+
+    /// Every notebook, used to show the notebook of the selected note
+    pub(crate) notebooks: Vec<Notebook>,
+
+    /// Positions in `notes` of the notes matching the filter, in display order
+    pub(crate) visible: Vec<usize>,
+
+    /// Selection of the note list, as a position in `visible`
+    pub(crate) list_state: ListState,
+
+    /// Text of the incremental filter, applied to title, content and tags
+    pub(crate) filter: String,
+
+    /// Active full-text search query, `None` when every note is listed
+    pub(crate) search: Option<String>,
+
+    /// Text being typed in the search box
+    pub(crate) input: String,
+
+    pub(crate) mode: Mode,
+
+    /// Vertical scroll of the preview pane, in lines
+    pub(crate) preview_scroll: u16,
+
+    /// Outcome of the last operation, shown in the footer; `true` when it is an error
+    pub(crate) status: Option<(String, bool)>,
+}
+
+impl TuiState {
+    /// Creates an empty state in browse mode.
+    pub fn new() -> Self {
+        TuiState {
+            notes: Vec::new(),
+            notebooks: Vec::new(),
+            visible: Vec::new(),
+            list_state: ListState::default(),
+            filter: String::new(),
+            search: None,
+            input: String::new(),
+            mode: Mode::Browse,
+            preview_scroll: 0,
+            status: None,
+        }
+    }
+
+    /// Replaces the loaded notes, keeping the selected note selected if it is still there.
+    ///
+    /// # Arguments
+    /// - `notes`: The notes to show.
+    pub fn set_notes(&mut self, notes: Vec<Note>) {
+        let selected_id = self.selected_note().and_then(|note| note.get_id());
+
+        self.notes = notes;
+        self.apply_filter();
+
+        if let Some(id) = selected_id {
+            if let Some(position) = self
+                .visible
+                .iter()
+                .position(|index| self.notes[*index].get_id() == Some(id))
+            {
+                self.list_state.select(Some(position));
+            }
+        }
+    }
+
+    /// Recomputes the visible notes from the filter text.
+    /// Every word of the filter must appear in the title, the content or the tags, ignoring case;
+    /// the sealed content of locked notes is left out.
+    pub fn apply_filter(&mut self) {
+        let words: Vec<String> = self.filter.split_whitespace().map(str::to_lowercase).collect();
+
+        self.visible = self
+            .notes
+            .iter()
+            .enumerate()
+            .filter(|(_, note)| {
+                let haystack = format!(
+                    "{} {} {}",
+                    note.get_title(),
+                    if note.is_locked() { "" } else { note.get_content() },
+                    note.get_tags().iter().cloned().collect::<Vec<String>>().join(" ")
+                )
+                .to_lowercase();
+
+                words.iter().all(|word| haystack.contains(word.as_str()))
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        let selected = match self.list_state.selected() {
+            _ if self.visible.is_empty() => None,
+            Some(position) => Some(position.min(self.visible.len() - 1)),
+            None => Some(0),
+        };
+        self.list_state.select(selected);
+        self.preview_scroll = 0;
+    }
+
+    /// Returns the note under the cursor, if any.
+    pub fn selected_note(&self) -> Option<&Note> {
+        self.list_state
+            .selected()
+            .and_then(|position| self.visible.get(position))
+            .map(|index| &self.notes[*index])
+    }
+
+    /// Moves the cursor by `offset` notes, stopping at both ends of the list.
+    ///
+    /// # Arguments
+    /// - `offset`: Positive to move down, negative to move up.
+    pub fn move_selection(&mut self, offset: isize) {
+        if self.visible.is_empty() {
+            return;
+        }
+
+        let current = self.list_state.selected().unwrap_or(0) as isize;
+        let last = self.visible.len() as isize - 1;
+        self.list_state.select(Some((current + offset).clamp(0, last) as usize));
+        self.preview_scroll = 0;
+    }
+
+    /// Moves the cursor to the first (`false`) or last (`true`) note.
+    pub fn select_edge(&mut self, last: bool) {
+        if self.visible.is_empty() {
+            return;
+        }
+
+        self.list_state
+            .select(Some(if last { self.visible.len() - 1 } else { 0 }));
+        self.preview_scroll = 0;
+    }
+
+    /// Sets the message shown in the footer.
+    ///
+    /// # Arguments
+    /// - `message`: The message.
+    /// - `is_error`: Whether it reports a problem.
+    pub fn set_status(&mut self, message: impl Into<String>, is_error: bool) {
+        self.status = Some((message.into(), is_error));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    domain::entities::notebook::Notebook,
+    infrastructure::ui::tui::state::{Mode, TuiState},
+};
+use chrono::prelude::*;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span, Text},
+    widgets::{Block, List, ListItem, Paragraph, Wrap},
+};
+
+/// Key bindings listed in the footer when there is nothing else to say.
+const HELP: &str = "j/k move  J/K scroll  / filter  s search  n new  e edit  d delete  r reload  q quit";
+
+/// Draws the whole screen: the note list, the preview, the input box and the footer.
+///
+/// # Arguments
+/// - `state`: What to show; the list selection is updated to keep it visible.
+/// - `frame`: The frame being drawn.
+pub fn render(state: &mut TuiState, frame: &mut Frame) {
+    let [main, input, footer] =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(1), Constraint::Length(1)]).areas(frame.area());
+    let [list, preview] =
+        Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)]).areas(main);
+
+    render_list(state, frame, list);
+    render_preview(state, frame, preview);
+    render_input(state, frame, input);
+    render_footer(state, frame, footer);
+}
+
+/// Draws the note list, with the number of notes shown and the active search in its title.
+fn render_list(state: &mut TuiState, frame: &mut Frame, area: Rect) {
+    let items: Vec<ListItem> = state
+        .visible
+        .iter()
+        .map(|index| ListItem::new(state.notes[*index].get_title().as_str()))
+        .collect();
+
+    let mut title = format!(" Notes ({}/{}) ", state.visible.len(), state.notes.len());
+    if let Some(query) = &state.search {
+        title.push_str(&format!("search: {} ", query));
+    }
+
+    let list = List::new(items)
+        .block(Block::bordered().title(title))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+
+    frame.render_stateful_widget(list, area, &mut state.list_state);
+}
+
+/// Draws the selected note: its notebook, tags and dates followed by the wrapped content.
+fn render_preview(state: &TuiState, frame: &mut Frame, area: Rect) {
+    let Some(note) = state.selected_note() else {
+        let empty = Paragraph::new("No notes to show".dark_gray()).block(Block::bordered().title(" Preview "));
+        frame.render_widget(empty, area);
+        return;
+    };
+
+    let mut lines: Vec<Line> = vec![Line::from(vec![
+        Span::from("Notebook: ").dark_gray(),
+        Span::from(Notebook::path_of(&state.notebooks, note.get_notebook_id())),
+    ])];
+
+    if !note.get_tags().is_empty() {
+        let tags: Vec<String> = note.get_tags().iter().map(|tag| format!("#{}", tag)).collect();
+        lines.push(Line::from(vec![
+            Span::from("Tags: ").dark_gray(),
+            Span::from(tags.join(" ")).magenta(),
+        ]));
+    }
+
+    lines.push(Line::from(vec![
+        Span::from("Updated: ").dark_gray(),
+        Span::from(
+            note.get_updated_at()
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+        ),
+    ]));
+    lines.push(Line::default());
+    if note.is_locked() {
+        let hint = format!("Locked note, open it with `notes show {}`", note.get_id().unwrap_or_default());
+        lines.push(Line::from(Span::from(hint).dark_gray().italic()));
+    } else {
+        lines.extend(note.get_content().lines().map(|line| Line::from(line.to_string())));
+    }
+
+    let title = format!(" {} - {} ", note.get_id().unwrap_or_default(), note.get_title());
+    let preview = Paragraph::new(Text::from(lines))
+        .block(Block::bordered().title(title.bold()))
+        .wrap(Wrap { trim: false })
+        .scroll((state.preview_scroll, 0));
+
+    frame.render_widget(preview, area);
+}
+
+/// Draws the filter or search box, placing the cursor at the end while typing.
+fn render_input(state: &TuiState, frame: &mut Frame, area: Rect) {
+    let (prompt, text) = match state.mode {
+        Mode::Search => ("Search: ", state.input.as_str()),
+        Mode::Filter => ("Filter: ", state.filter.as_str()),
+        _ if !state.filter.is_empty() => ("Filter: ", state.filter.as_str()),
+        _ => ("", ""),
+    };
+
+    let line = Line::from(vec![Span::from(prompt).cyan(), Span::from(text)]);
+    frame.render_widget(Paragraph::new(line), area);
+
+    if matches!(state.mode, Mode::Search | Mode::Filter) {
+        let offset = (prompt.chars().count() + text.chars().count()) as u16;
+        frame.set_cursor_position((area.x + offset.min(area.width.saturating_sub(1)), area.y));
+    }
+}
+
+/// Draws the outcome of the last operation, or the key bindings.
+fn render_footer(state: &TuiState, frame: &mut Frame, area: Rect) {
+    let line = match (&state.mode, &state.status) {
+        (Mode::Filter, _) => Line::from("Type to filter, enter to keep it, esc to clear it".dark_gray()),
+        (Mode::Search, _) => Line::from("\"exact phrases\", prefix* and AND / OR / NOT, enter to search, esc to cancel".dark_gray()),
+        (_, Some((message, true))) => Line::from(Span::from(message.as_str()).fg(Color::Red)),
+        (_, Some((message, false))) => Line::from(Span::from(message.as_str()).fg(Color::Green)),
+        (_, None) => Line::from(HELP.dark_gray()),
+    };
+
+    frame.render_widget(Paragraph::new(line), area);
+}
@@ -0,0 +1,389 @@
+use crate::application::palette::Palette;
+use ansi_term::Style;
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, LinkType, Options, Parser, Tag, TagEnd};
+use std::sync::OnceLock;
+use syntect::{
+    easy::HighlightLines,
+    highlighting::ThemeSet,
+    parsing::SyntaxSet,
+    util::{LinesWithEndings, as_24_bit_terminal_escaped},
+};
+
+static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+
+/// Renders Markdown as lines for the terminal, styled with ANSI escape codes.
+///
+/// Headings, emphasis, lists, block quotes and links are styled with the installed palette,
+/// fenced code blocks are highlighted for the language in their info string.
+/// Text is wrapped to the given width, code blocks are kept as they are.
+pub struct MarkdownRenderer {
+    width: usize,
+    styled: bool,
+}
+
+impl MarkdownRenderer {
+    /// Theme used to highlight the code blocks
+    const CODE_THEME: &'static str = "base16-ocean.dark";
+
+    /// Indentation of the code blocks
+    const CODE_INDENT: &'static str = "    ";
+
+    /// Narrowest width the text is wrapped to, whatever the indentation
+    const MIN_WIDTH: usize = 20;
+
+    /// Creates a renderer.
+    ///
+    /// # Arguments
+    /// - `width`: The number of columns the text is wrapped to.
+    /// - `styled`: Whether to use ANSI escape codes, plain text is rendered otherwise.
+    pub fn new(width: usize, styled: bool) -> Self {
+        MarkdownRenderer { width, styled }
+    }
+
+    /// Renders a Markdown document.
+    ///
+    /// # Arguments
+    /// - `markdown`: The Markdown source.
+    ///
+    /// # Returns
+    /// The rendered lines, without line breaks.
+    pub fn render(&self, markdown: &str) -> Vec<String> {
+        let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let mut state = RenderState::new(self);
+
+        for event in Parser::new_ext(markdown, options) {
+            state.handle(event);
+        }
+
+        state.finish()
+    }
+
+    /// Highlights a code block, one line per source line.
+    fn highlight(&self, language: &str, code: &str, prefix: &str) -> Vec<String> {
+        let code = code.replace('\t', Self::CODE_INDENT);
+
+        if !self.styled {
+            return code
+                .lines()
+                .map(|line| format!("{}{}{}", prefix, Self::CODE_INDENT, line))
+                .collect();
+        }
+
+        let syntaxes = SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines);
+        let themes = THEMES.get_or_init(ThemeSet::load_defaults);
+        let syntax = syntaxes
+            .find_syntax_by_token(language)
+            .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+        let mut highlighter = HighlightLines::new(syntax, &themes.themes[Self::CODE_THEME]);
+
+        LinesWithEndings::from(&code)
+            .map(|line| {
+                let escaped = match highlighter.highlight_line(line, syntaxes) {
+                    Ok(ranges) => as_24_bit_terminal_escaped(&ranges, false),
+                    Err(_) => line.to_string(),
+                };
+                format!(
+                    "{}{}{}\x1b[0m",
+                    prefix,
+                    Self::CODE_INDENT,
+                    escaped.trim_end_matches(['\n', '\r'])
+                )
+            })
+            .collect()
+    }
+}
+
+/// A list being rendered.
+struct ListState {
+    /// Number of the next item, `None` for bullet lists
+    next: Option<u64>,
+
+    /// Width of the marker of the current item, its other lines are indented as much
+    indent: usize,
+}
+
+/// What is known while walking the events of a document.
+struct RenderState<'r> {
+    renderer: &'r MarkdownRenderer,
+    lines: Vec<String>,
+    inline: String,
+    heading: Option<HeadingLevel>,
+    bold: usize,
+    italic: usize,
+    strikethrough: usize,
+    links: Vec<Option<String>>,
+    lists: Vec<ListState>,
+    marker: Option<String>,
+    quote_depth: usize,
+    code: Option<(String, String)>,
+    blank_at: Option<usize>,
+}
+
+impl<'r> RenderState<'r> {
+    fn new(renderer: &'r MarkdownRenderer) -> Self {
+        RenderState {
+            renderer,
+            lines: Vec::new(),
+            inline: String::new(),
+            heading: None,
+            bold: 0,
+            italic: 0,
+            strikethrough: 0,
+            links: Vec::new(),
+            lists: Vec::new(),
+            marker: None,
+            quote_depth: 0,
+            code: None,
+            blank_at: None,
+        }
+    }
+
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => match &mut self.code {
+                Some((_, code)) => code.push_str(&text),
+                None => self.push_text(&text, self.style()),
+            },
+            Event::Code(code) => {
+                let style = Palette::current().secondary.normal();
+                self.push_text(&code, style);
+            }
+            Event::InlineMath(text) | Event::DisplayMath(text) => self.push_text(&text, self.style()),
+            Event::Html(html) | Event::InlineHtml(html) => self.push_text(&html, self.style()),
+            Event::SoftBreak => self.inline.push(' '),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.flush();
+                let rule = "─".repeat(self.renderer.width.max(MarkdownRenderer::MIN_WIDTH));
+                let rule = self.paint(&rule, Palette::current().secondary.normal());
+                self.lines.push(rule);
+                self.blank_line();
+            }
+            Event::TaskListMarker(checked) => {
+                self.inline.push_str(if checked { "[x] " } else { "[ ] " });
+            }
+            Event::FootnoteReference(label) => self.push_text(&format!("[^{}]", label), self.style()),
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush();
+                self.heading = Some(level);
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or_default().to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.lists.push(ListState { next: start, indent: 0 });
+            }
+            Tag::Item => {
+                self.flush();
+                if let Some(list) = self.lists.last_mut() {
+                    let marker = match list.next {
+                        Some(number) => {
+                            list.next = Some(number + 1);
+                            format!("{}. ", number)
+                        }
+                        None => String::from("• "),
+                    };
+                    list.indent = marker.chars().count();
+                    self.marker = Some(marker);
+                }
+            }
+            Tag::Emphasis => self.italic += 1,
+            Tag::Strong => self.bold += 1,
+            Tag::Strikethrough => self.strikethrough += 1,
+            Tag::Link { link_type, dest_url, .. } => {
+                // Autolinks already show their address
+                let url = match link_type {
+                    LinkType::Autolink | LinkType::Email => None,
+                    _ => Some(dest_url.to_string()),
+                };
+                self.links.push(url);
+            }
+            Tag::Image { dest_url, .. } => {
+                self.push_text("[image]", Palette::current().secondary.normal());
+                self.inline.push(' ');
+                self.links.push(Some(dest_url.to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::HtmlBlock => {
+                self.flush();
+                self.blank_line();
+            }
+            TagEnd::Heading(_) => {
+                self.flush();
+                self.heading = None;
+                self.blank_line();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth -= 1;
+                self.blank_line();
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, code)) = self.code.take() {
+                    let prefix = self.prefix(false);
+                    let highlighted = self.renderer.highlight(&language, &code, &prefix);
+                    self.lines.extend(highlighted);
+                }
+                self.blank_line();
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Item => self.flush(),
+            TagEnd::Emphasis => self.italic -= 1,
+            TagEnd::Strong => self.bold -= 1,
+            TagEnd::Strikethrough => self.strikethrough -= 1,
+            TagEnd::Link | TagEnd::Image => {
+                if let Some(Some(url)) = self.links.pop() {
+                    if !url.is_empty() {
+                        self.inline.push(' ');
+                        self.push_text(&format!("({})", url), Palette::current().secondary.normal());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The style of the text at the current position.
+    fn style(&self) -> Style {
+        let palette = Palette::current();
+        let mut style = Style::new();
+
+        if let Some(level) = self.heading {
+            style = palette.accent.bold();
+            if level == HeadingLevel::H1 {
+                style = style.underline();
+            }
+        }
+        if self.bold > 0 {
+            style = style.bold();
+        }
+        if self.italic > 0 {
+            style = style.italic();
+        }
+        if self.strikethrough > 0 {
+            style = style.strikethrough();
+        }
+        if !self.links.is_empty() {
+            style = style.underline();
+        }
+
+        style
+    }
+
+    fn paint(&self, text: &str, style: Style) -> String {
+        if self.renderer.styled && Palette::current().enabled && !text.is_empty() {
+            style.paint(text).to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Appends text to the current paragraph. Every word is painted on its own,
+    /// so the styles survive the wrapping.
+    fn push_text(&mut self, text: &str, style: Style) {
+        for (index, word) in text.split(' ').enumerate() {
+            if index > 0 {
+                self.inline.push(' ');
+            }
+            if !word.is_empty() {
+                let painted = self.paint(word, style);
+                self.inline.push_str(&painted);
+            }
+        }
+    }
+
+    /// The indentation of a line: the block quote bars and the list indentation.
+    ///
+    /// # Arguments
+    /// - `first`: Whether it is the first line of a list item, which carries its marker.
+    fn prefix(&mut self, first: bool) -> String {
+        let mut prefix = self.paint(&"│ ".repeat(self.quote_depth), Palette::current().secondary.normal());
+        let indent: usize = self.lists.iter().map(|list| list.indent).sum();
+
+        match self.marker.take().filter(|_| first) {
+            Some(marker) => {
+                let outer = indent - self.lists.last().map(|list| list.indent).unwrap_or_default();
+                prefix.push_str(&" ".repeat(outer));
+                prefix.push_str(&self.paint(&marker, Palette::current().accent.normal()));
+            }
+            None => prefix.push_str(&" ".repeat(indent)),
+        }
+
+        prefix
+    }
+
+    /// Wraps the current paragraph and moves it to the rendered lines.
+    fn flush(&mut self) {
+        let inline = std::mem::take(&mut self.inline);
+        if inline.trim().is_empty() {
+            return;
+        }
+
+        let initial = self.prefix(true);
+        let subsequent = self.prefix(false);
+        let width = self
+            .renderer
+            .width
+            .max(textwrap::core::display_width(&subsequent) + MarkdownRenderer::MIN_WIDTH);
+        let options = textwrap::Options::new(width)
+            .initial_indent(&initial)
+            .subsequent_indent(&subsequent);
+
+        for line in textwrap::wrap(inline.trim(), options) {
+            self.lines.push(line.into_owned());
+        }
+    }
+
+    /// Separates two blocks, keeping the bars of the block quotes they are in.
+    /// A separator right after another one replaces it, as the block quote around may have ended.
+    fn blank_line(&mut self) {
+        if self.lines.is_empty() {
+            return;
+        }
+        if self.blank_at == Some(self.lines.len()) {
+            self.lines.pop();
+        }
+
+        let bars = "│ ".repeat(self.quote_depth);
+        let bars = self.paint(bars.trim_end(), Palette::current().secondary.normal());
+        self.lines.push(bars);
+        self.blank_at = Some(self.lines.len());
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        self.flush();
+        if self.blank_at == Some(self.lines.len()) {
+            self.lines.pop();
+        }
+        self.lines
+    }
+}
@@ -0,0 +1,66 @@
+pub mod markdown;
+pub mod pager;
+
+use crate::{
+    application::palette::Palette,
+    domain::entities::{note::Note, notebook::Notebook},
+    infrastructure::ui::viewer::{markdown::MarkdownRenderer, pager::Pager},
+};
+use chrono::prelude::*;
+use std::io::{self, IsTerminal};
+
+/// Read-only view of a note, shared by the interactive session and `notes show`.
+pub struct NoteViewer;
+
+impl NoteViewer {
+    /// Width the content is wrapped to when the terminal size is unknown
+    const DEFAULT_WIDTH: usize = 80;
+
+    /// Prints a note: its title, notebook, tags and dates, followed by its content.
+    ///
+    /// On a terminal the content is rendered as Markdown, wrapped to its width and paged
+    /// when it is too long. Otherwise it is printed as it is stored, so it can be piped.
+    ///
+    /// # Arguments
+    /// - `note`: The note to show.
+    /// - `notebooks`: Every notebook, to print the full path of the one holding the note.
+    pub fn show(note: &Note, notebooks: &[Notebook]) {
+        let mut lines = NoteViewer::header(note, notebooks);
+        lines.push(String::new());
+
+        if io::stdout().is_terminal() {
+            let width = crossterm::terminal::size()
+                .map(|(columns, _)| columns as usize)
+                .unwrap_or(NoteViewer::DEFAULT_WIDTH);
+            let renderer = MarkdownRenderer::new(width, Palette::current().enabled);
+            lines.extend(renderer.render(note.get_content()));
+        } else {
+            lines.extend(note.get_content().lines().map(String::from));
+        }
+
+        Pager::show(&lines);
+    }
+
+    fn header(note: &Note, notebooks: &[Notebook]) -> Vec<String> {
+        let mut lines = vec![
+            Palette::current().bold(note.get_title()).to_string(),
+            format!("Notebook: {}", Notebook::path_of(notebooks, note.get_notebook_id())),
+        ];
+
+        if !note.get_tags().is_empty() {
+            let tags: Vec<String> = note.get_tags().iter().map(|tag| format!("#{}", tag)).collect();
+            lines.push(format!("Tags: {}", tags.join(" ")));
+        }
+
+        lines.push(format!(
+            "Created: {}",
+            note.get_created_at().with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        ));
+        lines.push(format!(
+            "Updated: {}",
+            note.get_updated_at().with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        ));
+
+        lines
+    }
+}
@@ -0,0 +1,68 @@
+use std::{
+    env,
+    io::{self, IsTerminal, Write},
+    process::{Command, Stdio},
+};
+
+/// Prints text, through the user's pager (`$PAGER`) when it does not fit on the screen.
+pub struct Pager;
+
+impl Pager {
+    /// Pager launched when `$PAGER` is not set
+    #[cfg(windows)]
+    const FALLBACK_PAGER: &'static str = "more";
+    #[cfg(not(windows))]
+    const FALLBACK_PAGER: &'static str = "less";
+
+    /// Options given to `less` when `$LESS` is not set, so the colors are kept
+    const LESS_OPTIONS: &'static str = "R";
+
+    /// Prints the lines, paging them if they are taller than the terminal.
+    /// They are printed directly when the standard output is not a terminal
+    /// or the pager cannot be launched.
+    ///
+    /// # Arguments
+    /// - `lines`: The lines to print, without line breaks.
+    pub fn show(lines: &[String]) {
+        let fits = match crossterm::terminal::size() {
+            Ok((_, rows)) => lines.len() < rows as usize,
+            Err(_) => true,
+        };
+
+        if fits || !io::stdout().is_terminal() || Pager::page(lines).is_err() {
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+    }
+
+    /// Pipes the lines to the pager and waits until the user closes it.
+    fn page(lines: &[String]) -> io::Result<()> {
+        let command = env::var("PAGER")
+            .ok()
+            .filter(|pager| !pager.trim().is_empty())
+            .unwrap_or_else(|| String::from(Pager::FALLBACK_PAGER));
+
+        let mut parts = command.split_whitespace();
+        let program = parts.next().unwrap_or(Pager::FALLBACK_PAGER);
+
+        let mut pager = Command::new(program);
+        pager.args(parts).stdin(Stdio::piped());
+        if env::var_os("LESS").is_none() {
+            pager.env("LESS", Pager::LESS_OPTIONS);
+        }
+
+        let mut child = pager.spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            for line in lines {
+                // The pipe breaks when the user quits before the end
+                if writeln!(stdin, "{}", line).is_err() {
+                    break;
+                }
+            }
+        }
+
+        child.wait()?;
+        Ok(())
+    }
+}
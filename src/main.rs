@@ -6,15 +6,197 @@ mod infrastructure;
 
 use domain::repositories::note_repository::NoteRepository;
 
+use application::palette::Palette;
 use application::use_cases::notes::create::CreateNewNote;
 use application::use_cases::notes::find_by_id::FindById;
 use application::use_cases::notes::find_by_title::FindByTitle;
 use application::use_cases::notes::update::UpdateNote;
 use application::use_cases::notes::delete::DeletedNote;
+use application::use_cases::trash::purge_expired::PurgeExpiredNotes;
 
+use infrastructure::config::{database_key::DatabaseKey, database_location::DatabaseLocation, settings::Settings};
+use infrastructure::persistence::{database_encryption::DatabaseEncryption, sqlite_note_repository::SqliteNoteRepository};
+use infrastructure::ui::cli::{
+    args::{Cli, Command},
+    exit_code::ExitCode,
+    runner::CommandRunner,
+};
+use infrastructure::ui::passphrase::PassphrasePrompt;
+use infrastructure::ui::icli::error_renderer::ErrorRenderer;
 use infrastructure::ui::icli::presenter::Presenter;
 
+use clap::Parser;
+use inquire::Confirm;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
 fn main() {
-    let presenter = Presenter::new();
-    presenter.execute();
+    // Parse the arguments first so `--help` and usage errors do not touch the database
+    let cli = Cli::parse();
+
+    // Read the settings before anything else, they change how everything is shown
+    let settings = match Settings::load() {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{} {}", Palette::current().error(">"), err);
+            std::process::exit(ExitCode::FAILURE);
+        }
+    };
+    Palette::install(settings.colors.clone());
+
+    let database_path = match database_path(&cli, &settings) {
+        Ok(database_path) => database_path,
+        Err(err) => {
+            eprintln!("{} {}", Palette::current().error(">"), err);
+            std::process::exit(ExitCode::FAILURE);
+        }
+    };
+
+    // Open the database once at startup so pending migrations are applied
+    // (and a newer, unsupported database is rejected) before showing the menu
+    let key = match database_key(&cli, &settings, &database_path) {
+        Ok(key) => key,
+        Err(err) => {
+            eprintln!("{} {}", Palette::current().error(">"), err);
+            std::process::exit(ExitCode::FAILURE);
+        }
+    };
+
+    let passphrase = key.as_ref().map(DatabaseKey::get_passphrase);
+    let note_repository = match SqliteNoteRepository::open_with_key(&database_path.to_string_lossy(), passphrase) {
+        Ok(note_repository) => note_repository,
+        Err(err) => {
+            eprintln!("{} {}", Palette::current().error(">"), err);
+            std::process::exit(ExitCode::FAILURE);
+        }
+    };
+
+    // Purge the notes kept in the trash for longer than the retention period,
+    // set by `trash.retention_days` or NOTES_TRASH_RETENTION_DAYS (0 keeps them forever)
+    match trash_retention_days(&settings) {
+        Ok(retention_days) => {
+            if let Err(err) = PurgeExpiredNotes::new(&note_repository).execute(retention_days) {
+                ErrorRenderer::render(&err);
+            }
+        }
+        Err(err) => eprintln!("{} {}", Palette::current().error(">"), err),
+    }
+
+    // Run the requested command, or fall back to the interactive menu
+    match cli.command {
+        Some(command) => std::process::exit(CommandRunner::new(&note_repository, &settings, cli.output).run(command)),
+        None => {
+            let presenter = Presenter::new(&note_repository, &settings);
+            presenter.execute();
+        }
+    }
+}
+
+/// Resolves where the database lives, see `DatabaseLocation::resolve`.
+///
+/// When the default location is used for the first time and a database from older versions
+/// is found in the working directory, the user is offered to move it there. Until the question
+/// is answered (there is no terminal, or it is canceled) the old database keeps being used.
+fn database_path(cli: &Cli, settings: &Settings) -> Result<PathBuf, String> {
+    let location = DatabaseLocation::resolve(cli.db.as_deref(), settings.database_path.as_deref())?;
+
+    if let Some(legacy) = location.legacy_database() {
+        if !std::io::stdin().is_terminal() {
+            eprintln!(
+                "{} Using {}, run the application from a terminal to move it to {}",
+                Palette::current().warning(">"),
+                legacy.display(),
+                location.get_path().display()
+            );
+            return Ok(legacy);
+        }
+
+        let message = format!(
+            "Found a database in {}, move it to {}?",
+            legacy.display(),
+            location.get_path().display()
+        );
+        let answer = Confirm::new(&message)
+            .with_help_message("Answering no creates a new, empty database there; Esc asks again next time")
+            .with_default(true)
+            .prompt();
+
+        match answer {
+            Ok(true) => {
+                location.migrate_from(&legacy)?;
+                println!(
+                    "{} Moved the database to {}",
+                    Palette::current().success(">"),
+                    Palette::current().accent(location.get_path().display().to_string())
+                );
+            }
+            Ok(false) => {}
+            Err(_) => return Ok(legacy),
+        }
+    }
+
+    location.create_parent_dir()?;
+
+    Ok(location.get_path().to_path_buf())
+}
+
+/// Reads the number of days notes are kept in the trash from `NOTES_TRASH_RETENTION_DAYS`,
+/// or from the `trash.retention_days` setting when it is not set.
+///
+/// A value that is not a whole number of days is an error rather than the setting,
+/// so a typo like `365d` does not purge notes the user meant to keep; the trash is left alone.
+fn trash_retention_days(settings: &Settings) -> Result<i64, String> {
+    let Ok(days) = std::env::var("NOTES_TRASH_RETENTION_DAYS") else {
+        return Ok(settings.trash.retention_days);
+    };
+
+    days.trim().parse::<i64>().map_err(|_| {
+        format!(
+            "NOTES_TRASH_RETENTION_DAYS must be a whole number of days, not `{}`; the trash was not emptied",
+            days
+        )
+    })
+}
+
+/// Resolves the key the database is opened with, see `DatabaseKey::resolve`.
+///
+/// An encrypted database needs one, so it is asked for when none is given and there is a terminal.
+/// A new database is created encrypted when a key is given. A plain database is opened as it is,
+/// so `encrypt` can take its new key from the same places; other commands warn that the key is ignored.
+fn database_key(cli: &Cli, settings: &Settings, database: &Path) -> Result<Option<DatabaseKey>, String> {
+    let given = DatabaseKey::resolve(settings.database_keyfile.as_deref())?;
+    let exists = database.metadata().is_ok_and(|metadata| metadata.len() > 0);
+
+    if !DatabaseEncryption::is_encrypted(database) {
+        if !exists {
+            return Ok(given);
+        }
+
+        if given.is_some() && !matches!(cli.command, Some(Command::Encrypt { .. })) {
+            eprintln!(
+                "{} {} is not encrypted, so its key is ignored; encrypt it with `encrypt`",
+                Palette::current().warning(">"),
+                database.display()
+            );
+        }
+        return Ok(None);
+    }
+
+    if given.is_some() {
+        return Ok(given);
+    }
+
+    if !std::io::stdin().is_terminal() {
+        return Err(format!(
+            "{} is encrypted, give its passphrase with {} or {}, or run the application from a terminal",
+            database.display(),
+            DatabaseKey::ENV_VAR,
+            DatabaseKey::KEYFILE_ENV_VAR
+        ));
+    }
+
+    PassphrasePrompt::ask_database(database)
+        .map(DatabaseKey::typed)
+        .map(Some)
+        .ok_or_else(|| String::from("The database is encrypted and no passphrase was given"))
 }